@@ -1,8 +1,22 @@
-use crate::db::Database;
-use crate::depreciation::{current_book_value, depreciation_for_year, generate_schedule};
+use crate::db::{
+    asset_from_row, bonus_opted_out, category_default_method, category_revaluation_model,
+    deferred_tax_rate, fiscal_year, get_setting, lease_from_row, set_setting, Database,
+};
+use crate::depreciation::{
+    apply_book, book_value_at_disposal, business_use_percentage, combine_periods,
+    combine_schedules, component_schedules, current_book_value, deferred_tax,
+    depreciation_for_year, disposal_share, excess_depreciation, financial_book,
+    gain_loss_on_disposal, generate_revised_schedule, is_intangible, is_real_property,
+    monthly_schedule, post_revaluations, recapture, section_179_allowed_limit,
+    section_179_deduction, split_asset, temporary_difference, BOOK_FEDERAL, BOOK_FINANCIAL,
+    KIND_INTANGIBLE, METHOD_MACRS_GDS, METHOD_UNITS_OF_PRODUCTION, PERIOD_MONTH, PERIOD_QUARTER,
+    PROCEEDS_SALE,
+};
+use crate::fiscal::{month_range, FiscalYear};
+use crate::lease::{current_liability, liability_schedule, right_of_use_asset};
 use crate::models::*;
+use crate::money::Money;
 use crate::validation;
-use chrono::Datelike;
 use rusqlite::params;
 use tauri::State;
 
@@ -17,7 +31,8 @@ fn map_err<E: std::fmt::Display>(e: E) -> String {
 #[tauri::command]
 pub fn get_dashboard_stats(db: State<Database>) -> Result<DashboardStats> {
     let conn = db.conn.lock().map_err(map_err)?;
-    let current_year = chrono::Local::now().year();
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    let current_year = fiscal.current_year();
 
     let total_assets: i64 = conn
         .query_row(
@@ -27,7 +42,7 @@ pub fn get_dashboard_stats(db: State<Database>) -> Result<DashboardStats> {
         )
         .map_err(map_err)?;
 
-    let total_cost: f64 = conn
+    let total_cost: Money = conn
         .query_row(
             "SELECT COALESCE(SUM(cost), 0) FROM assets WHERE disposed_date IS NULL",
             [],
@@ -35,49 +50,31 @@ pub fn get_dashboard_stats(db: State<Database>) -> Result<DashboardStats> {
         )
         .map_err(map_err)?;
 
-    // Calculate total book value and current year depreciation
-    let mut stmt = conn
-        .prepare("SELECT * FROM assets WHERE disposed_date IS NULL")
-        .map_err(map_err)?;
+    // Calculate total book value and current year depreciation, which
+    // includes assets disposed of during the year
+    let mut stmt = conn.prepare("SELECT * FROM assets").map_err(map_err)?;
 
     let assets: Vec<Asset> = stmt
-        .query_map([], |row| {
-            Ok(Asset {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                category_id: row.get(3)?,
-                date_placed_in_service: row.get(4)?,
-                cost: row.get(5)?,
-                salvage_value: row.get(6)?,
-                useful_life_years: row.get(7)?,
-                property_class: row.get(8)?,
-                notes: row.get(9)?,
-                disposed_date: row.get(10)?,
-                disposed_value: row.get(11)?,
-                created_at: row.get(12)?,
-                updated_at: row.get(13)?,
-            })
-        })
+        .query_map([], asset_from_row)
         .map_err(map_err)?
         .filter_map(|r| r.ok())
         .collect();
 
-    let total_book_value: f64 = assets
-        .iter()
-        .map(|a| current_book_value(a, current_year))
-        .sum();
-
-    let current_year_depreciation: f64 = assets
-        .iter()
-        .map(|a| depreciation_for_year(a, current_year))
-        .sum();
+    let mut total_book_value = Money::ZERO;
+    let mut current_year_depreciation = Money::ZERO;
+    for asset in &assets {
+        let schedule = get_schedule_for_asset(&conn, asset.id.unwrap(), BOOK_FINANCIAL)?;
+        if asset.disposed_date.is_none() {
+            total_book_value += current_book_value(asset, &schedule, current_year);
+        }
+        current_year_depreciation += depreciation_for_year(asset, &schedule, current_year, fiscal);
+    }
 
     Ok(DashboardStats {
         total_assets,
-        total_cost: (total_cost * 100.0).round() / 100.0,
-        total_book_value: (total_book_value * 100.0).round() / 100.0,
-        current_year_depreciation: (current_year_depreciation * 100.0).round() / 100.0,
+        total_cost,
+        total_book_value,
+        current_year_depreciation,
     })
 }
 
@@ -87,7 +84,7 @@ pub fn get_dashboard_stats(db: State<Database>) -> Result<DashboardStats> {
 pub fn get_categories(db: State<Database>) -> Result<Vec<Category>> {
     let conn = db.conn.lock().map_err(map_err)?;
     let mut stmt = conn
-        .prepare("SELECT id, name, default_useful_life, default_property_class, created_at, updated_at FROM categories ORDER BY name")
+        .prepare("SELECT id, name, default_useful_life, default_property_class, default_method, revaluation_model, created_at, updated_at FROM categories ORDER BY name")
         .map_err(map_err)?;
 
     let categories = stmt
@@ -97,8 +94,10 @@ pub fn get_categories(db: State<Database>) -> Result<Vec<Category>> {
                 name: row.get(1)?,
                 default_useful_life: row.get(2)?,
                 default_property_class: row.get(3)?,
-                created_at: row.get(4)?,
-                updated_at: row.get(5)?,
+                default_method: row.get(4)?,
+                revaluation_model: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
             })
         })
         .map_err(map_err)?
@@ -115,8 +114,14 @@ pub fn create_category(db: State<Database>, category: Category) -> Result<i64> {
 
     let conn = db.conn.lock().map_err(map_err)?;
     conn.execute(
-        "INSERT INTO categories (name, default_useful_life, default_property_class) VALUES (?1, ?2, ?3)",
-        params![category.name.trim(), category.default_useful_life, category.default_property_class],
+        "INSERT INTO categories (name, default_useful_life, default_property_class, default_method, revaluation_model) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            category.name.trim(),
+            category.default_useful_life,
+            category.default_property_class,
+            category.default_method,
+            category.revaluation_model,
+        ],
     )
     .map_err(map_err)?;
 
@@ -132,8 +137,15 @@ pub fn update_category(db: State<Database>, category: Category) -> Result<()> {
     let id = category.id.ok_or("Category ID required")?;
 
     conn.execute(
-        "UPDATE categories SET name = ?1, default_useful_life = ?2, default_property_class = ?3, updated_at = CURRENT_TIMESTAMP WHERE id = ?4",
-        params![category.name.trim(), category.default_useful_life, category.default_property_class, id],
+        "UPDATE categories SET name = ?1, default_useful_life = ?2, default_property_class = ?3, default_method = ?4, revaluation_model = ?5, updated_at = CURRENT_TIMESTAMP WHERE id = ?6",
+        params![
+            category.name.trim(),
+            category.default_useful_life,
+            category.default_property_class,
+            category.default_method,
+            category.revaluation_model,
+            id,
+        ],
     )
     .map_err(map_err)?;
 
@@ -207,7 +219,8 @@ pub fn get_categories_with_counts(db: State<Database>) -> Result<Vec<CategoryWit
     let mut stmt = conn
         .prepare(
             "SELECT c.id, c.name, c.default_useful_life, c.default_property_class,
-                    c.created_at, c.updated_at, COUNT(a.id) as asset_count
+                    c.default_method, c.revaluation_model, c.created_at, c.updated_at,
+                    COUNT(a.id) as asset_count
              FROM categories c
              LEFT JOIN assets a ON c.id = a.category_id
              GROUP BY c.id
@@ -222,9 +235,11 @@ pub fn get_categories_with_counts(db: State<Database>) -> Result<Vec<CategoryWit
                 name: row.get(1)?,
                 default_useful_life: row.get(2)?,
                 default_property_class: row.get(3)?,
-                created_at: row.get(4)?,
-                updated_at: row.get(5)?,
-                asset_count: row.get(6)?,
+                default_method: row.get(4)?,
+                revaluation_model: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+                asset_count: row.get(8)?,
             })
         })
         .map_err(map_err)?
@@ -252,36 +267,32 @@ pub fn get_assets(db: State<Database>) -> Result<Vec<AssetWithSchedule>> {
     let assets: Vec<(Asset, Option<String>)> = stmt
         .query_map([], |row| {
             Ok((
-                Asset {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    description: row.get(2)?,
-                    category_id: row.get(3)?,
-                    date_placed_in_service: row.get(4)?,
-                    cost: row.get(5)?,
-                    salvage_value: row.get(6)?,
-                    useful_life_years: row.get(7)?,
-                    property_class: row.get(8)?,
-                    notes: row.get(9)?,
-                    disposed_date: row.get(10)?,
-                    disposed_value: row.get(11)?,
-                    created_at: row.get(12)?,
-                    updated_at: row.get(13)?,
-                },
-                row.get(14)?, // category_name
+                asset_from_row(row)?,
+                row.get("category_name")?,
             ))
         })
         .map_err(map_err)?
         .filter_map(|r| r.ok())
         .collect();
 
+    // Improvements are rolled up into the asset they improve
+    let (improvements, assets): (Vec<_>, Vec<_>) = assets
+        .into_iter()
+        .partition(|(asset, _)| asset.improved_asset_id.is_some());
+
     let mut result = Vec::new();
     for (asset, category_name) in assets {
-        let schedule = get_schedule_for_asset(&conn, asset.id.unwrap())?;
+        let improvements: Vec<Asset> = improvements
+            .iter()
+            .filter(|(improvement, _)| improvement.improved_asset_id == asset.id)
+            .map(|(improvement, _)| improvement.clone())
+            .collect();
+        let schedule = rolled_up_schedule(&conn, &asset, &improvements, BOOK_FINANCIAL)?;
         result.push(AssetWithSchedule {
             asset,
             schedule,
             category_name,
+            improvements,
         });
     }
 
@@ -289,7 +300,8 @@ pub fn get_assets(db: State<Database>) -> Result<Vec<AssetWithSchedule>> {
 }
 
 #[tauri::command]
-pub fn get_asset(db: State<Database>, id: i64) -> Result<AssetWithSchedule> {
+pub fn get_asset(db: State<Database>, id: i64, book: Option<String>) -> Result<AssetWithSchedule> {
+    let book = selected_book(book)?;
     let conn = db.conn.lock().map_err(map_err)?;
 
     let (asset, category_name) = conn
@@ -301,34 +313,21 @@ pub fn get_asset(db: State<Database>, id: i64) -> Result<AssetWithSchedule> {
             [id],
             |row| {
                 Ok((
-                    Asset {
-                        id: row.get(0)?,
-                        name: row.get(1)?,
-                        description: row.get(2)?,
-                        category_id: row.get(3)?,
-                        date_placed_in_service: row.get(4)?,
-                        cost: row.get(5)?,
-                        salvage_value: row.get(6)?,
-                        useful_life_years: row.get(7)?,
-                        property_class: row.get(8)?,
-                        notes: row.get(9)?,
-                        disposed_date: row.get(10)?,
-                        disposed_value: row.get(11)?,
-                        created_at: row.get(12)?,
-                        updated_at: row.get(13)?,
-                    },
-                    row.get::<_, Option<String>>(14)?,
+                    asset_from_row(row)?,
+                    row.get::<_, Option<String>>("category_name")?,
                 ))
             },
         )
         .map_err(map_err)?;
 
-    let schedule = get_schedule_for_asset(&conn, id)?;
+    let improvements = get_improvements(&conn, id)?;
+    let schedule = rolled_up_schedule(&conn, &asset, &improvements, &book)?;
 
     Ok(AssetWithSchedule {
         asset,
         schedule,
         category_name,
+        improvements,
     })
 }
 
@@ -339,9 +338,31 @@ pub fn create_asset(db: State<Database>, asset: Asset) -> Result<i64> {
 
     let conn = db.conn.lock().map_err(map_err)?;
 
+    if let Some(improved_id) = asset.improved_asset_id {
+        let improved = conn
+            .query_row(
+                "SELECT * FROM assets WHERE id = ?1",
+                [improved_id],
+                asset_from_row,
+            )
+            .map_err(|_| "Improved asset not found")?;
+        validation::validate_improvement(&asset, &improved).map_err(map_err)?;
+    }
+
+    // Fall back to the category's default method
+    let mut asset = asset;
+    if asset.method.is_none() {
+        if let Some(category_id) = asset.category_id {
+            asset.method = category_default_method(&conn, category_id)
+                .map_err(map_err)?
+                .ok_or("Category not found")?;
+            validation::validate_asset(&asset).map_err(map_err)?;
+        }
+    }
+
     conn.execute(
-        "INSERT INTO assets (name, description, category_id, date_placed_in_service, cost, salvage_value, useful_life_years, property_class, notes)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        "INSERT INTO assets (name, description, category_id, date_placed_in_service, cost, salvage_value, useful_life_years, property_class, method, convention, total_units, section_179_amount, bonus_percentage, business_use_percentage, passenger_auto, asset_kind, notes, quantity, improved_asset_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
         params![
             asset.name.trim(),
             asset.description.as_ref().map(|s| s.trim()),
@@ -351,7 +372,17 @@ pub fn create_asset(db: State<Database>, asset: Asset) -> Result<i64> {
             asset.salvage_value,
             asset.useful_life_years,
             asset.property_class,
+            asset.method,
+            asset.convention,
+            asset.total_units,
+            asset.section_179_amount,
+            asset.bonus_percentage,
+            asset.business_use_percentage,
+            asset.passenger_auto,
+            asset.asset_kind,
             asset.notes.as_ref().map(|s| s.trim()),
+            asset.quantity,
+            asset.improved_asset_id,
         ],
     )
     .map_err(map_err)?;
@@ -374,12 +405,43 @@ pub fn update_asset(db: State<Database>, asset: Asset) -> Result<()> {
     let conn = db.conn.lock().map_err(map_err)?;
     let id = asset.id.ok_or("Asset ID required")?;
 
+    // Fall back to the category's default method
+    let mut asset = asset;
+    if asset.method.is_none() {
+        if let Some(category_id) = asset.category_id {
+            asset.method = category_default_method(&conn, category_id)
+                .map_err(map_err)?
+                .ok_or("Category not found")?;
+            validation::validate_asset(&asset).map_err(map_err)?;
+        }
+    }
+
+    let component_cost: Money = get_components_for_asset(&conn, id)?
+        .iter()
+        .map(|component| component.cost)
+        .sum();
+    if component_cost > asset.cost {
+        return Err(format!(
+            "The asset's components cost ${}, more than the asset",
+            component_cost
+        ));
+    }
+
+    // A tax book's settings can stop fitting the asset, such as MACRS GDS
+    // once business use drops to 50% or less
+    for book in get_books_for_asset(&conn, id)? {
+        validation::validate_asset_book(&book, &asset).map_err(map_err)?;
+    }
+
     conn.execute(
         "UPDATE assets SET
             name = ?1, description = ?2, category_id = ?3, date_placed_in_service = ?4,
             cost = ?5, salvage_value = ?6, useful_life_years = ?7, property_class = ?8,
-            notes = ?9, disposed_date = ?10, disposed_value = ?11, updated_at = CURRENT_TIMESTAMP
-         WHERE id = ?12",
+            method = ?9, convention = ?10, total_units = ?11, section_179_amount = ?12,
+            bonus_percentage = ?13, business_use_percentage = ?14, passenger_auto = ?15,
+            asset_kind = ?16, notes = ?17, disposed_date = ?18, disposed_value = ?19,
+            selling_costs = ?20, proceeds_type = ?21, quantity = ?22, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?23",
         params![
             asset.name.trim(),
             asset.description.as_ref().map(|s| s.trim()),
@@ -389,9 +451,20 @@ pub fn update_asset(db: State<Database>, asset: Asset) -> Result<()> {
             asset.salvage_value,
             asset.useful_life_years,
             asset.property_class,
+            asset.method,
+            asset.convention,
+            asset.total_units,
+            asset.section_179_amount,
+            asset.bonus_percentage,
+            asset.business_use_percentage,
+            asset.passenger_auto,
+            asset.asset_kind,
             asset.notes.as_ref().map(|s| s.trim()),
             asset.disposed_date,
             asset.disposed_value,
+            asset.selling_costs,
+            asset.proceeds_type,
+            asset.quantity,
             id,
         ],
     )
@@ -406,11 +479,7 @@ pub fn update_asset(db: State<Database>, asset: Asset) -> Result<()> {
 #[tauri::command]
 pub fn delete_asset(db: State<Database>, id: i64) -> Result<()> {
     let conn = db.conn.lock().map_err(map_err)?;
-    conn.execute("DELETE FROM depreciation_schedule WHERE asset_id = ?1", [id])
-        .map_err(map_err)?;
-    conn.execute("DELETE FROM assets WHERE id = ?1", [id])
-        .map_err(map_err)?;
-    Ok(())
+    remove_asset(&conn, id)
 }
 
 #[tauri::command]
@@ -418,7 +487,9 @@ pub fn dispose_asset(
     db: State<Database>,
     id: i64,
     disposed_date: String,
-    disposed_value: Option<f64>,
+    disposed_value: Option<Money>,
+    selling_costs: Option<Money>,
+    proceeds_type: Option<String>,
 ) -> Result<()> {
     let conn = db.conn.lock().map_err(map_err)?;
 
@@ -434,109 +505,2732 @@ pub fn dispose_asset(
     // Validate the disposal
     validation::validate_disposal(&disposed_date, disposed_value, &date_placed_in_service)
         .map_err(map_err)?;
+    validation::validate_disposal_terms(selling_costs, proceeds_type.as_deref())
+        .map_err(map_err)?;
 
-    conn.execute(
-        "UPDATE assets SET disposed_date = ?1, disposed_value = ?2, updated_at = CURRENT_TIMESTAMP WHERE id = ?3",
-        params![disposed_date, disposed_value, id],
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    tx.execute(
+        "UPDATE assets SET disposed_date = ?1, disposed_value = ?2, selling_costs = ?3,
+            proceeds_type = ?4, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?5",
+        params![
+            disposed_date,
+            disposed_value,
+            selling_costs,
+            proceeds_type,
+            id
+        ],
     )
     .map_err(map_err)?;
-    Ok(())
-}
 
-// ============ Annual Summary ============
+    // Improvements still in service go with the asset. The proceeds are the
+    // asset's, and its gain or loss takes in their book value.
+    for improvement in get_improvements(&tx, id)? {
+        if improvement.disposed_date.is_some() {
+            continue;
+        }
+        let improvement_id = improvement.id.ok_or("Asset ID required")?;
+        tx.execute(
+            "UPDATE assets SET disposed_date = ?1, disposed_value = NULL, selling_costs = NULL,
+                proceeds_type = ?2, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?3",
+            params![disposed_date, proceeds_type, improvement_id],
+        )
+        .map_err(map_err)?;
+        let improvement = tx
+            .query_row(
+                "SELECT * FROM assets WHERE id = ?1",
+                [improvement_id],
+                asset_from_row,
+            )
+            .map_err(map_err)?;
+        save_schedule(&tx, &improvement)?;
+    }
+
+    // Schedules stop at disposal, and the gain or loss is worked out from them
+    let asset = tx
+        .query_row("SELECT * FROM assets WHERE id = ?1", [id], asset_from_row)
+        .map_err(map_err)?;
+    save_schedule(&tx, &asset)?;
+    tx.commit().map_err(map_err)
+}
 
+/// Dispose of part of an asset. The disposed part is split off into an
+/// asset of its own, linked back to the original, carrying its share of the
+/// cost, tax book settings and usage. The original keeps the rest. Both
+/// schedules are regenerated, so the part's accumulated depreciation and
+/// gain or loss come from its own schedule. Returns the new part's ID.
 #[tauri::command]
-pub fn get_annual_summary(db: State<Database>) -> Result<Vec<AnnualSummary>> {
+pub fn partial_dispose_asset(db: State<Database>, disposal: PartialDisposal) -> Result<i64> {
     let conn = db.conn.lock().map_err(map_err)?;
 
-    let mut stmt = conn
-        .prepare(
-            "SELECT year, SUM(depreciation_expense) as total, COUNT(DISTINCT asset_id) as count
-             FROM depreciation_schedule ds
-             JOIN assets a ON ds.asset_id = a.id
-             WHERE a.disposed_date IS NULL OR
-                   CAST(substr(a.disposed_date, 1, 4) AS INTEGER) >= ds.year
-             GROUP BY year
-             ORDER BY year",
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [disposal.asset_id],
+            asset_from_row,
         )
+        .map_err(|_| "Asset not found")?;
+
+    validation::validate_disposal(
+        &disposal.disposed_date,
+        disposal.disposed_value,
+        &asset.date_placed_in_service,
+    )
+    .map_err(map_err)?;
+    validation::validate_disposal_terms(disposal.selling_costs, disposal.proceeds_type.as_deref())
         .map_err(map_err)?;
+    validation::validate_partial_disposal(&disposal, &asset).map_err(map_err)?;
+
+    let share = disposal_share(&asset, disposal.quantity, disposal.percentage);
+    let (remaining, mut part) = split_asset(&asset, disposal.quantity, disposal.percentage);
+    part.disposed_date = Some(disposal.disposed_date.trim().to_string());
+    part.disposed_value = disposal.disposed_value;
+    part.selling_costs = disposal.selling_costs;
+    part.proceeds_type = disposal.proceeds_type;
+
+    // Split everything recorded against the asset in one transaction, so a
+    // failure part way leaves the original as it was
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    let part_id = insert_asset_part(&tx, &part)?;
+    part.id = Some(part_id);
+    split_books(&tx, disposal.asset_id, part_id, share)?;
+    split_estimate_changes(&tx, disposal.asset_id, part_id, share)?;
+    split_impairments(&tx, disposal.asset_id, part_id, share)?;
+    split_revaluations(&tx, disposal.asset_id, part_id, share)?;
+    split_components(&tx, disposal.asset_id, part_id, share)?;
+    split_usage(&tx, disposal.asset_id, part_id, share)?;
+    copy_business_use(&tx, disposal.asset_id, part_id)?;
+
+    tx.execute(
+        "UPDATE assets SET cost = ?1, salvage_value = ?2, total_units = ?3,
+            section_179_amount = ?4, quantity = ?5, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?6",
+        params![
+            remaining.cost,
+            remaining.salvage_value,
+            remaining.total_units,
+            remaining.section_179_amount,
+            remaining.quantity,
+            disposal.asset_id,
+        ],
+    )
+    .map_err(map_err)?;
 
-    let summaries = stmt
-        .query_map([], |row| {
-            Ok(AnnualSummary {
-                year: row.get(0)?,
-                total_depreciation: row.get(1)?,
-                asset_count: row.get(2)?,
-            })
-        })
-        .map_err(map_err)?
-        .filter_map(|r| r.ok())
-        .collect();
+    save_schedule(&tx, &part)?;
+    save_schedule(&tx, &remaining)?;
+    tx.commit().map_err(map_err)?;
 
-    Ok(summaries)
+    Ok(part_id)
 }
 
-// ============ Helper Functions ============
-
-fn get_schedule_for_asset(
-    conn: &rusqlite::Connection,
-    asset_id: i64,
-) -> Result<Vec<DepreciationEntry>> {
+/// Parts disposed of from an asset, oldest first
+#[tauri::command]
+pub fn get_asset_parts(db: State<Database>, asset_id: i64) -> Result<Vec<Asset>> {
+    let conn = db.conn.lock().map_err(map_err)?;
     let mut stmt = conn
-        .prepare(
-            "SELECT id, asset_id, year, beginning_book_value, depreciation_expense,
-                    accumulated_depreciation, ending_book_value
-             FROM depreciation_schedule
-             WHERE asset_id = ?1
-             ORDER BY year",
-        )
+        .prepare("SELECT * FROM assets WHERE parent_asset_id = ?1 ORDER BY disposed_date, id")
         .map_err(map_err)?;
 
-    let entries = stmt
-        .query_map([asset_id], |row| {
-            Ok(DepreciationEntry {
-                id: row.get(0)?,
-                asset_id: row.get(1)?,
-                year: row.get(2)?,
-                beginning_book_value: row.get(3)?,
-                depreciation_expense: row.get(4)?,
-                accumulated_depreciation: row.get(5)?,
-                ending_book_value: row.get(6)?,
-            })
-        })
+    let parts = stmt
+        .query_map([asset_id], asset_from_row)
         .map_err(map_err)?
         .filter_map(|r| r.ok())
         .collect();
 
-    Ok(entries)
+    Ok(parts)
 }
 
-fn save_schedule(conn: &rusqlite::Connection, asset: &Asset) -> Result<()> {
-    let id = asset.id.ok_or("Asset ID required")?;
+// ============ Usage ============
 
-    // Delete existing schedule
-    conn.execute("DELETE FROM depreciation_schedule WHERE asset_id = ?1", [id])
+#[tauri::command]
+pub fn get_asset_usage(db: State<Database>, asset_id: i64) -> Result<Vec<UsageEntry>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_usage_for_asset(&conn, asset_id)
+}
+
+#[tauri::command]
+pub fn record_usage(db: State<Database>, usage: UsageEntry) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [usage.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    if asset.method.as_deref() != Some(METHOD_UNITS_OF_PRODUCTION) {
+        return Err("Usage can only be recorded for units-of-production assets".to_string());
+    }
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    validation::validate_usage(&usage, &asset.date_placed_in_service, fiscal).map_err(map_err)?;
+
+    // One entry per asset and year; recording a year again replaces it
+    conn.execute(
+        "INSERT INTO asset_usage (asset_id, year, units, notes) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(asset_id, year) DO UPDATE SET units = excluded.units, notes = excluded.notes",
+        params![
+            usage.asset_id,
+            usage.year,
+            usage.units,
+            usage.notes.as_ref().map(|s| s.trim()),
+        ],
+    )
+    .map_err(map_err)?;
+
+    let id: i64 = conn
+        .query_row(
+            "SELECT id FROM asset_usage WHERE asset_id = ?1 AND year = ?2",
+            params![usage.asset_id, usage.year],
+            |row| row.get(0),
+        )
         .map_err(map_err)?;
 
-    // Generate and insert new schedule
-    let schedule = generate_schedule(asset);
+    // Recompute the schedule from the actual usage
+    save_schedule(&conn, &asset)?;
 
-    for entry in schedule {
-        conn.execute(
-            "INSERT INTO depreciation_schedule
-             (asset_id, year, beginning_book_value, depreciation_expense, accumulated_depreciation, ending_book_value)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![
-                id,
-                entry.year,
-                entry.beginning_book_value,
-                entry.depreciation_expense,
-                entry.accumulated_depreciation,
-                entry.ending_book_value,
-            ],
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn delete_usage(db: State<Database>, id: i64) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT a.* FROM assets a JOIN asset_usage u ON u.asset_id = a.id WHERE u.id = ?1",
+            [id],
+            asset_from_row,
         )
+        .map_err(|_| "Usage entry not found")?;
+
+    conn.execute("DELETE FROM asset_usage WHERE id = ?1", [id])
         .map_err(map_err)?;
+
+    // Recompute the schedule without the removed usage
+    save_schedule(&conn, &asset)?;
+
+    Ok(())
+}
+
+// ============ Business Use ============
+
+#[tauri::command]
+pub fn get_business_use(db: State<Database>, asset_id: i64) -> Result<Vec<BusinessUseEntry>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_business_use_for_asset(&conn, asset_id)
+}
+
+#[tauri::command]
+pub fn record_business_use(db: State<Database>, entry: BusinessUseEntry) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [entry.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    if asset.business_use_percentage.is_none() {
+        return Err("Business use can only be recorded for listed property".to_string());
     }
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    validation::validate_business_use(&entry, &asset.date_placed_in_service, fiscal)
+        .map_err(map_err)?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    // One entry per asset and year; recording a year again replaces it
+    tx.execute(
+        "INSERT INTO business_use (asset_id, year, percentage, notes) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(asset_id, year) DO UPDATE SET percentage = excluded.percentage, notes = excluded.notes",
+        params![
+            entry.asset_id,
+            entry.year,
+            entry.percentage,
+            entry.notes.as_ref().map(|s| s.trim()),
+        ],
+    )
+    .map_err(map_err)?;
+
+    let id = tx
+        .query_row(
+            "SELECT id FROM business_use WHERE asset_id = ?1 AND year = ?2",
+            params![entry.asset_id, entry.year],
+            |row| row.get(0),
+        )
+        .map_err(map_err)?;
+
+    // Recompute the schedule at the recorded business use
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn delete_business_use(db: State<Database>, id: i64) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT a.* FROM assets a JOIN business_use b ON b.asset_id = a.id WHERE b.id = ?1",
+            [id],
+            asset_from_row,
+        )
+        .map_err(|_| "Business use entry not found")?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    tx.execute("DELETE FROM business_use WHERE id = ?1", [id])
+        .map_err(map_err)?;
+
+    // Recompute the schedule without the removed business use
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)?;
+    Ok(())
+}
+
+// ============ Books ============
+
+#[tauri::command]
+pub fn get_asset_books(db: State<Database>, asset_id: i64) -> Result<Vec<AssetBook>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_books_for_asset(&conn, asset_id)
+}
+
+#[tauri::command]
+pub fn save_asset_book(db: State<Database>, book: AssetBook) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [book.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    validation::validate_asset_book(&book, &asset).map_err(map_err)?;
+
+    conn.execute(
+        "INSERT INTO asset_books (asset_id, book, method, convention, useful_life_years, property_class, section_179_amount, bonus_percentage)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT(asset_id, book) DO UPDATE SET
+            method = excluded.method, convention = excluded.convention,
+            useful_life_years = excluded.useful_life_years, property_class = excluded.property_class,
+            section_179_amount = excluded.section_179_amount, bonus_percentage = excluded.bonus_percentage",
+        params![
+            book.asset_id,
+            book.book,
+            book.method,
+            book.convention,
+            book.useful_life_years,
+            book.property_class,
+            book.section_179_amount,
+            book.bonus_percentage,
+        ],
+    )
+    .map_err(map_err)?;
+
+    save_schedule(&conn, &asset)?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_asset_book(db: State<Database>, asset_id: i64, book: String) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    conn.execute(
+        "DELETE FROM asset_books WHERE asset_id = ?1 AND book = ?2",
+        params![asset_id, book],
+    )
+    .map_err(map_err)?;
+
+    // Drops the book's schedule along with the settings
+    save_schedule(&conn, &asset)?;
 
     Ok(())
 }
+
+// ============ Changes in Estimate ============
+
+#[tauri::command]
+pub fn get_estimate_changes(db: State<Database>, asset_id: i64) -> Result<Vec<EstimateChange>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_estimate_changes_for_asset(&conn, asset_id)
+}
+
+/// Revise an asset's useful life and salvage value from an effective date
+/// on. The financial book's earlier years stand and the rest of the book
+/// value is spread over the remaining life; editing the asset instead
+/// restates the whole schedule. The estimate replaced is recorded with the
+/// change.
+#[tauri::command]
+pub fn revise_estimate(db: State<Database>, change: EstimateChange) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [change.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    let changes = get_estimate_changes_for_asset(&conn, change.asset_id)?;
+    let latest = changes.last();
+    validation::validate_estimate_change(&change, &asset, latest).map_err(map_err)?;
+    if !get_components_for_asset(&conn, change.asset_id)?.is_empty() {
+        return Err("Revise a componentized asset through its components".to_string());
+    }
+
+    let (previous_life, previous_salvage) = latest
+        .map(|latest| (latest.useful_life_years, latest.salvage_value))
+        .unwrap_or((asset.useful_life_years, asset.salvage_value));
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    tx.execute(
+        "INSERT INTO estimate_changes (asset_id, effective_date, useful_life_years, salvage_value,
+            previous_useful_life_years, previous_salvage_value, reason)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            change.asset_id,
+            change.effective_date.trim(),
+            change.useful_life_years,
+            change.salvage_value,
+            previous_life,
+            previous_salvage,
+            change
+                .reason
+                .as_ref()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty()),
+        ],
+    )
+    .map_err(map_err)?;
+    let id = tx.last_insert_rowid();
+
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)?;
+    Ok(id)
+}
+
+// ============ Impairments ============
+
+#[tauri::command]
+pub fn get_impairments(db: State<Database>, asset_id: i64) -> Result<Vec<Impairment>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_impairments_for_asset(&conn, asset_id)
+}
+
+/// Record an impairment loss against an asset. The financial book writes the
+/// carrying amount down in the loss's fiscal year, apart from depreciation,
+/// and depreciates only what is left from then on.
+#[tauri::command]
+pub fn record_impairment(db: State<Database>, impairment: Impairment) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [impairment.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    let impairments = get_impairments_for_asset(&conn, impairment.asset_id)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    let schedule = get_schedule_for_asset(&conn, impairment.asset_id, BOOK_FINANCIAL)?;
+    let carrying_amount = fiscal
+        .year_of(impairment.impairment_date.trim())
+        .map(|year| current_book_value(&asset, &schedule, year))
+        .unwrap_or(asset.cost);
+    validation::validate_impairment(&impairment, &asset, impairments.last(), carrying_amount)
+        .map_err(map_err)?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    tx.execute(
+        "INSERT INTO impairments (asset_id, impairment_date, amount, reason)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            impairment.asset_id,
+            impairment.impairment_date.trim(),
+            impairment.amount,
+            impairment
+                .reason
+                .as_ref()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty()),
+        ],
+    )
+    .map_err(map_err)?;
+    let id = tx.last_insert_rowid();
+
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)?;
+    Ok(id)
+}
+
+// ============ Revaluations ============
+
+/// An asset's revaluations, with the carrying amount each replaced and how
+/// the change was posted
+#[tauri::command]
+pub fn get_revaluations(db: State<Database>, asset_id: i64) -> Result<Vec<Revaluation>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let revaluations = get_revaluations_for_asset(&conn, asset_id)?;
+    let schedule = get_schedule_for_asset(&conn, asset_id, BOOK_FINANCIAL)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    Ok(post_revaluations(&revaluations, &schedule, fiscal))
+}
+
+/// Revalue an asset in a category on the revaluation model to its fair
+/// value. The financial book restates the carrying amount at the end of the
+/// revaluation's fiscal year and depreciates the revalued amount from then on.
+#[tauri::command]
+pub fn record_revaluation(db: State<Database>, revaluation: Revaluation) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [revaluation.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    let revaluation_model = match asset.category_id {
+        Some(category_id) => category_revaluation_model(&conn, category_id)
+            .map_err(map_err)?
+            .ok_or("Category not found")?,
+        None => false,
+    };
+    if !revaluation_model {
+        return Err("The asset's category doesn't use the revaluation model".to_string());
+    }
+
+    let revaluations = get_revaluations_for_asset(&conn, revaluation.asset_id)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    validation::validate_revaluation(&revaluation, &asset, revaluations.last(), fiscal)
+        .map_err(map_err)?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    tx.execute(
+        "INSERT INTO revaluations (asset_id, revaluation_date, fair_value, reason)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            revaluation.asset_id,
+            revaluation.revaluation_date.trim(),
+            revaluation.fair_value,
+            revaluation
+                .reason
+                .as_ref()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty()),
+        ],
+    )
+    .map_err(map_err)?;
+    let id = tx.last_insert_rowid();
+
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)?;
+    Ok(id)
+}
+
+// ============ Components ============
+
+/// An asset's components with their financial book schedules, the rest of
+/// the asset first when the components don't make up its whole cost
+#[tauri::command]
+pub fn get_asset_components(
+    db: State<Database>,
+    asset_id: i64,
+) -> Result<Vec<ComponentWithSchedule>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+    let components = get_components_for_asset(&conn, asset_id)?;
+    if components.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let usage = get_usage_for_asset(&conn, asset_id)?;
+    let business_use = get_business_use_for_asset(&conn, asset_id)?;
+    let impairments = get_impairments_for_asset(&conn, asset_id)?;
+    let revaluations = get_revaluations_for_asset(&conn, asset_id)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    let parts = component_schedules(
+        &financial_book(&asset),
+        &components,
+        &usage,
+        &business_use,
+        &impairments,
+        &revaluations,
+        fiscal,
+    );
+
+    // The parts come back in the same order, after the rest of the asset
+    let mut parts = parts.into_iter();
+    let mut result = Vec::new();
+    if parts.len() > components.len() {
+        if let Some((rest, schedule)) = parts.next() {
+            let component = AssetComponent {
+                id: None,
+                asset_id,
+                name: rest.name,
+                cost: rest.cost,
+                salvage_value: rest.salvage_value,
+                useful_life_years: rest.useful_life_years,
+                method: rest.method,
+                convention: rest.convention,
+            };
+            result.push(ComponentWithSchedule {
+                component,
+                schedule,
+            });
+        }
+    }
+    for (component, (_, schedule)) in components.into_iter().zip(parts) {
+        result.push(ComponentWithSchedule {
+            component,
+            schedule,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Add a component to an asset, or update it when it has an ID, and
+/// regenerate the asset's schedule
+#[tauri::command]
+pub fn save_asset_component(db: State<Database>, component: AssetComponent) -> Result<i64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [component.asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Asset not found")?;
+
+    let others = get_components_for_asset(&conn, component.asset_id)?;
+    validation::validate_component(&component, &asset, &others).map_err(map_err)?;
+    if !get_estimate_changes_for_asset(&conn, component.asset_id)?.is_empty() {
+        return Err("An asset with changes in estimate can't be split into components".to_string());
+    }
+
+    let name = component.name.trim();
+    let id = if let Some(id) = component.id {
+        conn.execute(
+            "UPDATE asset_components SET name = ?1, cost = ?2, salvage_value = ?3,
+                useful_life_years = ?4, method = ?5, convention = ?6
+             WHERE id = ?7 AND asset_id = ?8",
+            params![
+                name,
+                component.cost,
+                component.salvage_value,
+                component.useful_life_years,
+                component.method,
+                component.convention,
+                id,
+                component.asset_id,
+            ],
+        )
+        .map_err(map_err)?;
+        id
+    } else {
+        conn.execute(
+            "INSERT INTO asset_components (asset_id, name, cost, salvage_value,
+                useful_life_years, method, convention)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                component.asset_id,
+                name,
+                component.cost,
+                component.salvage_value,
+                component.useful_life_years,
+                component.method,
+                component.convention,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.last_insert_rowid()
+    };
+
+    save_schedule(&conn, &asset)?;
+
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn delete_asset_component(db: State<Database>, id: i64) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    let asset = conn
+        .query_row(
+            "SELECT a.* FROM assets a
+             JOIN asset_components c ON c.asset_id = a.id
+             WHERE c.id = ?1",
+            [id],
+            asset_from_row,
+        )
+        .map_err(|_| "Component not found")?;
+
+    conn.execute("DELETE FROM asset_components WHERE id = ?1", [id])
+        .map_err(map_err)?;
+
+    // Its cost goes back to the rest of the asset
+    save_schedule(&conn, &asset)?;
+
+    Ok(())
+}
+
+// ============ Leases ============
+
+#[tauri::command]
+pub fn get_leases(db: State<Database>) -> Result<Vec<Lease>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    get_all_leases(&conn)
+}
+
+#[tauri::command]
+pub fn get_lease(db: State<Database>, id: i64) -> Result<LeaseWithSchedule> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let lease = conn
+        .query_row("SELECT * FROM leases WHERE id = ?1", [id], lease_from_row)
+        .map_err(|_| "Lease not found")?;
+    let asset_id = lease.asset_id.ok_or("Lease has no right-of-use asset")?;
+    let asset = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Right-of-use asset not found")?;
+
+    let schedule = get_schedule_for_asset(&conn, asset_id, BOOK_FINANCIAL)?;
+    let liability = liability_schedule(&lease, fiscal);
+
+    Ok(LeaseWithSchedule {
+        lease,
+        asset,
+        schedule,
+        liability,
+    })
+}
+
+/// Record a lease and put its right-of-use asset on the register
+#[tauri::command]
+pub fn create_lease(db: State<Database>, lease: Lease) -> Result<i64> {
+    validation::validate_lease(&lease).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    // The lease and its asset are recorded together or not at all
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    let mut asset = right_of_use_asset(&lease);
+    tx.execute(
+        "INSERT INTO assets (name, description, date_placed_in_service, cost, salvage_value, useful_life_years, method, convention)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            asset.name,
+            asset.description,
+            asset.date_placed_in_service,
+            asset.cost,
+            asset.salvage_value,
+            asset.useful_life_years,
+            asset.method,
+            asset.convention,
+        ],
+    )
+    .map_err(map_err)?;
+    asset.id = Some(tx.last_insert_rowid());
+    save_schedule(&tx, &asset)?;
+
+    tx.execute(
+        "INSERT INTO leases (asset_id, name, commencement_date, term_years, monthly_payment, payments_in_advance, discount_rate, initial_direct_costs, notes)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            asset.id,
+            lease.name.trim(),
+            lease.commencement_date.trim(),
+            lease.term_years,
+            lease.monthly_payment,
+            lease.payments_in_advance,
+            lease.discount_rate,
+            lease.initial_direct_costs,
+            lease.notes.as_ref().map(|s| s.trim()),
+        ],
+    )
+    .map_err(map_err)?;
+    let id = tx.last_insert_rowid();
+
+    tx.commit().map_err(map_err)?;
+    Ok(id)
+}
+
+/// Update a lease's terms. The right-of-use asset is remeasured from them
+/// and its schedule regenerated; its category and notes are kept.
+#[tauri::command]
+pub fn update_lease(db: State<Database>, lease: Lease) -> Result<()> {
+    validation::validate_lease(&lease).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    let id = lease.id.ok_or("Lease ID required")?;
+
+    let asset_id: i64 = conn
+        .query_row("SELECT asset_id FROM leases WHERE id = ?1", [id], |row| {
+            row.get(0)
+        })
+        .map_err(|_| "Lease not found")?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    let terms = right_of_use_asset(&lease);
+    tx.execute(
+        "UPDATE assets SET
+            name = ?1, date_placed_in_service = ?2, cost = ?3, salvage_value = ?4,
+            useful_life_years = ?5, method = ?6, convention = ?7, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?8",
+        params![
+            terms.name,
+            terms.date_placed_in_service,
+            terms.cost,
+            terms.salvage_value,
+            terms.useful_life_years,
+            terms.method,
+            terms.convention,
+            asset_id,
+        ],
+    )
+    .map_err(map_err)?;
+
+    tx.execute(
+        "UPDATE leases SET
+            name = ?1, commencement_date = ?2, term_years = ?3, monthly_payment = ?4,
+            payments_in_advance = ?5, discount_rate = ?6, initial_direct_costs = ?7,
+            notes = ?8, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?9",
+        params![
+            lease.name.trim(),
+            lease.commencement_date.trim(),
+            lease.term_years,
+            lease.monthly_payment,
+            lease.payments_in_advance,
+            lease.discount_rate,
+            lease.initial_direct_costs,
+            lease.notes.as_ref().map(|s| s.trim()),
+            id,
+        ],
+    )
+    .map_err(map_err)?;
+
+    let asset = tx
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [asset_id],
+            asset_from_row,
+        )
+        .map_err(|_| "Right-of-use asset not found")?;
+    save_schedule(&tx, &asset)?;
+
+    tx.commit().map_err(map_err)
+}
+
+/// Delete a lease together with its right-of-use asset
+#[tauri::command]
+pub fn delete_lease(db: State<Database>, id: i64) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let asset_id: i64 = conn
+        .query_row("SELECT asset_id FROM leases WHERE id = ?1", [id], |row| {
+            row.get(0)
+        })
+        .map_err(|_| "Lease not found")?;
+
+    let tx = conn.unchecked_transaction().map_err(map_err)?;
+    remove_asset(&tx, asset_id)?;
+    tx.commit().map_err(map_err)
+}
+
+// ============ Annual Summary ============
+
+#[tauri::command]
+pub fn get_annual_summary(db: State<Database>, book: Option<String>) -> Result<Vec<AnnualSummary>> {
+    let book = selected_book(book)?;
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    // Schedules stop at disposal, so every stored year counts. Intangibles
+    // are amortized, and reported apart from depreciation.
+    let mut stmt = conn
+        .prepare(
+            "SELECT ds.year,
+                    SUM(CASE WHEN a.asset_kind = ?2 THEN 0 ELSE ds.depreciation_expense END) as total,
+                    SUM(CASE WHEN a.asset_kind = ?2 THEN ds.depreciation_expense ELSE 0 END) as amortization,
+                    COUNT(DISTINCT ds.asset_id) as count
+             FROM depreciation_schedule ds
+             JOIN assets a ON ds.asset_id = a.id
+             WHERE ds.book = ?1
+             GROUP BY ds.year
+             ORDER BY ds.year",
+        )
+        .map_err(map_err)?;
+
+    let summaries = stmt
+        .query_map(params![book, KIND_INTANGIBLE], |row| {
+            Ok(AnnualSummary {
+                year: row.get(0)?,
+                total_depreciation: row.get(1)?,
+                total_amortization: row.get(2)?,
+                asset_count: row.get(3)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(summaries)
+}
+
+// ============ Periods ============
+
+#[tauri::command]
+pub fn get_period_summary(
+    db: State<Database>,
+    book: Option<String>,
+    period: Option<String>,
+) -> Result<Vec<PeriodSummary>> {
+    let book = selected_book(book)?;
+    let period = period.unwrap_or_else(|| PERIOD_MONTH.to_string());
+    validation::validate_period(&period).map_err(map_err)?;
+    let months: u32 = if period == PERIOD_QUARTER { 3 } else { 1 };
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT dp.year, (dp.period - 1) / ?2 + 1 as p, SUM(dp.depreciation_expense) as total,
+                    COUNT(DISTINCT dp.asset_id) as count
+             FROM depreciation_periods dp
+             WHERE dp.book = ?1
+             GROUP BY dp.year, p
+             ORDER BY dp.year, p",
+        )
+        .map_err(map_err)?;
+
+    let summaries = stmt
+        .query_map(params![book, months], |row| {
+            let year: i32 = row.get(0)?;
+            let period: u32 = row.get(1)?;
+            Ok(PeriodSummary {
+                year,
+                period,
+                period_start: fiscal.period_start(year, (period - 1) * months + 1),
+                total_depreciation: row.get(2)?,
+                asset_count: row.get(3)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(summaries)
+}
+
+#[tauri::command]
+pub fn get_depreciation_for_range(
+    db: State<Database>,
+    start_date: String,
+    end_date: String,
+    book: Option<String>,
+) -> Result<DepreciationRange> {
+    let book = selected_book(book)?;
+    validation::validate_date_range(&start_date, &end_date).map_err(map_err)?;
+    let start_date = start_date.trim().to_string();
+    let end_date = end_date.trim().to_string();
+
+    // Whole months: every month starting from the start date's month
+    // through the end date
+    let (first_month, last_date) =
+        month_range(&start_date, &end_date).ok_or("Invalid date range")?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT a.id, a.name, SUM(dp.depreciation_expense)
+             FROM depreciation_periods dp
+             JOIN assets a ON dp.asset_id = a.id
+             WHERE dp.book = ?1 AND dp.period_start >= ?2 AND dp.period_start <= ?3
+             GROUP BY a.id
+             ORDER BY a.name",
+        )
+        .map_err(map_err)?;
+
+    let assets: Vec<AssetDepreciation> = stmt
+        .query_map(params![book, first_month, last_date], |row| {
+            Ok(AssetDepreciation {
+                asset_id: row.get(0)?,
+                name: row.get(1)?,
+                depreciation_expense: row.get(2)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let total_depreciation = assets.iter().map(|a| a.depreciation_expense).sum();
+
+    Ok(DepreciationRange {
+        start_date,
+        end_date,
+        book,
+        total_depreciation,
+        assets,
+    })
+}
+
+// ============ Disposals ============
+
+#[tauri::command]
+pub fn get_disposal(db: State<Database>, asset_id: i64) -> Result<Option<Disposal>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    Ok(get_disposals(&conn, Some(asset_id))?.into_iter().next())
+}
+
+#[tauri::command]
+pub fn get_disposal_report(db: State<Database>, year: i32) -> Result<DisposalReport> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let disposals: Vec<Disposal> = get_disposals(&conn, None)?
+        .into_iter()
+        .filter(|disposal| fiscal.year_of(&disposal.disposed_date) == Some(year))
+        .collect();
+
+    Ok(DisposalReport {
+        year,
+        total_proceeds: disposals.iter().map(|d| d.proceeds).sum(),
+        total_book_value: disposals.iter().map(|d| d.book_value).sum(),
+        total_gain_loss: disposals.iter().map(|d| d.gain_loss).sum(),
+        disposals,
+    })
+}
+
+#[tauri::command]
+pub fn get_recapture_report(
+    db: State<Database>,
+    year: i32,
+    tax_book: Option<String>,
+) -> Result<RecaptureReport> {
+    let tax_book = selected_tax_book(tax_book)?;
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT * FROM assets WHERE disposed_date IS NOT NULL ORDER BY disposed_date, name",
+        )
+        .map_err(map_err)?;
+
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .filter(|asset: &Asset| {
+            asset
+                .disposed_date
+                .as_deref()
+                .and_then(|date| fiscal.year_of(date))
+                == Some(year)
+        })
+        .collect();
+
+    let mut recaptures = Vec::new();
+    for asset in &assets {
+        // Improvements disposed of with their asset are recaptured as part of it
+        if disposed_parent(&conn, asset)?.is_some() {
+            continue;
+        }
+        let improvements = improvements_disposed_with(&conn, asset)?;
+        if improvements.is_empty() {
+            let schedule = tax_schedule_for_asset(&conn, asset, &tax_book)?;
+            recaptures.push(recapture(asset, &schedule));
+            continue;
+        }
+
+        let mut schedules = vec![(asset, tax_schedule_for_asset(&conn, asset, &tax_book)?)];
+        for improvement in &improvements {
+            schedules.push((
+                improvement,
+                tax_schedule_for_asset(&conn, improvement, &tax_book)?,
+            ));
+        }
+        let mut whole = asset.clone();
+        whole.cost += improvements.iter().map(|i| i.cost).sum::<Money>();
+        recaptures.push(recapture(&whole, &combine_schedules(&schedules)));
+    }
+
+    Ok(RecaptureReport {
+        year,
+        tax_book,
+        total_gain_loss: recaptures.iter().map(|r| r.gain_loss).sum(),
+        total_ordinary_income: recaptures.iter().map(|r| r.ordinary_income).sum(),
+        total_unrecaptured_1250: recaptures.iter().map(|r| r.unrecaptured_1250).sum(),
+        total_capital_gain: recaptures.iter().map(|r| r.capital_gain).sum(),
+        recaptures,
+    })
+}
+
+/// Listed property whose business use first dropped to 50% or less in the
+/// fiscal year while it was depreciated under MACRS GDS, with the excess
+/// depreciation over ADS that is recaptured
+#[tauri::command]
+pub fn get_listed_property_report(
+    db: State<Database>,
+    year: i32,
+    tax_book: Option<String>,
+) -> Result<ListedPropertyReport> {
+    let tax_book = selected_tax_book(tax_book)?;
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut stmt = conn
+        .prepare("SELECT * FROM assets WHERE business_use_percentage IS NOT NULL ORDER BY name")
+        .map_err(map_err)?;
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut recaptures = Vec::new();
+    for asset in &assets {
+        let id = asset.id.unwrap();
+        let business_use = get_business_use_for_asset(&conn, id)?;
+        let Some(dropped) = business_use.iter().find(|entry| entry.percentage <= 50.0) else {
+            continue;
+        };
+        if dropped.year != year {
+            continue;
+        }
+
+        // Without settings of its own the tax book follows the asset's
+        let tax_asset = match get_books_for_asset(&conn, id)?
+            .into_iter()
+            .find(|book| book.book == tax_book)
+        {
+            Some(book) => apply_book(asset, &book),
+            None => asset.clone(),
+        };
+        if tax_asset.method.as_deref() != Some(METHOD_MACRS_GDS) {
+            continue;
+        }
+        let schedule = tax_schedule_for_asset(&conn, asset, &tax_book)?;
+
+        let (depreciation_taken, ads_depreciation) =
+            excess_depreciation(&tax_asset, &schedule, &business_use, year, fiscal);
+        recaptures.push(ListedPropertyRecapture {
+            asset_id: id,
+            name: asset.name.clone(),
+            placed_in_service_use: asset.business_use_percentage.unwrap_or(100.0),
+            business_use: dropped.percentage,
+            depreciation_taken,
+            ads_depreciation,
+            recapture: (depreciation_taken - ads_depreciation).max(Money::ZERO),
+        });
+    }
+
+    Ok(ListedPropertyReport {
+        year,
+        tax_book,
+        total_recapture: recaptures.iter().map(|r| r.recapture).sum(),
+        assets: recaptures,
+    })
+}
+
+#[tauri::command]
+pub fn get_impairment_report(db: State<Database>, year: i32) -> Result<ImpairmentReport> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT i.asset_id, a.name, i.impairment_date, i.amount, i.reason
+             FROM impairments i
+             JOIN assets a ON i.asset_id = a.id
+             ORDER BY i.impairment_date, a.name",
+        )
+        .map_err(map_err)?;
+
+    let rows: Vec<(i64, String, String, Money, Option<String>)> = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut impairments = Vec::new();
+    for (asset_id, name, impairment_date, amount, reason) in rows {
+        if fiscal.year_of(&impairment_date) != Some(year) {
+            continue;
+        }
+        let carrying_amount = get_schedule_for_asset(&conn, asset_id, BOOK_FINANCIAL)?
+            .iter()
+            .find(|entry| entry.year == year)
+            .map(|entry| entry.ending_book_value)
+            .unwrap_or(Money::ZERO);
+        impairments.push(ImpairmentReportEntry {
+            asset_id,
+            name,
+            impairment_date,
+            amount,
+            carrying_amount,
+            reason,
+        });
+    }
+
+    Ok(ImpairmentReport {
+        year,
+        total_amount: impairments.iter().map(|i| i.amount).sum(),
+        impairments,
+    })
+}
+
+/// Right-of-use assets and lease liabilities at the end of a fiscal year,
+/// with the year's depreciation, interest and payments, for each lease in
+/// effect during it
+#[tauri::command]
+pub fn get_lease_report(db: State<Database>, year: i32) -> Result<LeaseReport> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut leases = Vec::new();
+    for lease in get_all_leases(&conn)? {
+        let (Some(lease_id), Some(asset_id)) = (lease.id, lease.asset_id) else {
+            continue;
+        };
+        let liability = liability_schedule(&lease, fiscal);
+        let schedule = get_schedule_for_asset(&conn, asset_id, BOOK_FINANCIAL)?;
+
+        let liability_entry = liability.iter().find(|entry| entry.year == year);
+        let asset_entry = schedule.iter().find(|entry| entry.year == year);
+        if liability_entry.is_none() && asset_entry.is_none() {
+            continue;
+        }
+
+        leases.push(LeaseReportEntry {
+            lease_id,
+            asset_id,
+            name: lease.name,
+            asset_depreciation: asset_entry.map_or(Money::ZERO, |e| e.depreciation_expense),
+            asset_book_value: asset_entry.map_or(Money::ZERO, |e| e.ending_book_value),
+            interest: liability_entry.map_or(Money::ZERO, |e| e.interest),
+            payments: liability_entry.map_or(Money::ZERO, |e| e.payments),
+            liability: liability_entry.map_or(Money::ZERO, |e| e.ending_balance),
+            current_liability: current_liability(&liability, year),
+        });
+    }
+
+    Ok(LeaseReport {
+        year,
+        total_asset_book_value: leases.iter().map(|l| l.asset_book_value).sum(),
+        total_liability: leases.iter().map(|l| l.liability).sum(),
+        total_current_liability: leases.iter().map(|l| l.current_liability).sum(),
+        leases,
+    })
+}
+
+/// Movements in the revaluation reserve over a fiscal year, for each asset
+/// that has been revalued. What is left of an asset's reserve is transferred
+/// to retained earnings in the year it is disposed of.
+#[tauri::command]
+pub fn get_revaluation_reserve_report(
+    db: State<Database>,
+    year: i32,
+) -> Result<RevaluationReserveReport> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT * FROM assets
+             WHERE id IN (SELECT asset_id FROM revaluations)
+             ORDER BY name",
+        )
+        .map_err(map_err)?;
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut entries = Vec::new();
+    for asset in assets {
+        let asset_id = asset.id.unwrap_or(0);
+        let disposal_year = asset
+            .disposed_date
+            .as_deref()
+            .and_then(|date| fiscal.year_of(date));
+        if disposal_year.is_some_and(|disposed| disposed < year) {
+            continue;
+        }
+
+        let schedule = get_schedule_for_asset(&conn, asset_id, BOOK_FINANCIAL)?;
+        let revaluations = get_revaluations_for_asset(&conn, asset_id)?;
+        let mut opening_balance = Money::ZERO;
+        let mut increase = Money::ZERO;
+        let mut decrease = Money::ZERO;
+        let mut profit_loss = Money::ZERO;
+        for revaluation in post_revaluations(&revaluations, &schedule, fiscal) {
+            let Some(revaluation_year) = fiscal.year_of(&revaluation.revaluation_date) else {
+                continue;
+            };
+            let reserve_change = revaluation.reserve_change.unwrap_or(Money::ZERO);
+            if revaluation_year < year {
+                opening_balance += reserve_change;
+            } else if revaluation_year == year {
+                if reserve_change >= Money::ZERO {
+                    increase += reserve_change;
+                } else {
+                    decrease -= reserve_change;
+                }
+                profit_loss += revaluation.profit_loss.unwrap_or(Money::ZERO);
+            }
+        }
+
+        let transferred = if disposal_year == Some(year) {
+            opening_balance + increase - decrease
+        } else {
+            Money::ZERO
+        };
+        if [opening_balance, increase, decrease, profit_loss]
+            .iter()
+            .all(|amount| *amount == Money::ZERO)
+        {
+            continue;
+        }
+
+        entries.push(RevaluationReserveEntry {
+            asset_id,
+            name: asset.name,
+            opening_balance,
+            increase,
+            decrease,
+            transferred,
+            closing_balance: opening_balance + increase - decrease - transferred,
+            profit_loss,
+        });
+    }
+
+    Ok(RevaluationReserveReport {
+        year,
+        total_opening_balance: entries.iter().map(|e| e.opening_balance).sum(),
+        total_closing_balance: entries.iter().map(|e| e.closing_balance).sum(),
+        total_profit_loss: entries.iter().map(|e| e.profit_loss).sum(),
+        entries,
+    })
+}
+
+// ============ Section 179 ============
+
+#[tauri::command]
+pub fn get_section_179_limits(db: State<Database>) -> Result<Vec<Section179Limit>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT year, dollar_limit, phase_out_threshold FROM section_179_limits ORDER BY year",
+        )
+        .map_err(map_err)?;
+
+    let limits = stmt
+        .query_map([], |row| {
+            Ok(Section179Limit {
+                year: row.get(0)?,
+                dollar_limit: row.get(1)?,
+                phase_out_threshold: row.get(2)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(limits)
+}
+
+#[tauri::command]
+pub fn update_section_179_limit(db: State<Database>, limit: Section179Limit) -> Result<()> {
+    // Validate before saving
+    validation::validate_section_179_limit(&limit).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    conn.execute(
+        "INSERT INTO section_179_limits (year, dollar_limit, phase_out_threshold) VALUES (?1, ?2, ?3)
+         ON CONFLICT(year) DO UPDATE SET dollar_limit = excluded.dollar_limit, phase_out_threshold = excluded.phase_out_threshold",
+        params![limit.year, limit.dollar_limit, limit.phase_out_threshold],
+    )
+    .map_err(map_err)?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_section_179_report(db: State<Database>, year: i32) -> Result<Section179Report> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    // Years without their own limits use the most recent earlier year's
+    let (dollar_limit, phase_out_threshold): (Money, Money) = conn
+        .query_row(
+            "SELECT dollar_limit, phase_out_threshold FROM section_179_limits
+             WHERE year <= ?1 ORDER BY year DESC LIMIT 1",
+            [year],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|_| format!("No Section 179 limit configured for {}", year))?;
+
+    // Assets placed in service during the fiscal (tax) year
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    // Leased right-of-use assets weren't purchased, so they aren't among them
+    let mut stmt = conn
+        .prepare(
+            "SELECT * FROM assets WHERE id NOT IN (SELECT asset_id FROM leases)
+             ORDER BY date_placed_in_service, name",
+        )
+        .map_err(map_err)?;
+
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .filter(|a| fiscal.year_of(&a.date_placed_in_service) == Some(year))
+        .collect();
+
+    // Real property, intangibles and listed property used 50% or less for
+    // business aren't section 179 property, so they don't count toward the
+    // phase-out, and only the business use of the rest does
+    let qualifying_cost: Money = assets
+        .iter()
+        .filter(|a| !is_real_property(a) && !is_intangible(a) && business_use_percentage(a) > 50.0)
+        .map(|a| a.cost.percent(business_use_percentage(a)))
+        .sum();
+
+    // The election is the federal book's where it has settings of its own
+    let mut federal_assets = Vec::new();
+    for asset in &assets {
+        let id = asset.id.unwrap_or(0);
+        federal_assets.push(
+            match get_books_for_asset(&conn, id)?
+                .into_iter()
+                .find(|book| book.book == BOOK_FEDERAL)
+            {
+                Some(book) => apply_book(asset, &book),
+                None => asset.clone(),
+            },
+        );
+    }
+
+    let elections: Vec<Section179Election> = federal_assets
+        .iter()
+        .filter(|a| section_179_deduction(a) > Money::ZERO)
+        .map(|a| Section179Election {
+            asset_id: a.id.unwrap_or(0),
+            name: a.name.clone(),
+            date_placed_in_service: a.date_placed_in_service.clone(),
+            cost: a.cost,
+            amount: section_179_deduction(a),
+        })
+        .collect();
+
+    let total_elected: Money = elections.iter().map(|e| e.amount).sum();
+    let allowed_limit =
+        section_179_allowed_limit(dollar_limit, phase_out_threshold, qualifying_cost);
+
+    Ok(Section179Report {
+        year,
+        dollar_limit,
+        phase_out_threshold,
+        qualifying_cost,
+        allowed_limit,
+        total_elected,
+        excess: (total_elected - allowed_limit).max(Money::ZERO),
+        elections,
+    })
+}
+
+// ============ Bonus Depreciation ============
+
+#[tauri::command]
+pub fn get_bonus_opt_outs(db: State<Database>) -> Result<Vec<BonusOptOut>> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let mut stmt = conn
+        .prepare("SELECT year, property_class FROM bonus_opt_outs ORDER BY year, property_class")
+        .map_err(map_err)?;
+
+    let opt_outs = stmt
+        .query_map([], |row| {
+            Ok(BonusOptOut {
+                year: row.get(0)?,
+                property_class: row.get(1)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(opt_outs)
+}
+
+#[tauri::command]
+pub fn set_bonus_opt_out(
+    db: State<Database>,
+    year: i32,
+    property_class: String,
+    opted_out: bool,
+) -> Result<()> {
+    validation::validate_bonus_opt_out(year, &property_class).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    let property_class = property_class.trim();
+
+    if opted_out {
+        conn.execute(
+            "INSERT OR IGNORE INTO bonus_opt_outs (year, property_class) VALUES (?1, ?2)",
+            params![year, property_class],
+        )
+        .map_err(map_err)?;
+    } else {
+        conn.execute(
+            "DELETE FROM bonus_opt_outs WHERE year = ?1 AND property_class = ?2",
+            params![year, property_class],
+        )
+        .map_err(map_err)?;
+    }
+
+    // Regenerate schedules for the class's assets placed in service that fiscal year
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    let mut stmt = conn
+        .prepare("SELECT * FROM assets WHERE TRIM(property_class) = ?1")
+        .map_err(map_err)?;
+
+    let assets: Vec<Asset> = stmt
+        .query_map([property_class], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .filter(|a| fiscal.year_of(&a.date_placed_in_service) == Some(year))
+        .collect();
+
+    for asset in &assets {
+        save_schedule(&conn, asset)?;
+    }
+
+    Ok(())
+}
+
+// ============ Fiscal Year ============
+
+#[tauri::command]
+pub fn get_fiscal_year(db: State<Database>) -> Result<FiscalYearSettings> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    Ok(FiscalYearSettings {
+        end_month: fiscal.end_month(),
+        current_year: fiscal.current_year(),
+    })
+}
+
+#[tauri::command]
+pub fn set_fiscal_year_end(db: State<Database>, end_month: u32) -> Result<()> {
+    // Validate before saving
+    validation::validate_fiscal_year_end(end_month).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    set_setting(&conn, "fiscal_year_end_month", &end_month.to_string()).map_err(map_err)?;
+
+    // Every schedule is laid out in fiscal years, so regenerate them all
+    regenerate_all_schedules(&conn)
+}
+
+// ============ Book-Tax Differences ============
+
+#[tauri::command]
+pub fn get_deferred_tax_rate(db: State<Database>) -> Result<f64> {
+    let conn = db.conn.lock().map_err(map_err)?;
+    deferred_tax_rate(&conn).map_err(map_err)
+}
+
+#[tauri::command]
+pub fn set_deferred_tax_rate(db: State<Database>, rate: f64) -> Result<()> {
+    // Validate before saving
+    validation::validate_tax_rate(rate).map_err(map_err)?;
+
+    let conn = db.conn.lock().map_err(map_err)?;
+    set_setting(&conn, "deferred_tax_rate", &rate.to_string()).map_err(map_err)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_book_tax_report(
+    db: State<Database>,
+    year: i32,
+    tax_book: Option<String>,
+) -> Result<BookTaxReport> {
+    let tax_book = selected_tax_book(tax_book)?;
+    let conn = db.conn.lock().map_err(map_err)?;
+    let tax_rate = deferred_tax_rate(&conn).map_err(map_err)?;
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+
+    let mut differences = Vec::new();
+    for (asset, book, tax) in book_tax_schedules(&conn, &tax_book)? {
+        if in_service_during(&asset, year, fiscal) {
+            differences.push(book_tax_difference(
+                &asset, &book, &tax, year, tax_rate, fiscal,
+            ));
+        }
+    }
+
+    let total_difference: Money = differences.iter().map(|d| d.temporary_difference).sum();
+    let total_deferred_tax: Money = differences.iter().map(|d| d.deferred_tax).sum();
+
+    Ok(BookTaxReport {
+        year,
+        tax_book,
+        tax_rate,
+        total_difference,
+        total_deferred_tax,
+        differences,
+    })
+}
+
+// ============ Helper Functions ============
+
+/// The book a selector asked for, defaulting to the financial book
+pub(crate) fn selected_book(book: Option<String>) -> Result<String> {
+    let book = book.unwrap_or_else(|| BOOK_FINANCIAL.to_string());
+    validation::validate_book(&book).map_err(map_err)?;
+    Ok(book)
+}
+
+/// The tax book a book-to-tax comparison asked for, defaulting to federal
+pub(crate) fn selected_tax_book(book: Option<String>) -> Result<String> {
+    let book = selected_book(Some(book.unwrap_or_else(|| BOOK_FEDERAL.to_string())))?;
+    if book == BOOK_FINANCIAL {
+        return Err("Choose a tax book to compare against the financial book".to_string());
+    }
+    Ok(book)
+}
+
+/// Whether the asset was in service at some point during fiscal `year`
+pub(crate) fn in_service_during(asset: &Asset, year: i32, fiscal: FiscalYear) -> bool {
+    fiscal
+        .year_of(&asset.date_placed_in_service)
+        .is_some_and(|placed| placed <= year)
+        && asset
+            .disposed_date
+            .as_deref()
+            .and_then(|date| fiscal.year_of(date))
+            .is_none_or(|disposed| disposed >= year)
+}
+
+/// An asset with its financial and tax book schedules
+pub(crate) type BookTaxSchedules = (Asset, Vec<DepreciationEntry>, Vec<DepreciationEntry>);
+
+/// Every asset alongside its financial book schedule and its schedule in
+/// `tax_book`, including assets without settings of their own in that book
+pub(crate) fn book_tax_schedules(
+    conn: &rusqlite::Connection,
+    tax_book: &str,
+) -> Result<Vec<BookTaxSchedules>> {
+    let mut stmt = conn
+        .prepare("SELECT * FROM assets ORDER BY name")
+        .map_err(map_err)?;
+
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut result = Vec::new();
+    for asset in assets {
+        let id = asset.id.unwrap();
+        let book = get_schedule_for_asset(conn, id, BOOK_FINANCIAL)?;
+        let tax = tax_schedule_for_asset(conn, &asset, tax_book)?;
+        result.push((asset, book, tax));
+    }
+
+    Ok(result)
+}
+
+/// One asset's book-to-tax comparison for `year`
+pub(crate) fn book_tax_difference(
+    asset: &Asset,
+    book: &[DepreciationEntry],
+    tax: &[DepreciationEntry],
+    year: i32,
+    tax_rate: f64,
+    fiscal: FiscalYear,
+) -> BookTaxDifference {
+    let difference = temporary_difference(asset, book, tax, year);
+    BookTaxDifference {
+        asset_id: asset.id.unwrap_or(0),
+        name: asset.name.clone(),
+        book_depreciation: depreciation_for_year(asset, book, year, fiscal),
+        tax_depreciation: depreciation_for_year(asset, tax, year, fiscal),
+        book_value: current_book_value(asset, book, year),
+        tax_basis: current_book_value(asset, tax, year),
+        temporary_difference: difference,
+        deferred_tax: deferred_tax(difference, tax_rate),
+    }
+}
+
+pub(crate) fn get_schedule_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    book: &str,
+) -> Result<Vec<DepreciationEntry>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, year, beginning_book_value, depreciation_expense,
+                    impairment_loss, revaluation_adjustment, accumulated_depreciation,
+                    ending_book_value
+             FROM depreciation_schedule
+             WHERE asset_id = ?1 AND book = ?2
+             ORDER BY year",
+        )
+        .map_err(map_err)?;
+
+    let entries = stmt
+        .query_map(params![asset_id, book], |row| {
+            Ok(DepreciationEntry {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                year: row.get(2)?,
+                beginning_book_value: row.get(3)?,
+                depreciation_expense: row.get(4)?,
+                impairment_loss: row.get(5)?,
+                revaluation_adjustment: row.get(6)?,
+                accumulated_depreciation: row.get(7)?,
+                ending_book_value: row.get(8)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(entries)
+}
+
+/// Capital improvements and additions to an asset, in the order they went
+/// into service
+pub(crate) fn get_improvements(conn: &rusqlite::Connection, asset_id: i64) -> Result<Vec<Asset>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT * FROM assets WHERE improved_asset_id = ?1
+             ORDER BY date_placed_in_service, id",
+        )
+        .map_err(map_err)?;
+
+    let improvements = stmt
+        .query_map([asset_id], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(improvements)
+}
+
+/// An asset's schedule in `book`, combined with its improvements' schedules
+pub(crate) fn rolled_up_schedule(
+    conn: &rusqlite::Connection,
+    asset: &Asset,
+    improvements: &[Asset],
+    book: &str,
+) -> Result<Vec<DepreciationEntry>> {
+    let id = asset.id.ok_or("Asset ID required")?;
+    let schedule = get_schedule_for_asset(conn, id, book)?;
+    if improvements.is_empty() {
+        return Ok(schedule);
+    }
+
+    let mut schedules = vec![(asset, schedule)];
+    for improvement in improvements {
+        let improvement_id = improvement.id.ok_or("Asset ID required")?;
+        let schedule = get_schedule_for_asset(conn, improvement_id, book)?;
+        schedules.push((improvement, schedule));
+    }
+    Ok(combine_schedules(&schedules))
+}
+
+fn get_business_use_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+) -> Result<Vec<BusinessUseEntry>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, year, percentage, notes, created_at
+             FROM business_use
+             WHERE asset_id = ?1
+             ORDER BY year",
+        )
+        .map_err(map_err)?;
+
+    let entries = stmt
+        .query_map([asset_id], |row| {
+            Ok(BusinessUseEntry {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                year: row.get(2)?,
+                percentage: row.get(3)?,
+                notes: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(entries)
+}
+
+fn get_usage_for_asset(conn: &rusqlite::Connection, asset_id: i64) -> Result<Vec<UsageEntry>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, year, units, notes, created_at
+             FROM asset_usage
+             WHERE asset_id = ?1
+             ORDER BY year",
+        )
+        .map_err(map_err)?;
+
+    let entries = stmt
+        .query_map([asset_id], |row| {
+            Ok(UsageEntry {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                year: row.get(2)?,
+                units: row.get(3)?,
+                notes: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(entries)
+}
+
+/// An asset's impairments, in date order
+fn get_impairments_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+) -> Result<Vec<Impairment>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, impairment_date, amount, reason, created_at
+             FROM impairments
+             WHERE asset_id = ?1
+             ORDER BY impairment_date, id",
+        )
+        .map_err(map_err)?;
+
+    let impairments = stmt
+        .query_map([asset_id], |row| {
+            Ok(Impairment {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                impairment_date: row.get(2)?,
+                amount: row.get(3)?,
+                reason: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(impairments)
+}
+
+/// An asset's revaluations, in date order
+fn get_revaluations_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+) -> Result<Vec<Revaluation>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, revaluation_date, fair_value, reason, created_at
+             FROM revaluations
+             WHERE asset_id = ?1
+             ORDER BY revaluation_date, id",
+        )
+        .map_err(map_err)?;
+
+    let revaluations = stmt
+        .query_map([asset_id], |row| {
+            Ok(Revaluation {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                revaluation_date: row.get(2)?,
+                fair_value: row.get(3)?,
+                reason: row.get(4)?,
+                created_at: row.get(5)?,
+                carrying_amount: None,
+                reserve_change: None,
+                profit_loss: None,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(revaluations)
+}
+
+fn get_components_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+) -> Result<Vec<AssetComponent>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, name, cost, salvage_value, useful_life_years, method, convention
+             FROM asset_components
+             WHERE asset_id = ?1
+             ORDER BY id",
+        )
+        .map_err(map_err)?;
+
+    let components = stmt
+        .query_map([asset_id], |row| {
+            Ok(AssetComponent {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                name: row.get(2)?,
+                cost: row.get(3)?,
+                salvage_value: row.get(4)?,
+                useful_life_years: row.get(5)?,
+                method: row.get(6)?,
+                convention: row.get(7)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(components)
+}
+
+/// An asset's changes in estimate, in the order they take effect
+fn get_estimate_changes_for_asset(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+) -> Result<Vec<EstimateChange>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, asset_id, effective_date, useful_life_years, salvage_value,
+                    previous_useful_life_years, previous_salvage_value, reason, created_at
+             FROM estimate_changes
+             WHERE asset_id = ?1
+             ORDER BY effective_date, id",
+        )
+        .map_err(map_err)?;
+
+    let changes = stmt
+        .query_map([asset_id], |row| {
+            Ok(EstimateChange {
+                id: row.get(0)?,
+                asset_id: row.get(1)?,
+                effective_date: row.get(2)?,
+                useful_life_years: row.get(3)?,
+                salvage_value: row.get(4)?,
+                previous_useful_life_years: row.get(5)?,
+                previous_salvage_value: row.get(6)?,
+                reason: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(changes)
+}
+
+fn get_books_for_asset(conn: &rusqlite::Connection, asset_id: i64) -> Result<Vec<AssetBook>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT asset_id, book, method, convention, useful_life_years, property_class,
+                    section_179_amount, bonus_percentage
+             FROM asset_books
+             WHERE asset_id = ?1
+             ORDER BY book",
+        )
+        .map_err(map_err)?;
+
+    let books = stmt
+        .query_map([asset_id], |row| {
+            Ok(AssetBook {
+                asset_id: row.get(0)?,
+                book: row.get(1)?,
+                method: row.get(2)?,
+                convention: row.get(3)?,
+                useful_life_years: row.get(4)?,
+                property_class: row.get(5)?,
+                section_179_amount: row.get(6)?,
+                bonus_percentage: row.get(7)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(books)
+}
+
+pub(crate) fn get_all_leases(conn: &rusqlite::Connection) -> Result<Vec<Lease>> {
+    let mut stmt = conn
+        .prepare("SELECT * FROM leases ORDER BY commencement_date, name")
+        .map_err(map_err)?;
+
+    let leases = stmt
+        .query_map([], lease_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(leases)
+}
+
+/// Insert an asset split off by a partial disposal, returning its ID
+fn insert_asset_part(conn: &rusqlite::Connection, part: &Asset) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO assets (name, description, category_id, date_placed_in_service, cost, salvage_value, useful_life_years, property_class, method, convention, total_units, section_179_amount, bonus_percentage, business_use_percentage, passenger_auto, asset_kind, notes, quantity, disposed_date, disposed_value, selling_costs, proceeds_type, parent_asset_id, improved_asset_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)",
+        params![
+            part.name,
+            part.description,
+            part.category_id,
+            part.date_placed_in_service,
+            part.cost,
+            part.salvage_value,
+            part.useful_life_years,
+            part.property_class,
+            part.method,
+            part.convention,
+            part.total_units,
+            part.section_179_amount,
+            part.bonus_percentage,
+            part.business_use_percentage,
+            part.passenger_auto,
+            part.asset_kind,
+            part.notes,
+            part.quantity,
+            part.disposed_date,
+            part.disposed_value,
+            part.selling_costs,
+            part.proceeds_type,
+            part.parent_asset_id,
+            part.improved_asset_id,
+        ],
+    )
+    .map_err(map_err)?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Copy an asset's tax book settings to a part split off from it, with
+/// Section 179 shared out like the cost
+fn split_books(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    for book in get_books_for_asset(conn, asset_id)? {
+        let split_179 = book
+            .section_179_amount
+            .map(|amount| amount.mul_div(numerator, denominator));
+        conn.execute(
+            "INSERT INTO asset_books (asset_id, book, method, convention, useful_life_years,
+                property_class, section_179_amount, bonus_percentage)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                part_id,
+                book.book,
+                book.method,
+                book.convention,
+                book.useful_life_years,
+                book.property_class,
+                split_179,
+                book.bonus_percentage,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE asset_books SET section_179_amount = ?1 WHERE asset_id = ?2 AND book = ?3",
+            params![
+                book.section_179_amount
+                    .zip(split_179)
+                    .map(|(amount, split)| amount - split),
+                asset_id,
+                book.book,
+            ],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Copy an asset's changes in estimate to a part split off from it, with
+/// salvage value shared out
+fn split_estimate_changes(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    for change in get_estimate_changes_for_asset(conn, asset_id)? {
+        let split_salvage = change.salvage_value.mul_div(numerator, denominator);
+        let split_previous = change
+            .previous_salvage_value
+            .map(|salvage| salvage.mul_div(numerator, denominator));
+        conn.execute(
+            "INSERT INTO estimate_changes (asset_id, effective_date, useful_life_years,
+                salvage_value, previous_useful_life_years, previous_salvage_value, reason,
+                created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                part_id,
+                change.effective_date,
+                change.useful_life_years,
+                split_salvage,
+                change.previous_useful_life_years,
+                split_previous,
+                change.reason,
+                change.created_at,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE estimate_changes SET salvage_value = ?1, previous_salvage_value = ?2
+             WHERE id = ?3",
+            params![
+                change.salvage_value - split_salvage,
+                change
+                    .previous_salvage_value
+                    .zip(split_previous)
+                    .map(|(salvage, split)| salvage - split),
+                change.id,
+            ],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Share an asset's impairments out with a part split off from it
+fn split_impairments(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    for impairment in get_impairments_for_asset(conn, asset_id)? {
+        let split_amount = impairment.amount.mul_div(numerator, denominator);
+        conn.execute(
+            "INSERT INTO impairments (asset_id, impairment_date, amount, reason, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                part_id,
+                impairment.impairment_date,
+                split_amount,
+                impairment.reason,
+                impairment.created_at,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE impairments SET amount = ?1 WHERE id = ?2",
+            params![impairment.amount - split_amount, impairment.id],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Share an asset's revaluations out with a part split off from it
+fn split_revaluations(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    for revaluation in get_revaluations_for_asset(conn, asset_id)? {
+        let split_value = revaluation.fair_value.mul_div(numerator, denominator);
+        conn.execute(
+            "INSERT INTO revaluations (asset_id, revaluation_date, fair_value, reason, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                part_id,
+                revaluation.revaluation_date,
+                split_value,
+                revaluation.reason,
+                revaluation.created_at,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE revaluations SET fair_value = ?1 WHERE id = ?2",
+            params![revaluation.fair_value - split_value, revaluation.id],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Share an asset's components out with a part split off from it
+fn split_components(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    for component in get_components_for_asset(conn, asset_id)? {
+        let split_cost = component.cost.mul_div(numerator, denominator);
+        let split_salvage = component.salvage_value.mul_div(numerator, denominator);
+        conn.execute(
+            "INSERT INTO asset_components (asset_id, name, cost, salvage_value,
+                useful_life_years, method, convention)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                part_id,
+                component.name,
+                split_cost,
+                split_salvage,
+                component.useful_life_years,
+                component.method,
+                component.convention,
+            ],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE asset_components SET cost = ?1, salvage_value = ?2 WHERE id = ?3",
+            params![
+                component.cost - split_cost,
+                component.salvage_value - split_salvage,
+                component.id,
+            ],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Share the usage recorded so far for an asset out with a part split off from it
+fn split_usage(
+    conn: &rusqlite::Connection,
+    asset_id: i64,
+    part_id: i64,
+    (numerator, denominator): (i64, i64),
+) -> Result<()> {
+    let fraction = numerator as f64 / denominator as f64;
+    for usage in get_usage_for_asset(conn, asset_id)? {
+        let split_units = usage.units * fraction;
+        conn.execute(
+            "INSERT INTO asset_usage (asset_id, year, units, notes) VALUES (?1, ?2, ?3, ?4)",
+            params![part_id, usage.year, split_units, usage.notes],
+        )
+        .map_err(map_err)?;
+        conn.execute(
+            "UPDATE asset_usage SET units = ?1 WHERE id = ?2",
+            params![usage.units - split_units, usage.id],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Copy an asset's business use to a part split off from it. Business use
+/// is a share of use, so the part keeps the same percentages.
+fn copy_business_use(conn: &rusqlite::Connection, asset_id: i64, part_id: i64) -> Result<()> {
+    for entry in get_business_use_for_asset(conn, asset_id)? {
+        conn.execute(
+            "INSERT INTO business_use (asset_id, year, percentage, notes) VALUES (?1, ?2, ?3, ?4)",
+            params![part_id, entry.year, entry.percentage, entry.notes],
+        )
+        .map_err(map_err)?;
+    }
+    Ok(())
+}
+
+/// Delete an asset along with everything recorded against it, including the
+/// lease of a right-of-use asset
+fn remove_asset(conn: &rusqlite::Connection, id: i64) -> Result<()> {
+    conn.execute("DELETE FROM depreciation_schedule WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM depreciation_periods WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM asset_usage WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM business_use WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM asset_books WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM disposals WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM estimate_changes WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM asset_components WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM impairments WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM revaluations WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM leases WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    // Parts split off the asset and its improvements stay, without the link
+    // back to it
+    conn.execute(
+        "UPDATE assets SET parent_asset_id = NULL WHERE parent_asset_id = ?1",
+        [id],
+    )
+    .map_err(map_err)?;
+    conn.execute(
+        "UPDATE assets SET improved_asset_id = NULL WHERE improved_asset_id = ?1",
+        [id],
+    )
+    .map_err(map_err)?;
+    conn.execute("DELETE FROM assets WHERE id = ?1", [id])
+        .map_err(map_err)?;
+    Ok(())
+}
+
+pub(crate) fn save_schedule(conn: &rusqlite::Connection, asset: &Asset) -> Result<()> {
+    let id = asset.id.ok_or("Asset ID required")?;
+
+    // Delete existing schedule
+    conn.execute("DELETE FROM depreciation_schedule WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+    conn.execute("DELETE FROM depreciation_periods WHERE asset_id = ?1", [id])
+        .map_err(map_err)?;
+
+    // The financial book, then each tax book the asset has settings for
+    let mut books = vec![(BOOK_FINANCIAL.to_string(), financial_book(asset))];
+    for book in get_books_for_asset(conn, id)? {
+        let book_asset = apply_book(asset, &book);
+        books.push((book.book, book_asset));
+    }
+
+    // Generate and insert new schedules, from actual usage and business use
+    // where recorded. Changes in estimate are made in the financial book only.
+    let usage = get_usage_for_asset(conn, id)?;
+    let business_use = get_business_use_for_asset(conn, id)?;
+    let estimates = get_estimate_changes_for_asset(conn, id)?;
+    let impairments = get_impairments_for_asset(conn, id)?;
+    let revaluations = get_revaluations_for_asset(conn, id)?;
+    let components = get_components_for_asset(conn, id)?;
+    let fiscal = fiscal_year(conn).map_err(map_err)?;
+    for (book, mut book_asset) in books {
+        // The election out of bonus is a tax election, so only tax books take it
+        if book != BOOK_FINANCIAL && bonus_opted_out(conn, &book_asset).map_err(map_err)? {
+            book_asset.bonus_percentage = Some(0.0);
+        }
+
+        // A componentized asset's financial book is the sum of its parts
+        let (schedule, periods) = if book == BOOK_FINANCIAL && !components.is_empty() {
+            let parts = component_schedules(
+                &book_asset,
+                &components,
+                &usage,
+                &business_use,
+                &impairments,
+                &revaluations,
+                fiscal,
+            );
+            let periods = combine_periods(
+                parts
+                    .iter()
+                    .flat_map(|(part, schedule)| monthly_schedule(part, schedule, fiscal)),
+            );
+            let parts: Vec<(&Asset, Vec<DepreciationEntry>)> = parts
+                .iter()
+                .map(|(part, schedule)| (part, schedule.clone()))
+                .collect();
+            (combine_schedules(&parts), periods)
+        } else {
+            let schedule = if book == BOOK_FINANCIAL {
+                generate_revised_schedule(
+                    &book_asset,
+                    &usage,
+                    &business_use,
+                    &estimates,
+                    &impairments,
+                    &revaluations,
+                    fiscal,
+                )
+            } else {
+                generate_revised_schedule(&book_asset, &usage, &business_use, &[], &[], &[], fiscal)
+            };
+            let periods = monthly_schedule(&book_asset, &schedule, fiscal);
+            (schedule, periods)
+        };
+        if book == BOOK_FINANCIAL {
+            save_disposal(conn, &book_asset, &schedule)?;
+        }
+
+        for entry in &schedule {
+            conn.execute(
+                "INSERT INTO depreciation_schedule
+                 (asset_id, book, year, beginning_book_value, depreciation_expense, impairment_loss, revaluation_adjustment, accumulated_depreciation, ending_book_value)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    id,
+                    book,
+                    entry.year,
+                    entry.beginning_book_value,
+                    entry.depreciation_expense,
+                    entry.impairment_loss,
+                    entry.revaluation_adjustment,
+                    entry.accumulated_depreciation,
+                    entry.ending_book_value,
+                ],
+            )
+            .map_err(map_err)?;
+        }
+
+        for period in periods {
+            conn.execute(
+                "INSERT INTO depreciation_periods
+                 (asset_id, book, year, period, period_start, depreciation_expense)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    id,
+                    book,
+                    period.year,
+                    period.period,
+                    period.period_start,
+                    period.depreciation_expense,
+                ],
+            )
+            .map_err(map_err)?;
+        }
+    }
+
+    // An improvement disposed of with its asset is part of the asset's disposal
+    if let Some(parent) = disposed_parent(conn, asset)? {
+        let parent_id = parent.id.ok_or("Asset ID required")?;
+        let schedule = get_schedule_for_asset(conn, parent_id, BOOK_FINANCIAL)?;
+        save_disposal(conn, &parent, &schedule)?;
+    }
+
+    Ok(())
+}
+
+/// An asset's schedule in a tax book. Without settings of its own the tax
+/// book follows the asset's, but not the financial book's changes in
+/// estimate, impairments and revaluations, so its schedule is generated
+/// from the asset as the book would depreciate it rather than read from the
+/// financial book.
+fn tax_schedule_for_asset(
+    conn: &rusqlite::Connection,
+    asset: &Asset,
+    tax_book: &str,
+) -> Result<Vec<DepreciationEntry>> {
+    let id = asset.id.ok_or("Asset ID required")?;
+    let schedule = get_schedule_for_asset(conn, id, tax_book)?;
+    if !schedule.is_empty() {
+        return Ok(schedule);
+    }
+
+    let no_settings = AssetBook {
+        asset_id: id,
+        book: tax_book.to_string(),
+        method: None,
+        convention: None,
+        useful_life_years: None,
+        property_class: None,
+        section_179_amount: None,
+        bonus_percentage: None,
+    };
+    let mut tax_asset = apply_book(asset, &no_settings);
+    if bonus_opted_out(conn, &tax_asset).map_err(map_err)? {
+        tax_asset.bonus_percentage = Some(0.0);
+    }
+    let usage = get_usage_for_asset(conn, id)?;
+    let business_use = get_business_use_for_asset(conn, id)?;
+    let fiscal = fiscal_year(conn).map_err(map_err)?;
+    Ok(generate_revised_schedule(
+        &tax_asset,
+        &usage,
+        &business_use,
+        &[],
+        &[],
+        &[],
+        fiscal,
+    ))
+}
+
+/// The asset an improvement was disposed of together with, if any
+fn disposed_parent(conn: &rusqlite::Connection, asset: &Asset) -> Result<Option<Asset>> {
+    let (Some(parent_id), Some(disposed_date)) = (asset.improved_asset_id, &asset.disposed_date)
+    else {
+        return Ok(None);
+    };
+    let parent = conn
+        .query_row(
+            "SELECT * FROM assets WHERE id = ?1",
+            [parent_id],
+            asset_from_row,
+        )
+        .map_err(map_err)?;
+    Ok(Some(parent).filter(|parent| parent.disposed_date.as_ref() == Some(disposed_date)))
+}
+
+/// Improvements disposed of together with an asset, on the same date
+fn improvements_disposed_with(conn: &rusqlite::Connection, asset: &Asset) -> Result<Vec<Asset>> {
+    let Some(id) = asset.id else {
+        return Ok(Vec::new());
+    };
+    Ok(get_improvements(conn, id)?
+        .into_iter()
+        .filter(|improvement| {
+            asset.disposed_date.is_some() && improvement.disposed_date == asset.disposed_date
+        })
+        .collect())
+}
+
+/// Record a disposed asset's net book value and gain or loss from its
+/// financial book schedule, or clear the record if it is no longer disposed of.
+/// Improvements disposed of with the asset add their book value to its
+/// disposal and have no record of their own.
+fn save_disposal(
+    conn: &rusqlite::Connection,
+    asset: &Asset,
+    schedule: &[DepreciationEntry],
+) -> Result<()> {
+    let id = asset.id.ok_or("Asset ID required")?;
+
+    if asset.disposed_date.is_none() || disposed_parent(conn, asset)?.is_some() {
+        conn.execute("DELETE FROM disposals WHERE asset_id = ?1", [id])
+            .map_err(map_err)?;
+        return Ok(());
+    }
+
+    let mut improvements_value = Money::ZERO;
+    for improvement in improvements_disposed_with(conn, asset)? {
+        let improvement_id = improvement.id.ok_or("Asset ID required")?;
+        let schedule = get_schedule_for_asset(conn, improvement_id, BOOK_FINANCIAL)?;
+        improvements_value += book_value_at_disposal(&improvement, &schedule);
+    }
+
+    conn.execute(
+        "INSERT INTO disposals (asset_id, book_value, gain_loss) VALUES (?1, ?2, ?3)
+         ON CONFLICT(asset_id) DO UPDATE SET book_value = excluded.book_value, gain_loss = excluded.gain_loss",
+        params![
+            id,
+            book_value_at_disposal(asset, schedule) + improvements_value,
+            gain_loss_on_disposal(asset, schedule) - improvements_value,
+        ],
+    )
+    .map_err(map_err)?;
+
+    Ok(())
+}
+
+/// Disposed assets with their recorded gain or loss, or just the one asset
+pub(crate) fn get_disposals(
+    conn: &rusqlite::Connection,
+    asset_id: Option<i64>,
+) -> Result<Vec<Disposal>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT a.id, a.name, a.disposed_date, a.proceeds_type,
+                    a.cost + (SELECT COALESCE(SUM(i.cost), 0) FROM assets i
+                              WHERE i.improved_asset_id = a.id
+                                AND i.disposed_date = a.disposed_date),
+                    d.book_value, a.disposed_value, a.selling_costs, d.gain_loss
+             FROM disposals d
+             JOIN assets a ON d.asset_id = a.id
+             WHERE a.disposed_date IS NOT NULL AND (?1 IS NULL OR a.id = ?1)
+             ORDER BY a.disposed_date, a.name",
+        )
+        .map_err(map_err)?;
+
+    let disposals = stmt
+        .query_map([asset_id], |row| {
+            let cost: Money = row.get(4)?;
+            let book_value: Money = row.get(5)?;
+            Ok(Disposal {
+                asset_id: row.get(0)?,
+                name: row.get(1)?,
+                disposed_date: row.get(2)?,
+                proceeds_type: row
+                    .get::<_, Option<String>>(3)?
+                    .unwrap_or_else(|| PROCEEDS_SALE.to_string()),
+                cost,
+                accumulated_depreciation: cost - book_value,
+                book_value,
+                proceeds: row.get::<_, Option<Money>>(6)?.unwrap_or(Money::ZERO),
+                selling_costs: row.get::<_, Option<Money>>(7)?.unwrap_or(Money::ZERO),
+                gain_loss: row.get(8)?,
+            })
+        })
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(disposals)
+}
+
+/// Regenerate every asset's schedules, after a change that affects them all
+pub(crate) fn regenerate_all_schedules(conn: &rusqlite::Connection) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT * FROM assets").map_err(map_err)?;
+
+    let assets: Vec<Asset> = stmt
+        .query_map([], asset_from_row)
+        .map_err(map_err)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    for asset in &assets {
+        save_schedule(conn, asset)?;
+    }
+
+    Ok(())
+}
+
+/// Bring stored schedules up to date with what the current version keeps,
+/// once, when the app starts
+pub(crate) fn upgrade_schedules(db: &Database) -> Result<()> {
+    let conn = db.conn.lock().map_err(map_err)?;
+
+    // Monthly periods weren't stored before, schedules ran on past disposal,
+    // and gains and losses on disposal weren't recorded
+    let upgrades = ["schedule_periods", "schedule_disposals", "disposal_gains"];
+    let mut pending = Vec::new();
+    for upgrade in upgrades {
+        if get_setting(&conn, upgrade).map_err(map_err)?.is_none() {
+            pending.push(upgrade);
+        }
+    }
+
+    if !pending.is_empty() {
+        regenerate_all_schedules(&conn)?;
+        for upgrade in pending {
+            set_setting(&conn, upgrade, "1").map_err(map_err)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_asset() -> Asset {
+        Asset {
+            id: None,
+            name: "Test Machine".to_string(),
+            description: None,
+            category_id: None,
+            date_placed_in_service: "2024-01-15".to_string(),
+            cost: Money::from_dollars(10000.0),
+            salvage_value: Money::ZERO,
+            useful_life_years: 5,
+            property_class: Some("5".to_string()),
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: Some(0.0),
+            business_use_percentage: None,
+            passenger_auto: false,
+            asset_kind: None,
+            notes: None,
+            quantity: None,
+            disposed_date: None,
+            disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+            parent_asset_id: None,
+            improved_asset_id: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Add the asset and save its schedules
+    fn add_asset(conn: &rusqlite::Connection, mut asset: Asset) -> Asset {
+        asset.id = Some(insert_asset_part(conn, &asset).unwrap());
+        save_schedule(conn, &asset).unwrap();
+        asset
+    }
+
+    #[test]
+    fn test_book_tax_schedules_include_assets_without_tax_settings() {
+        let db = Database::in_memory().unwrap();
+        let conn = db.conn.lock().unwrap();
+        let asset = add_asset(&conn, test_asset());
+        conn.execute(
+            "INSERT INTO impairments (asset_id, impairment_date, amount) VALUES (?1, ?2, ?3)",
+            params![asset.id, "2025-06-30", Money::from_dollars(2000.0)],
+        )
+        .unwrap();
+        save_schedule(&conn, &asset).unwrap();
+
+        // The tax book has no settings or stored rows of its own, so it
+        // follows the asset without the impairment
+        let schedules = book_tax_schedules(&conn, BOOK_FEDERAL).unwrap();
+        assert_eq!(schedules.len(), 1);
+        let (asset, book, tax) = &schedules[0];
+        assert!(!tax.is_empty());
+        assert_eq!(temporary_difference(asset, book, tax, 2025).to_dollars(), -2000.0);
+    }
+
+    #[test]
+    fn test_section_179_is_a_temporary_difference() {
+        let db = Database::in_memory().unwrap();
+        let conn = db.conn.lock().unwrap();
+        let mut asset = test_asset();
+        asset.section_179_amount = Some(Money::from_dollars(5000.0));
+        add_asset(&conn, asset);
+
+        // The financial book depreciates the full cost, while the tax book
+        // expenses the election in the first year
+        let schedules = book_tax_schedules(&conn, BOOK_FEDERAL).unwrap();
+        let (asset, book, tax) = &schedules[0];
+        assert_eq!(book[0].depreciation_expense.to_dollars(), 2000.0);
+        assert_eq!(tax[0].depreciation_expense.to_dollars(), 6000.0);
+        assert_eq!(temporary_difference(asset, book, tax, 2024).to_dollars(), 4000.0);
+    }
+}
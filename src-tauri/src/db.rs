@@ -1,7 +1,26 @@
-use rusqlite::{Connection, Result};
+use crate::fiscal::FiscalYear;
+use crate::models::{Asset, Lease};
+use crate::money::Money;
+use rusqlite::{params, Connection, OptionalExtension, Result, Row};
 use std::path::PathBuf;
 use std::sync::Mutex;
 
+/// Tax rate applied to book-to-tax differences until one is configured
+const DEFAULT_DEFERRED_TAX_RATE: f64 = 21.0;
+
+/// IRS Section 179 dollar limit and phase-out threshold by tax year
+/// (Rev. Procs. and, from 2025, the 2025 reconciliation act)
+const SECTION_179_LIMITS: &[(i32, f64, f64)] = &[
+    (2018, 1_000_000.0, 2_500_000.0),
+    (2019, 1_020_000.0, 2_550_000.0),
+    (2020, 1_040_000.0, 2_590_000.0),
+    (2021, 1_050_000.0, 2_620_000.0),
+    (2022, 1_080_000.0, 2_700_000.0),
+    (2023, 1_160_000.0, 2_890_000.0),
+    (2024, 1_220_000.0, 3_050_000.0),
+    (2025, 2_500_000.0, 4_000_000.0),
+];
+
 pub struct Database {
     pub conn: Mutex<Connection>,
 }
@@ -23,6 +42,16 @@ impl Database {
         Ok(db)
     }
 
+    /// An empty database held in memory, for tests
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        let db = Database {
+            conn: Mutex::new(Connection::open_in_memory()?),
+        };
+        db.init_schema()?;
+        Ok(db)
+    }
+
     fn init_schema(&self) -> Result<()> {
         let conn = self.conn.lock().unwrap();
 
@@ -34,6 +63,8 @@ impl Database {
                 name TEXT NOT NULL UNIQUE,
                 default_useful_life INTEGER,
                 default_property_class TEXT,
+                default_method TEXT,
+                revaluation_model INTEGER NOT NULL DEFAULT 0,
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
                 updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
             );
@@ -45,34 +76,192 @@ impl Database {
                 description TEXT,
                 category_id INTEGER REFERENCES categories(id),
                 date_placed_in_service DATE NOT NULL,
-                cost REAL NOT NULL,
-                salvage_value REAL NOT NULL DEFAULT 0,
+                cost INTEGER NOT NULL,
+                salvage_value INTEGER NOT NULL DEFAULT 0,
                 useful_life_years INTEGER NOT NULL,
                 property_class TEXT,
+                method TEXT,
+                convention TEXT,
+                total_units REAL,
+                section_179_amount INTEGER,
+                bonus_percentage REAL,
+                business_use_percentage REAL,
+                passenger_auto INTEGER NOT NULL DEFAULT 0,
+                asset_kind TEXT,
                 notes TEXT,
+                quantity INTEGER,
                 disposed_date DATE,
-                disposed_value REAL,
+                disposed_value INTEGER,
+                selling_costs INTEGER,
+                proceeds_type TEXT,
+                parent_asset_id INTEGER REFERENCES assets(id),
+                improved_asset_id INTEGER REFERENCES assets(id),
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
                 updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
             );
 
-            -- Pre-computed depreciation schedule
+            -- Pre-computed depreciation schedule, one per book
             CREATE TABLE IF NOT EXISTS depreciation_schedule (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                book TEXT NOT NULL DEFAULT 'book',
                 year INTEGER NOT NULL,
-                beginning_book_value REAL NOT NULL,
-                depreciation_expense REAL NOT NULL,
-                accumulated_depreciation REAL NOT NULL,
-                ending_book_value REAL NOT NULL,
+                beginning_book_value INTEGER NOT NULL,
+                depreciation_expense INTEGER NOT NULL,
+                impairment_loss INTEGER NOT NULL DEFAULT 0,
+                revaluation_adjustment INTEGER NOT NULL DEFAULT 0,
+                accumulated_depreciation INTEGER NOT NULL,
+                ending_book_value INTEGER NOT NULL,
+                UNIQUE(asset_id, book, year)
+            );
+
+            -- The schedule spread over fiscal months, one set per book
+            CREATE TABLE IF NOT EXISTS depreciation_periods (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                book TEXT NOT NULL DEFAULT 'book',
+                year INTEGER NOT NULL,
+                period INTEGER NOT NULL,
+                period_start DATE NOT NULL,
+                depreciation_expense INTEGER NOT NULL,
+                UNIQUE(asset_id, book, year, period)
+            );
+
+            -- Net book value and gain or loss of each disposed asset, worked
+            -- out from its financial book schedule
+            CREATE TABLE IF NOT EXISTS disposals (
+                asset_id INTEGER PRIMARY KEY REFERENCES assets(id) ON DELETE CASCADE,
+                book_value INTEGER NOT NULL,
+                gain_loss INTEGER NOT NULL
+            );
+
+            -- Changes in the financial book's useful life and salvage value
+            -- estimates, applied prospectively and kept for audit
+            CREATE TABLE IF NOT EXISTS estimate_changes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                effective_date DATE NOT NULL,
+                useful_life_years INTEGER NOT NULL,
+                salvage_value INTEGER NOT NULL,
+                previous_useful_life_years INTEGER NOT NULL,
+                previous_salvage_value INTEGER NOT NULL,
+                reason TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+
+            -- Impairment losses written off the financial book's carrying amount
+            CREATE TABLE IF NOT EXISTS impairments (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                impairment_date DATE NOT NULL,
+                amount INTEGER NOT NULL,
+                reason TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+
+            -- Fair-value revaluations of assets in categories on the
+            -- revaluation model, restating the financial book's carrying amount
+            CREATE TABLE IF NOT EXISTS revaluations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                revaluation_date DATE NOT NULL,
+                fair_value INTEGER NOT NULL,
+                reason TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+
+            -- Significant parts of an asset depreciated separately in the
+            -- financial book; the rest of the cost follows the asset
+            CREATE TABLE IF NOT EXISTS asset_components (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                cost INTEGER NOT NULL,
+                salvage_value INTEGER NOT NULL DEFAULT 0,
+                useful_life_years INTEGER NOT NULL,
+                method TEXT,
+                convention TEXT
+            );
+
+            -- Per-asset settings for the tax books; unset columns inherit the asset's
+            CREATE TABLE IF NOT EXISTS asset_books (
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                book TEXT NOT NULL,
+                method TEXT,
+                convention TEXT,
+                useful_life_years INTEGER,
+                property_class TEXT,
+                section_179_amount INTEGER,
+                bonus_percentage REAL,
+                PRIMARY KEY (asset_id, book)
+            );
+
+            -- Units recorded per year for units-of-production assets
+            CREATE TABLE IF NOT EXISTS asset_usage (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                year INTEGER NOT NULL,
+                units REAL NOT NULL,
+                notes TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
                 UNIQUE(asset_id, year)
             );
 
+            -- Business use recorded per year for listed property
+            CREATE TABLE IF NOT EXISTS business_use (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                year INTEGER NOT NULL,
+                percentage REAL NOT NULL,
+                notes TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(asset_id, year)
+            );
+
+            -- Leases the company is the lessee of. The right-of-use asset is
+            -- kept on the asset register; the liability is computed from the terms.
+            CREATE TABLE IF NOT EXISTS leases (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                commencement_date DATE NOT NULL,
+                term_years INTEGER NOT NULL,
+                monthly_payment INTEGER NOT NULL,
+                payments_in_advance INTEGER NOT NULL DEFAULT 0,
+                discount_rate REAL NOT NULL,
+                initial_direct_costs INTEGER,
+                notes TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+
+            -- Section 179 dollar limit and phase-out threshold by tax year
+            CREATE TABLE IF NOT EXISTS section_179_limits (
+                year INTEGER PRIMARY KEY,
+                dollar_limit INTEGER NOT NULL,
+                phase_out_threshold INTEGER NOT NULL
+            );
+
+            -- Property classes elected out of bonus depreciation for a tax year
+            CREATE TABLE IF NOT EXISTS bonus_opt_outs (
+                year INTEGER NOT NULL,
+                property_class TEXT NOT NULL,
+                PRIMARY KEY (year, property_class)
+            );
+
+            -- Application settings as key/value pairs
+            CREATE TABLE IF NOT EXISTS settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );
+
             -- Indexes
             CREATE INDEX IF NOT EXISTS idx_assets_category ON assets(category_id);
             CREATE INDEX IF NOT EXISTS idx_assets_date ON assets(date_placed_in_service);
             CREATE INDEX IF NOT EXISTS idx_schedule_year ON depreciation_schedule(year);
             CREATE INDEX IF NOT EXISTS idx_schedule_asset ON depreciation_schedule(asset_id);
+            CREATE INDEX IF NOT EXISTS idx_periods_start ON depreciation_periods(period_start);
+            CREATE INDEX IF NOT EXISTS idx_periods_asset ON depreciation_periods(asset_id);
             "
         )?;
 
@@ -89,10 +278,278 @@ impl Database {
             conn.execute("UPDATE categories SET updated_at = CURRENT_TIMESTAMP WHERE updated_at IS NULL", [])?;
         }
 
+        // Columns added after the initial release
+        add_column_if_missing(&conn, "assets", "method", "TEXT")?;
+        add_column_if_missing(&conn, "assets", "convention", "TEXT")?;
+        add_column_if_missing(&conn, "assets", "total_units", "REAL")?;
+        add_column_if_missing(&conn, "assets", "section_179_amount", "REAL")?;
+        add_column_if_missing(&conn, "assets", "bonus_percentage", "REAL")?;
+        add_column_if_missing(&conn, "assets", "business_use_percentage", "REAL")?;
+        add_column_if_missing(
+            &conn,
+            "assets",
+            "passenger_auto",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+        add_column_if_missing(&conn, "assets", "asset_kind", "TEXT")?;
+        add_column_if_missing(&conn, "assets", "selling_costs", "INTEGER")?;
+        add_column_if_missing(&conn, "assets", "proceeds_type", "TEXT")?;
+        add_column_if_missing(&conn, "assets", "quantity", "INTEGER")?;
+        add_column_if_missing(
+            &conn,
+            "assets",
+            "parent_asset_id",
+            "INTEGER REFERENCES assets(id)",
+        )?;
+        add_column_if_missing(
+            &conn,
+            "assets",
+            "improved_asset_id",
+            "INTEGER REFERENCES assets(id)",
+        )?;
+        add_column_if_missing(&conn, "categories", "default_method", "TEXT")?;
+        add_column_if_missing(
+            &conn,
+            "categories",
+            "revaluation_model",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+
+        // Schedules kept a single book before; the unique key has to change
+        // too, which SQLite only allows by rebuilding the table
+        let has_book: bool = conn
+            .prepare("SELECT COUNT(*) FROM pragma_table_info('depreciation_schedule') WHERE name = 'book'")?
+            .query_row([], |row| row.get::<_, i64>(0))
+            .map(|count| count > 0)
+            .unwrap_or(false);
+
+        if !has_book {
+            conn.execute_batch(
+                "
+                ALTER TABLE depreciation_schedule RENAME TO depreciation_schedule_old;
+                CREATE TABLE depreciation_schedule (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    asset_id INTEGER NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+                    book TEXT NOT NULL DEFAULT 'book',
+                    year INTEGER NOT NULL,
+                    beginning_book_value REAL NOT NULL,
+                    depreciation_expense REAL NOT NULL,
+                    accumulated_depreciation REAL NOT NULL,
+                    ending_book_value REAL NOT NULL,
+                    UNIQUE(asset_id, book, year)
+                );
+                INSERT INTO depreciation_schedule
+                    (asset_id, year, beginning_book_value, depreciation_expense, accumulated_depreciation, ending_book_value)
+                    SELECT asset_id, year, beginning_book_value, depreciation_expense, accumulated_depreciation, ending_book_value
+                    FROM depreciation_schedule_old;
+                DROP TABLE depreciation_schedule_old;
+                CREATE INDEX IF NOT EXISTS idx_schedule_year ON depreciation_schedule(year);
+                CREATE INDEX IF NOT EXISTS idx_schedule_asset ON depreciation_schedule(asset_id);
+                ",
+            )?;
+        }
+        add_column_if_missing(
+            &conn,
+            "depreciation_schedule",
+            "impairment_loss",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+        add_column_if_missing(
+            &conn,
+            "depreciation_schedule",
+            "revaluation_adjustment",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+
+        // Money was stored as REAL dollars before; convert it to integer
+        // cents once. Older columns keep their REAL type, which holds whole
+        // cents exactly.
+        if get_setting(&conn, "money_in_cents")?.is_none() {
+            conn.execute_batch(
+                "
+                BEGIN;
+                UPDATE assets SET
+                    cost = CAST(ROUND(cost * 100) AS INTEGER),
+                    salvage_value = CAST(ROUND(salvage_value * 100) AS INTEGER),
+                    section_179_amount = CAST(ROUND(section_179_amount * 100) AS INTEGER),
+                    disposed_value = CAST(ROUND(disposed_value * 100) AS INTEGER);
+                UPDATE depreciation_schedule SET
+                    beginning_book_value = CAST(ROUND(beginning_book_value * 100) AS INTEGER),
+                    depreciation_expense = CAST(ROUND(depreciation_expense * 100) AS INTEGER),
+                    accumulated_depreciation = CAST(ROUND(accumulated_depreciation * 100) AS INTEGER),
+                    ending_book_value = CAST(ROUND(ending_book_value * 100) AS INTEGER);
+                UPDATE asset_books SET
+                    section_179_amount = CAST(ROUND(section_179_amount * 100) AS INTEGER);
+                UPDATE section_179_limits SET
+                    dollar_limit = CAST(ROUND(dollar_limit * 100) AS INTEGER),
+                    phase_out_threshold = CAST(ROUND(phase_out_threshold * 100) AS INTEGER);
+                INSERT INTO settings (key, value) VALUES ('money_in_cents', '1');
+                COMMIT;
+                ",
+            )?;
+        }
+
+        // Published limits; existing rows are left alone so edits stick
+        for (year, dollar_limit, phase_out_threshold) in SECTION_179_LIMITS {
+            conn.execute(
+                "INSERT OR IGNORE INTO section_179_limits (year, dollar_limit, phase_out_threshold) VALUES (?1, ?2, ?3)",
+                rusqlite::params![
+                    year,
+                    Money::from_dollars(*dollar_limit),
+                    Money::from_dollars(*phase_out_threshold),
+                ],
+            )?;
+        }
+
         Ok(())
     }
 }
 
+/// Add a column to an existing table unless it is already present
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    let exists: bool = conn
+        .prepare(&format!("SELECT COUNT(*) FROM pragma_table_info('{}') WHERE name = ?1", table))?
+        .query_row([column], |row| row.get::<_, i64>(0))
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !exists {
+        conn.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])?;
+    }
+
+    Ok(())
+}
+
+/// Depreciation method a category assigns to new assets, if any, or
+/// `None` when the category doesn't exist
+pub fn category_default_method(
+    conn: &Connection,
+    category_id: i64,
+) -> Result<Option<Option<String>>> {
+    conn.query_row(
+        "SELECT default_method FROM categories WHERE id = ?1",
+        [category_id],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Whether a category carries its assets at revalued amounts (IAS 16)
+/// rather than at cost, or `None` when the category doesn't exist
+pub fn category_revaluation_model(conn: &Connection, category_id: i64) -> Result<Option<bool>> {
+    conn.query_row(
+        "SELECT revaluation_model FROM categories WHERE id = ?1",
+        [category_id],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Read a setting, if it has been saved
+pub fn get_setting(conn: &Connection, key: &str) -> Result<Option<String>> {
+    conn.query_row("SELECT value FROM settings WHERE key = ?1", [key], |row| row.get(0))
+        .optional()
+}
+
+pub fn set_setting(conn: &Connection, key: &str, value: &str) -> Result<()> {
+    conn.execute(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        [key, value],
+    )?;
+    Ok(())
+}
+
+/// Percentage rate used for deferred tax on book-to-tax differences
+pub fn deferred_tax_rate(conn: &Connection) -> Result<f64> {
+    Ok(get_setting(conn, "deferred_tax_rate")?
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_DEFERRED_TAX_RATE))
+}
+
+/// The company's fiscal year; calendar years until a year end is configured
+pub fn fiscal_year(conn: &Connection) -> Result<FiscalYear> {
+    Ok(get_setting(conn, "fiscal_year_end_month")?
+        .and_then(|value| value.parse().ok())
+        .map(FiscalYear::ending_in)
+        .unwrap_or_default())
+}
+
+/// Whether the asset's property class was elected out of bonus depreciation
+/// for the fiscal year it was placed in service
+pub fn bonus_opted_out(conn: &Connection, asset: &Asset) -> Result<bool> {
+    let Some(property_class) = asset.property_class.as_deref().map(str::trim) else {
+        return Ok(false);
+    };
+    let Some(year) = fiscal_year(conn)?.year_of(&asset.date_placed_in_service) else {
+        return Ok(false);
+    };
+
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM bonus_opt_outs WHERE year = ?1 AND property_class = ?2)",
+        params![year, property_class],
+        |row| row.get(0),
+    )
+}
+
+/// Map a row selected with `a.*` from the assets table into an Asset.
+/// Columns are read by name so migrated databases, whose added columns
+/// sit at the end of the table, map the same as fresh ones.
+pub fn asset_from_row(row: &Row) -> Result<Asset> {
+    Ok(Asset {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        description: row.get("description")?,
+        category_id: row.get("category_id")?,
+        date_placed_in_service: row.get("date_placed_in_service")?,
+        cost: row.get("cost")?,
+        salvage_value: row.get("salvage_value")?,
+        useful_life_years: row.get("useful_life_years")?,
+        property_class: row.get("property_class")?,
+        method: row.get("method")?,
+        convention: row.get("convention")?,
+        total_units: row.get("total_units")?,
+        section_179_amount: row.get("section_179_amount")?,
+        bonus_percentage: row.get("bonus_percentage")?,
+        business_use_percentage: row.get("business_use_percentage")?,
+        passenger_auto: row.get("passenger_auto")?,
+        asset_kind: row.get("asset_kind")?,
+        notes: row.get("notes")?,
+        quantity: row.get("quantity")?,
+        disposed_date: row.get("disposed_date")?,
+        disposed_value: row.get("disposed_value")?,
+        selling_costs: row.get("selling_costs")?,
+        proceeds_type: row.get("proceeds_type")?,
+        parent_asset_id: row.get("parent_asset_id")?,
+        improved_asset_id: row.get("improved_asset_id")?,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+/// Map a row selected with `*` from the leases table into a Lease
+pub fn lease_from_row(row: &Row) -> Result<Lease> {
+    Ok(Lease {
+        id: row.get("id")?,
+        asset_id: row.get("asset_id")?,
+        name: row.get("name")?,
+        commencement_date: row.get("commencement_date")?,
+        term_years: row.get("term_years")?,
+        monthly_payment: row.get("monthly_payment")?,
+        payments_in_advance: row.get("payments_in_advance")?,
+        discount_rate: row.get("discount_rate")?,
+        initial_direct_costs: row.get("initial_direct_costs")?,
+        notes: row.get("notes")?,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
 fn get_db_path() -> PathBuf {
     if let Some(proj_dirs) = directories::ProjectDirs::from("com", "caleb", "abacus-depreciation") {
         proj_dirs.data_dir().join("depreciation.db")
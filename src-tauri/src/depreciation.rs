@@ -1,147 +1,3220 @@
-use crate::models::{Asset, DepreciationEntry};
+use crate::fiscal::FiscalYear;
+use crate::models::{
+    Asset, AssetBook, AssetComponent, BusinessUseEntry, DepreciationEntry, DepreciationPeriod,
+    EstimateChange, Impairment, Recapture, Revaluation, UsageEntry,
+};
+use crate::money::Money;
+use std::collections::BTreeMap;
 
-/// Generate straight-line depreciation schedule for an asset
-pub fn generate_schedule(asset: &Asset) -> Vec<DepreciationEntry> {
+/// Depreciation method identifiers stored on `Asset.method`
+pub const METHOD_STRAIGHT_LINE: &str = "straight_line";
+pub const METHOD_MACRS_GDS: &str = "macrs_gds";
+pub const METHOD_MACRS_ADS: &str = "macrs_ads";
+pub const METHOD_DECLINING_200: &str = "declining_200";
+pub const METHOD_DECLINING_150: &str = "declining_150";
+pub const METHOD_SUM_OF_YEARS_DIGITS: &str = "sum_of_years_digits";
+pub const METHOD_UNITS_OF_PRODUCTION: &str = "units_of_production";
+
+/// Convention identifiers stored on `Asset.convention`. These control how
+/// much of a year's depreciation is taken in the first and last years.
+pub const CONVENTION_FULL_YEAR: &str = "full_year";
+pub const CONVENTION_HALF_YEAR: &str = "half_year";
+pub const CONVENTION_MID_QUARTER: &str = "mid_quarter";
+pub const CONVENTION_MID_MONTH: &str = "mid_month";
+pub const CONVENTION_FULL_MONTH: &str = "full_month";
+
+/// Depreciation books kept for every asset. The financial book follows the
+/// asset's own fields; the tax books apply per-asset overrides on top of them.
+pub const BOOK_FINANCIAL: &str = "book";
+pub const BOOK_FEDERAL: &str = "federal";
+pub const BOOK_STATE: &str = "state";
+pub const BOOK_AMT: &str = "amt";
+pub const BOOKS: &[&str] = &[BOOK_FINANCIAL, BOOK_FEDERAL, BOOK_STATE, BOOK_AMT];
+
+/// How a disposed asset left the business, stored on `Asset.proceeds_type`
+pub const PROCEEDS_SALE: &str = "sale";
+pub const PROCEEDS_TRADE_IN: &str = "trade_in";
+pub const PROCEEDS_SCRAP: &str = "scrap";
+pub const PROCEEDS_CASUALTY: &str = "casualty";
+pub const PROCEEDS_TYPES: &[&str] = &[
+    PROCEEDS_SALE,
+    PROCEEDS_TRADE_IN,
+    PROCEEDS_SCRAP,
+    PROCEEDS_CASUALTY,
+];
+
+/// Asset kinds stored on `Asset.asset_kind`. Intangibles are amortized
+/// straight-line from the month acquired under IRC §197.
+pub const KIND_TANGIBLE: &str = "tangible";
+pub const KIND_INTANGIBLE: &str = "intangible";
+pub const ASSET_KINDS: &[&str] = &[KIND_TANGIBLE, KIND_INTANGIBLE];
+
+/// Years a §197 intangible is amortized over for federal tax
+pub const SECTION_197_YEARS: i32 = 15;
+
+/// Period lengths the monthly schedule can be summarized by
+pub const PERIOD_MONTH: &str = "month";
+pub const PERIOD_QUARTER: &str = "quarter";
+
+/// IRS Pub 946 Table A-1: GDS 200%/150% declining balance, half-year convention.
+/// Percentages of the unadjusted basis, keyed by property class.
+const MACRS_HALF_YEAR: &[(&str, &[f64])] = &[
+    ("3", &[33.33, 44.45, 14.81, 7.41]),
+    ("5", &[20.00, 32.00, 19.20, 11.52, 11.52, 5.76]),
+    ("7", &[14.29, 24.49, 17.49, 12.49, 8.93, 8.92, 8.93, 4.46]),
+    (
+        "10",
+        &[
+            10.00, 18.00, 14.40, 11.52, 9.22, 7.37, 6.55, 6.55, 6.56, 6.55, 3.28,
+        ],
+    ),
+    (
+        "15",
+        &[
+            5.00, 9.50, 8.55, 7.70, 6.93, 6.23, 5.90, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91, 5.90,
+            5.91, 2.95,
+        ],
+    ),
+    (
+        "20",
+        &[
+            3.750, 7.219, 6.677, 6.177, 5.713, 5.285, 4.888, 4.522, 4.462, 4.461, 4.462, 4.461,
+            4.462, 4.461, 4.462, 4.461, 4.462, 4.461, 4.462, 4.461, 2.231,
+        ],
+    ),
+];
+
+/// IRS Pub 946 Tables A-2 through A-5: GDS declining balance, mid-quarter
+/// convention. Indexed by the quarter the asset was placed in service.
+const MACRS_MID_QUARTER: [&[(&str, &[f64])]; 4] = [
+    // Table A-2: placed in service in quarter 1
+    &[
+        ("3", &[58.33, 27.78, 12.35, 1.54]),
+        ("5", &[35.00, 26.00, 15.60, 11.01, 11.01, 1.38]),
+        ("7", &[25.00, 21.43, 15.31, 10.93, 8.75, 8.74, 8.75, 1.09]),
+        (
+            "10",
+            &[
+                17.50, 16.50, 13.20, 10.56, 8.45, 6.76, 6.55, 6.55, 6.56, 6.55, 0.82,
+            ],
+        ),
+        (
+            "15",
+            &[
+                8.75, 9.13, 8.21, 7.39, 6.65, 5.99, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91,
+                5.90, 0.74,
+            ],
+        ),
+        (
+            "20",
+            &[
+                6.563, 7.008, 6.482, 5.996, 5.546, 5.130, 4.746, 4.459, 4.459, 4.459, 4.459, 4.460,
+                4.459, 4.460, 4.459, 4.460, 4.459, 4.460, 4.459, 4.460, 0.557,
+            ],
+        ),
+    ],
+    // Table A-3: placed in service in quarter 2
+    &[
+        ("3", &[41.67, 38.89, 14.14, 5.30]),
+        ("5", &[25.00, 30.00, 18.00, 11.37, 11.37, 4.26]),
+        ("7", &[17.86, 23.47, 16.76, 11.97, 8.87, 8.87, 8.87, 3.33]),
+        (
+            "10",
+            &[
+                12.50, 17.50, 14.00, 11.20, 8.96, 7.17, 6.55, 6.55, 6.56, 6.55, 2.46,
+            ],
+        ),
+        (
+            "15",
+            &[
+                6.25, 9.38, 8.44, 7.59, 6.83, 6.15, 5.91, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91, 5.90,
+                5.91, 2.21,
+            ],
+        ),
+        (
+            "20",
+            &[
+                4.688, 7.148, 6.612, 6.116, 5.658, 5.233, 4.841, 4.478, 4.463, 4.463, 4.463, 4.463,
+                4.463, 4.463, 4.462, 4.463, 4.462, 4.463, 4.462, 4.463, 1.673,
+            ],
+        ),
+    ],
+    // Table A-4: placed in service in quarter 3
+    &[
+        ("3", &[25.00, 50.00, 16.67, 8.33]),
+        ("5", &[15.00, 34.00, 20.40, 12.24, 11.30, 7.06]),
+        ("7", &[10.71, 25.51, 18.22, 13.02, 9.30, 8.85, 8.86, 5.53]),
+        (
+            "10",
+            &[
+                7.50, 18.50, 14.80, 11.84, 9.47, 7.58, 6.55, 6.55, 6.56, 6.55, 4.10,
+            ],
+        ),
+        (
+            "15",
+            &[
+                3.75, 9.63, 8.66, 7.80, 7.02, 6.31, 5.90, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91, 5.90,
+                5.91, 3.69,
+            ],
+        ),
+        (
+            "20",
+            &[
+                2.813, 7.289, 6.742, 6.237, 5.769, 5.336, 4.936, 4.566, 4.460, 4.460, 4.460, 4.460,
+                4.461, 4.460, 4.461, 4.460, 4.461, 4.460, 4.461, 4.460, 2.788,
+            ],
+        ),
+    ],
+    // Table A-5: placed in service in quarter 4
+    &[
+        ("3", &[8.33, 61.11, 20.37, 10.19]),
+        ("5", &[5.00, 38.00, 22.80, 13.68, 10.94, 9.58]),
+        ("7", &[3.57, 27.55, 19.68, 14.06, 10.04, 8.73, 8.73, 7.64]),
+        (
+            "10",
+            &[
+                2.50, 19.50, 15.60, 12.48, 9.98, 7.99, 6.55, 6.55, 6.56, 6.55, 5.74,
+            ],
+        ),
+        (
+            "15",
+            &[
+                1.25, 9.88, 8.89, 8.00, 7.20, 6.48, 5.90, 5.90, 5.90, 5.91, 5.90, 5.91, 5.90, 5.91,
+                5.90, 5.17,
+            ],
+        ),
+        (
+            "20",
+            &[
+                0.938, 7.430, 6.872, 6.357, 5.880, 5.439, 5.031, 4.654, 4.458, 4.458, 4.458, 4.458,
+                4.458, 4.458, 4.458, 4.458, 4.458, 4.459, 4.458, 4.459, 3.901,
+            ],
+        ),
+    ],
+];
+
+/// IRS Pub 946 Table A-6: residential rental property (27.5-year), mid-month.
+/// First-year percentage by month placed in service, in thousandths of a percent.
+const RESIDENTIAL_FIRST_YEAR: [u32; 12] = [
+    3485, 3182, 2879, 2576, 2273, 1970, 1667, 1364, 1061, 758, 455, 152,
+];
+
+/// IRS Pub 946 Table A-7a: nonresidential real property (39-year), mid-month.
+/// First-year percentage by month placed in service, in thousandths of a percent.
+const NONRESIDENTIAL_FIRST_YEAR: [u32; 12] = [
+    2461, 2247, 2033, 1819, 1605, 1391, 1177, 963, 749, 535, 321, 107,
+];
+
+/// IRS Pub 946 Appendix B: ADS recovery period in years for typical property
+/// in each GDS property class. Residential rental property placed in service
+/// before 2018 uses 40 years rather than 30.
+const ADS_RECOVERY_PERIODS: &[(&str, i32)] = &[
+    ("3", 4),
+    ("5", 5),
+    ("7", 10),
+    ("10", 15),
+    ("15", 20),
+    ("20", 25),
+    ("27.5", 30),
+    ("39", 40),
+];
+
+/// IRC §280F depreciation limits for passenger automobiles by calendar year
+/// placed in service, in dollars: the first year without and with bonus
+/// depreciation, the second year, the third year and each later year. A
+/// year uses the latest row at or before it; 2012 through 2017 share limits.
+const PASSENGER_AUTO_LIMITS: &[(i32, [f64; 5])] = &[
+    (2012, [3160.0, 11160.0, 5100.0, 3050.0, 1875.0]),
+    (2018, [10000.0, 18000.0, 16000.0, 9600.0, 5760.0]),
+    (2019, [10100.0, 18100.0, 16100.0, 9700.0, 5760.0]),
+    (2021, [10200.0, 18200.0, 16400.0, 9800.0, 5860.0]),
+    (2022, [11200.0, 19200.0, 18000.0, 10800.0, 6460.0]),
+    (2023, [12200.0, 20200.0, 19500.0, 11700.0, 6960.0]),
+    (2024, [12400.0, 20400.0, 19800.0, 11900.0, 7160.0]),
+    (2025, [12200.0, 20200.0, 19600.0, 11800.0, 7060.0]),
+];
+
+/// Bonus depreciation rate by the date placed in service (IRC §168(k)),
+/// as (first date, percent). Under the 2025 reconciliation act property
+/// acquired after January 19, 2025 is back at 100%; the date placed in
+/// service stands in for the acquisition date here.
+const BONUS_PHASE_DOWN: &[(&str, f64)] = &[
+    ("2008-01-01", 50.0),
+    ("2010-09-09", 100.0),
+    ("2012-01-01", 50.0),
+    ("2017-09-28", 100.0),
+    ("2023-01-01", 80.0),
+    ("2024-01-01", 60.0),
+    ("2025-01-01", 40.0),
+    ("2025-01-20", 100.0),
+];
+
+/// Longest schedule projected for a units-of-production asset, so a very low
+/// average usage can't produce an unbounded schedule
+const MAX_PROJECTION_YEARS: i32 = 100;
+
+/// Generate the depreciation schedule for an asset using its method, with one
+/// row per fiscal year through the year of disposal. `usage` is the recorded
+/// usage for units-of-production assets; other methods ignore it.
+pub fn generate_schedule(
+    asset: &Asset,
+    usage: &[UsageEntry],
+    fiscal: FiscalYear,
+) -> Vec<DepreciationEntry> {
+    generate_revised_schedule(asset, usage, &[], &[], &[], &[], fiscal)
+}
+
+/// A revision to the financial book schedule, applied in date order
+enum Revision<'a> {
+    Estimate(&'a EstimateChange),
+    Impairment(&'a Impairment),
+    Revaluation(&'a Revaluation),
+}
+
+/// Generate the schedule with changes in estimate, impairments and
+/// revaluations applied in date order. Each change in estimate leaves the
+/// years before it as they were and spreads the book value left over the
+/// rest of the revised life; each impairment writes the carrying amount down
+/// in its year and each revaluation restates it to fair value at the end of
+/// its year, and the depreciation after either is scaled to what is left.
+///
+/// `business_use` is the business use recorded for listed property in the
+/// years after it was placed in service, and each year is depreciated at
+/// that year's percentage. MACRS GDS listed property whose business use
+/// drops to 50% or less is depreciated under ADS from that year as though it
+/// had been from the start, and the excess depreciation recaptured is added
+/// back to its basis.
+pub fn generate_revised_schedule(
+    asset: &Asset,
+    usage: &[UsageEntry],
+    business_use: &[BusinessUseEntry],
+    estimates: &[EstimateChange],
+    impairments: &[Impairment],
+    revaluations: &[Revaluation],
+    fiscal: FiscalYear,
+) -> Vec<DepreciationEntry> {
+    let amortized;
+    let asset = if is_intangible(asset) {
+        amortized = amortization_terms(asset);
+        &amortized
+    } else {
+        asset
+    };
+    let (start_year, month) = service_period(asset, fiscal);
+
+    // Only the business use of listed property is depreciated, as of the
+    // year placed in service
+    let placed_in_service_use = business_use_percentage(asset);
+    let mut basis = asset.clone();
+    basis.cost = asset.cost.percent(placed_in_service_use);
+    basis.salvage_value = asset.salvage_value.percent(placed_in_service_use);
+
+    // Section 179 and then bonus depreciation are expensed in the first
+    // year and come off the basis before regular depreciation
+    let section_179 = section_179_deduction(asset);
+    let salvage = if is_macrs(asset.method.as_deref()) {
+        Money::ZERO
+    } else {
+        basis.salvage_value
+    };
+    let bonus = (basis.cost - section_179 - salvage)
+        .max(Money::ZERO)
+        .percent(bonus_rate(asset));
+    let expensed = section_179 + bonus;
+    basis.cost -= expensed;
+
+    let mut expenses = match basis.method.as_deref() {
+        Some(METHOD_UNITS_OF_PRODUCTION) => units_of_production_expenses(&basis, usage, start_year),
+        Some(METHOD_MACRS_GDS) => macrs_expenses(&basis, month),
+        Some(METHOD_MACRS_ADS) => ads_expenses(&basis, month),
+        Some(METHOD_DECLINING_200) => declining_balance_expenses(&basis, 2.0, month),
+        Some(METHOD_DECLINING_150) => declining_balance_expenses(&basis, 1.5, month),
+        Some(METHOD_SUM_OF_YEARS_DIGITS) => sum_of_years_digits_expenses(&basis, month),
+        _ => straight_line_expenses(&basis, month),
+    };
+
+    // Later years follow the business use recorded for them
+    if placed_in_service_use > 0.0 {
+        for (i, expense) in expenses.iter_mut().enumerate().skip(1) {
+            let year_use = business_use_in(asset, business_use, start_year + i as i32);
+            *expense = expense.scale(year_use / placed_in_service_use);
+        }
+    }
+
+    let mut revisions: Vec<(&str, Revision)> = estimates
+        .iter()
+        .map(|change| (change.effective_date.as_str(), Revision::Estimate(change)))
+        .chain(impairments.iter().map(|impairment| {
+            (
+                impairment.impairment_date.as_str(),
+                Revision::Impairment(impairment),
+            )
+        }))
+        .chain(revaluations.iter().map(|revaluation| {
+            (
+                revaluation.revaluation_date.as_str(),
+                Revision::Revaluation(revaluation),
+            )
+        }))
+        .collect();
+    revisions.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut losses: Vec<Money> = Vec::new();
+    let mut adjustments: Vec<Money> = Vec::new();
+    let mut current_salvage = salvage;
+    for (date, revision) in revisions {
+        let Some(year) = fiscal.year_of(date) else {
+            continue;
+        };
+        let index = usize::try_from(year - start_year).unwrap_or(0);
+
+        // The amount carried, after the losses and restatements so far
+        let mut carried = basis.clone();
+        carried.cost = basis.cost - losses.iter().copied().sum::<Money>()
+            + adjustments.iter().copied().sum::<Money>();
+        match revision {
+            Revision::Estimate(change) => {
+                revise_estimate(&carried, &mut expenses, (start_year, month), year, change);
+                current_salvage = change.salvage_value;
+            }
+            Revision::Impairment(impairment) => {
+                impair(
+                    &carried,
+                    &mut expenses,
+                    &mut losses,
+                    index,
+                    impairment.amount,
+                    current_salvage,
+                );
+            }
+            Revision::Revaluation(revaluation) => {
+                revalue(
+                    &carried,
+                    &mut expenses,
+                    &mut adjustments,
+                    index,
+                    revaluation.fair_value,
+                    current_salvage,
+                );
+            }
+        }
+    }
+
+    if let Some(disposal) = disposal_period(asset, fiscal) {
+        prorate_disposal_year(&basis, &mut expenses, (start_year, month), disposal);
+        losses.truncate(expenses.len());
+        adjustments.truncate(expenses.len());
+    }
+
+    if expensed > Money::ZERO {
+        if expenses.iter().all(|expense| *expense == Money::ZERO) {
+            expenses = vec![expensed];
+        } else {
+            expenses[0] += expensed;
+        }
+    }
+
+    if asset.passenger_auto && is_macrs(asset.method.as_deref()) {
+        let disposed = disposal_period(asset, fiscal).is_some();
+        limit_passenger_auto(
+            asset,
+            &mut expenses,
+            |index| business_use_in(asset, business_use, start_year + index as i32),
+            bonus > Money::ZERO,
+            disposed,
+        );
+    }
+
+    if let Some(index) =
+        business_use_drop(asset, business_use, start_year).filter(|index| *index < expenses.len())
+    {
+        let ads: Vec<Money> = generate_revised_schedule(
+            &ads_from_start(asset),
+            usage,
+            business_use,
+            &[],
+            &[],
+            &[],
+            fiscal,
+        )
+        .iter()
+        .map(|entry| entry.depreciation_expense)
+        .collect();
+        let taken: Money = expenses[..index].iter().copied().sum();
+        let allowed: Money = ads.iter().take(index).copied().sum();
+        expenses.truncate(index);
+        expenses.extend(ads.iter().skip(index));
+        if adjustments.len() <= index {
+            adjustments.resize(index + 1, Money::ZERO);
+        }
+        adjustments[index] += (taken - allowed).max(Money::ZERO);
+    }
+
+    build_schedule(asset, &expenses, &losses, &adjustments, start_year)
+}
+
+/// The asset as depreciated in the financial book. Section 179 and bonus
+/// depreciation are tax elections, so the financial book takes neither, and
+/// they stay with the tax books.
+pub fn financial_book(asset: &Asset) -> Asset {
+    let mut asset = asset.clone();
+    asset.section_179_amount = None;
+    asset.bonus_percentage = Some(0.0);
+    asset
+}
+
+/// The asset as depreciated in another book, with the book's overrides in
+/// place of the asset's own settings. Without a life of its own, the federal
+/// book amortizes an intangible over the §197 period rather than the
+/// asset's life.
+pub fn apply_book(asset: &Asset, book: &AssetBook) -> Asset {
+    let mut asset = asset.clone();
+    if book.method.is_some() {
+        asset.method = book.method.clone();
+    }
+    if book.convention.is_some() {
+        asset.convention = book.convention.clone();
+    }
+    if let Some(years) = book.useful_life_years {
+        asset.useful_life_years = years;
+    } else if book.book == BOOK_FEDERAL && is_intangible(&asset) {
+        asset.useful_life_years = SECTION_197_YEARS;
+    }
+    if book.property_class.is_some() {
+        asset.property_class = book.property_class.clone();
+    }
+    if book.section_179_amount.is_some() {
+        asset.section_179_amount = book.section_179_amount;
+    }
+    if book.bonus_percentage.is_some() {
+        asset.bonus_percentage = book.bonus_percentage;
+    }
+    asset
+}
+
+/// Whether the asset is an intangible amortized rather than depreciated
+pub fn is_intangible(asset: &Asset) -> bool {
+    asset.asset_kind.as_deref() == Some(KIND_INTANGIBLE)
+}
+
+/// An intangible on the terms it is amortized under: straight-line to zero
+/// over its life from the month acquired, with no first-year expensing
+fn amortization_terms(asset: &Asset) -> Asset {
+    let mut asset = asset.clone();
+    asset.method = Some(METHOD_STRAIGHT_LINE.to_string());
+    asset.convention = Some(CONVENTION_FULL_MONTH.to_string());
+    asset.salvage_value = Money::ZERO;
+    asset.section_179_amount = None;
+    asset.bonus_percentage = Some(0.0);
+    asset.business_use_percentage = None;
+    asset.passenger_auto = false;
+    asset
+}
+
+/// Section 179 amount elected for the asset, limited to its cost in
+/// business use
+pub fn section_179_deduction(asset: &Asset) -> Money {
+    let business_cost = asset.cost.percent(business_use_percentage(asset));
+    asset
+        .section_179_amount
+        .unwrap_or(Money::ZERO)
+        .clamp(Money::ZERO, business_cost.max(Money::ZERO))
+}
+
+/// Business use of listed property as a percentage, or 100 for property
+/// that isn't listed
+pub fn business_use_percentage(asset: &Asset) -> f64 {
+    asset
+        .business_use_percentage
+        .map_or(100.0, |percentage| percentage.clamp(0.0, 100.0))
+}
+
+/// Business use of listed property in fiscal `year`: the percentage recorded
+/// for the latest year up to it, or the asset's own percentage before any
+fn business_use_in(asset: &Asset, business_use: &[BusinessUseEntry], year: i32) -> f64 {
+    if asset.business_use_percentage.is_none() {
+        return 100.0;
+    }
+    business_use
+        .iter()
+        .filter(|entry| entry.year <= year)
+        .max_by_key(|entry| entry.year)
+        .map_or_else(
+            || business_use_percentage(asset),
+            |entry| entry.percentage.clamp(0.0, 100.0),
+        )
+}
+
+/// Index of the first year after the one placed in service in which MACRS
+/// GDS listed property was used 50% or less for business, if it has been
+fn business_use_drop(
+    asset: &Asset,
+    business_use: &[BusinessUseEntry],
+    start_year: i32,
+) -> Option<usize> {
+    if asset.business_use_percentage.is_none() || asset.method.as_deref() != Some(METHOD_MACRS_GDS)
+    {
+        return None;
+    }
+    business_use
+        .iter()
+        .filter(|entry| entry.year > start_year && entry.percentage <= 50.0)
+        .map(|entry| entry.year)
+        .min()
+        .and_then(|year| usize::try_from(year - start_year).ok())
+}
+
+/// The asset as depreciated under ADS straight-line from the start, without
+/// Section 179 or bonus
+fn ads_from_start(asset: &Asset) -> Asset {
+    let mut ads = asset.clone();
+    ads.method = Some(METHOD_MACRS_ADS.to_string());
+    ads.section_179_amount = None;
+    ads.bonus_percentage = Some(0.0);
+    ads
+}
+
+/// Bonus depreciation percentage for an asset: its own percentage when set,
+/// otherwise the phase-down rate for its year placed in service if it is
+/// MACRS property with a recovery period of 20 years or less
+pub fn bonus_rate(asset: &Asset) -> f64 {
+    if let Some(percentage) = asset.bonus_percentage {
+        return percentage.clamp(0.0, 100.0);
+    }
+    if asset.method.as_deref() != Some(METHOD_MACRS_GDS)
+        || asset.property_class.is_none()
+        || is_real_property(asset)
+    {
+        return 0.0;
+    }
+
+    let date = asset.date_placed_in_service.trim();
+    BONUS_PHASE_DOWN
+        .iter()
+        .rev()
+        .find(|(from, _)| date >= *from)
+        .map(|(_, rate)| *rate)
+        .unwrap_or(0.0)
+}
+
+/// Section 179 dollar limit after the phase-out: reduced dollar for dollar
+/// by the cost of qualifying property placed in service over the threshold
+pub fn section_179_allowed_limit(
+    dollar_limit: Money,
+    phase_out_threshold: Money,
+    qualifying_cost: Money,
+) -> Money {
+    let reduction = (qualifying_cost - phase_out_threshold).max(Money::ZERO);
+    (dollar_limit - reduction).max(Money::ZERO)
+}
+
+/// Straight-line: (cost - salvage) spread evenly over the useful life.
+/// A partial first year under the asset's convention pushes the remainder
+/// of the final year into one extra fiscal year.
+fn straight_line_expenses(asset: &Asset, month: u32) -> Vec<Money> {
     // Guard against division by zero
     if asset.useful_life_years < 1 {
         return Vec::new();
     }
 
-    let asset_id = asset.id.unwrap_or(0);
+    let life = asset.useful_life_years;
     let depreciable_base = asset.cost - asset.salvage_value;
-    let annual_depreciation = depreciable_base / asset.useful_life_years as f64;
+    let annual_depreciation = depreciable_base.mul_div(1, life as i64);
+    let first_year = first_year_fraction(asset.convention.as_deref(), month);
 
-    // Parse the service date to get the starting year
-    let start_year: i32 = asset
-        .date_placed_in_service
-        .split('-')
-        .next()
-        .and_then(|y| y.parse().ok())
-        .unwrap_or(2024);
+    let years = if first_year < 1.0 { life + 1 } else { life };
 
-    let mut schedule = Vec::new();
-    let mut accumulated = 0.0;
+    let mut expenses = vec![annual_depreciation; years as usize];
+    expenses[0] = depreciable_base.scale(first_year / life as f64);
+    // Last year absorbs the rounding remainder
+    let last = expenses.len() - 1;
+    expenses[last] = depreciable_base - expenses[..last].iter().sum::<Money>();
+
+    expenses
+}
+
+/// Declining balance at `factor` / life, switching to straight-line over the
+/// remaining life in the first year that gives the larger deduction. Book
+/// value never drops below salvage.
+fn declining_balance_expenses(asset: &Asset, factor: f64, month: u32) -> Vec<Money> {
+    // Guard against division by zero
+    if asset.useful_life_years < 1 {
+        return Vec::new();
+    }
+
+    let life = asset.useful_life_years as f64;
+    let rate = factor / life;
+    let first_year = first_year_fraction(asset.convention.as_deref(), month);
+
+    let mut expenses = Vec::new();
     let mut book_value = asset.cost;
+    let mut elapsed = 0.0;
 
-    for i in 0..asset.useful_life_years {
-        let year = start_year + i;
-        let beginning_value = book_value;
+    while elapsed < life {
+        let fraction = if expenses.is_empty() { first_year } else { 1.0 };
+        let remaining_life = life - elapsed;
+        let depreciable = book_value - asset.salvage_value;
 
-        // Last year might have rounding adjustment
-        let expense = if i == asset.useful_life_years - 1 {
-            book_value - asset.salvage_value
+        let expense = if remaining_life <= fraction {
+            depreciable
         } else {
-            annual_depreciation
+            let declining = book_value.scale(rate * fraction);
+            let straight_line = depreciable.scale(fraction / remaining_life);
+            declining.max(straight_line).min(depreciable)
         };
 
-        accumulated += expense;
+        expenses.push(expense);
         book_value -= expense;
+        elapsed += fraction;
+    }
+
+    expenses
+}
+
+/// Sum-of-the-years'-digits: each year of life takes remaining life / (n(n+1)/2)
+/// of (cost - salvage). With a partial first year, every fiscal year blends
+/// the tail of one life year with the start of the next.
+fn sum_of_years_digits_expenses(asset: &Asset, month: u32) -> Vec<Money> {
+    // Guard against division by zero
+    if asset.useful_life_years < 1 {
+        return Vec::new();
+    }
+
+    let life = asset.useful_life_years as i64;
+    let depreciable_base = asset.cost - asset.salvage_value;
+    let digits_sum = life * (life + 1) / 2;
+    let life_year_amounts: Vec<Money> = (0..life)
+        .map(|i| depreciable_base.mul_div(life - i, digits_sum))
+        .collect();
+
+    let first_year = first_year_fraction(asset.convention.as_deref(), month);
+    let mut expenses: Vec<Money> = (0..life as usize)
+        .map(|i| {
+            let carried = if i == 0 {
+                Money::ZERO
+            } else {
+                life_year_amounts[i - 1]
+            };
+            carried.scale(1.0 - first_year) + life_year_amounts[i].scale(first_year)
+        })
+        .collect();
+    if first_year < 1.0 {
+        expenses.push(Money::ZERO);
+    }
+
+    // Last year absorbs the rounding remainder
+    let last = expenses.len() - 1;
+    expenses[last] = depreciable_base - expenses[..last].iter().sum::<Money>();
+
+    expenses
+}
+
+/// Units-of-production: (cost - salvage) / total expected units for every unit
+/// used. Years up to the last one with recorded usage use the actual units;
+/// later years are projected at the average annual usage so far, or at total
+/// units / useful life before any usage has been logged.
+fn units_of_production_expenses(
+    asset: &Asset,
+    usage: &[UsageEntry],
+    start_year: i32,
+) -> Vec<Money> {
+    let total_units = match asset.total_units {
+        Some(units) if units > 0.0 => units,
+        _ => return Vec::new(),
+    };
+
+    let depreciable_base = asset.cost - asset.salvage_value;
+
+    let units_in = |year: i32| -> f64 {
+        usage
+            .iter()
+            .filter(|entry| entry.year == year)
+            .map(|entry| entry.units)
+            .sum()
+    };
+    let last_recorded_year = usage
+        .iter()
+        .map(|entry| entry.year)
+        .filter(|year| *year >= start_year)
+        .max();
+    let average_units = match last_recorded_year {
+        Some(last) => {
+            (start_year..=last).map(units_in).sum::<f64>() / (last - start_year + 1) as f64
+        }
+        None if asset.useful_life_years > 0 => total_units / asset.useful_life_years as f64,
+        None => 0.0,
+    };
+
+    let mut expenses = Vec::new();
+    let mut remaining = depreciable_base;
+    let mut year = start_year;
+
+    while remaining > Money::ZERO && year < start_year + MAX_PROJECTION_YEARS {
+        let units = match last_recorded_year {
+            Some(last) if year <= last => units_in(year),
+            _ if average_units > 0.0 => average_units,
+            _ => break,
+        };
+        let expense = depreciable_base.scale(units / total_units).min(remaining);
+
+        expenses.push(expense);
+        remaining -= expense;
+        year += 1;
+    }
+
+    expenses
+}
+
+/// MACRS under either the general or the alternative depreciation system
+pub fn is_macrs(method: Option<&str>) -> bool {
+    matches!(method, Some(METHOD_MACRS_GDS | METHOD_MACRS_ADS))
+}
+
+/// Residential rental (27.5-year) or nonresidential real (39-year) property
+pub fn is_real_property(asset: &Asset) -> bool {
+    matches!(
+        asset.property_class.as_deref().map(str::trim),
+        Some("27.5" | "39")
+    )
+}
+
+/// Portion of a full year's depreciation taken in the year placed in service,
+/// for an asset placed in service in fiscal `month`
+fn first_year_fraction(convention: Option<&str>, month: u32) -> f64 {
+    (24 - convention_point(convention, month)) as f64 / 24.0
+}
+
+/// When in fiscal `month` the convention treats an asset as placed in service
+/// or disposed of, in half months from the start of the fiscal year. The full
+/// year convention takes a full year when placed in service and none in the
+/// year of disposal.
+fn convention_point(convention: Option<&str>, month: u32) -> i64 {
+    let month = month.clamp(1, 12);
+    match convention {
+        Some(CONVENTION_HALF_YEAR) => 12,
+        Some(CONVENTION_MID_QUARTER) => 6 * service_quarter(month) as i64 - 3,
+        Some(CONVENTION_MID_MONTH) => 2 * month as i64 - 1,
+        Some(CONVENTION_FULL_MONTH) => 2 * (month as i64 - 1),
+        _ => 0,
+    }
+}
+
+/// The convention that shapes an asset's first and last years. MACRS uses
+/// mid-month for real property, and half-year unless mid-quarter applies.
+/// Intangibles are amortized from the month acquired.
+fn effective_convention(asset: &Asset) -> Option<&str> {
+    if is_intangible(asset) {
+        Some(CONVENTION_FULL_MONTH)
+    } else if !is_macrs(asset.method.as_deref()) {
+        asset.convention.as_deref()
+    } else if is_real_property(asset) {
+        Some(CONVENTION_MID_MONTH)
+    } else if asset.convention.as_deref() == Some(CONVENTION_MID_QUARTER) {
+        Some(CONVENTION_MID_QUARTER)
+    } else {
+        Some(CONVENTION_HALF_YEAR)
+    }
+}
+
+/// MACRS GDS: cost recovered by the published IRS percentage tables for
+/// the asset's property class. Salvage value is ignored under MACRS.
+fn macrs_expenses(asset: &Asset, month: u32) -> Vec<Money> {
+    let mid_quarter = asset.convention.as_deref() == Some(CONVENTION_MID_QUARTER);
+    let rates = match asset.property_class.as_deref().map(str::trim) {
+        Some(class) => macrs_rates(class, month, mid_quarter),
+        None => Vec::new(),
+    };
+    if rates.is_empty() {
+        return Vec::new();
+    }
+
+    let mut expenses: Vec<Money> = rates.iter().map(|rate| asset.cost.percent(*rate)).collect();
+    // Final year takes whatever the rounded table percentages left over
+    let last = expenses.len() - 1;
+    expenses[last] = asset.cost - expenses[..last].iter().sum::<Money>();
+
+    expenses
+}
+
+/// MACRS ADS: straight-line over the ADS recovery period for the asset's
+/// property class, under the same conventions as GDS and with no salvage
+fn ads_expenses(asset: &Asset, month: u32) -> Vec<Money> {
+    let Some(period) = ads_recovery_period(asset) else {
+        return Vec::new();
+    };
+
+    let mut ads = asset.clone();
+    ads.useful_life_years = period;
+    ads.salvage_value = Money::ZERO;
+    ads.convention = effective_convention(asset).map(str::to_string);
+    straight_line_expenses(&ads, month)
+}
+
+/// Hold a passenger automobile's deductions, including Section 179 and bonus
+/// in the first year, to the §280F limits for its year placed in service,
+/// scaled by each year's business use. What the limits hold back is deducted
+/// after the recovery period, up to the later-year limit each year, unless
+/// the asset has been disposed of.
+fn limit_passenger_auto(
+    asset: &Asset,
+    expenses: &mut Vec<Money>,
+    business_use: impl Fn(usize) -> f64,
+    bonus: bool,
+    disposed: bool,
+) {
+    let year: i32 = asset
+        .date_placed_in_service
+        .trim()
+        .get(..4)
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(0);
+    let limits = PASSENGER_AUTO_LIMITS
+        .iter()
+        .rev()
+        .find(|(from, _)| year >= *from)
+        .unwrap_or(&PASSENGER_AUTO_LIMITS[0])
+        .1;
+    let limit = |index: usize| {
+        let dollars = match index {
+            0 if bonus => limits[1],
+            0 => limits[0],
+            1 => limits[2],
+            2 => limits[3],
+            _ => limits[4],
+        };
+        Money::from_dollars(dollars).percent(business_use(index))
+    };
+
+    let mut held_back = Money::ZERO;
+    for (index, expense) in expenses.iter_mut().enumerate() {
+        let allowed = (*expense).min(limit(index));
+        held_back += *expense - allowed;
+        *expense = allowed;
+    }
+    while !disposed && held_back > Money::ZERO && limit(expenses.len()) > Money::ZERO {
+        let expense = held_back.min(limit(expenses.len()));
+        expenses.push(expense);
+        held_back -= expense;
+    }
+}
+
+/// ADS recovery period for the asset's property class, if it has one
+fn ads_recovery_period(asset: &Asset) -> Option<i32> {
+    let class = asset.property_class.as_deref().map(str::trim)?;
+    if class == "27.5" && asset.date_placed_in_service.trim() < "2018-01-01" {
+        return Some(40);
+    }
+    ADS_RECOVERY_PERIODS
+        .iter()
+        .find(|(c, _)| *c == class)
+        .map(|(_, period)| *period)
+}
+
+/// MACRS percentages for a property class placed in service in fiscal `month`.
+/// Real property always uses mid-month; personal property uses half-year
+/// unless the mid-quarter convention applies.
+fn macrs_rates(property_class: &str, month: u32, mid_quarter: bool) -> Vec<f64> {
+    let personal_table = if mid_quarter {
+        MACRS_MID_QUARTER[(service_quarter(month) - 1) as usize]
+    } else {
+        MACRS_HALF_YEAR
+    };
+
+    match property_class {
+        "27.5" => real_property_rates(&RESIDENTIAL_FIRST_YEAR, month, |year| {
+            // Table A-6 alternates 3.636/3.637 from year 10 on, phased by
+            // whether the asset went into service in the first or second half
+            if year >= 10 && (year % 2 == 0) == (month <= 6) {
+                3637
+            } else {
+                3636
+            }
+        }),
+        "39" => real_property_rates(&NONRESIDENTIAL_FIRST_YEAR, month, |_| 2564),
+        _ => personal_table
+            .iter()
+            .find(|(class, _)| *class == property_class)
+            .map(|(_, rates)| rates.to_vec())
+            .unwrap_or_default(),
+    }
+}
+
+/// Expand a mid-month straight-line real property table into yearly percentages
+fn real_property_rates(
+    first_year: &[u32; 12],
+    month: u32,
+    full_year: impl Fn(u32) -> u32,
+) -> Vec<f64> {
+    let first = first_year[(month.clamp(1, 12) - 1) as usize];
+    let mut rates = vec![first];
+    let mut remaining = 100_000 - first;
+    let mut year = 2;
+    while remaining > 0 {
+        let rate = full_year(year).min(remaining);
+        rates.push(rate);
+        remaining -= rate;
+        year += 1;
+    }
+    rates.into_iter().map(|r| r as f64 / 1000.0).collect()
+}
+
+/// Apply a change in estimate from fiscal `effective_year` on. Earlier years
+/// stand; the book value left at the start of the effective year, less the
+/// new salvage value, is spread straight-line over what remains of the new
+/// useful life, counted in half months from the convention's service point.
+/// A life that has already run out takes it all in the effective year.
+fn revise_estimate(
+    asset: &Asset,
+    expenses: &mut Vec<Money>,
+    (start_year, month): (i32, u32),
+    effective_year: i32,
+    change: &EstimateChange,
+) {
+    let index = usize::try_from(effective_year - start_year).unwrap_or(0);
+    expenses.resize(index, Money::ZERO);
+
+    let taken: Money = expenses.iter().copied().sum();
+    let mut remaining = (asset.cost - taken - change.salvage_value).max(Money::ZERO);
+
+    let first_year_halves = 24 - convention_point(effective_convention(asset), month);
+    let elapsed = if index == 0 {
+        0
+    } else {
+        first_year_halves + 24 * (index as i64 - 1)
+    };
+    let total_halves = (i64::from(change.useful_life_years) * 24 - elapsed).max(1);
+    let depreciable = remaining;
+
+    // Each year is rounded to the cent and the last takes what is left, so
+    // the schedule lands on the new salvage value exactly
+    let mut halves_left = total_halves;
+    while remaining > Money::ZERO && halves_left > 0 {
+        let available = if expenses.is_empty() {
+            first_year_halves
+        } else {
+            24
+        };
+        let halves = available.min(halves_left);
+        let expense = if halves == halves_left {
+            remaining
+        } else {
+            depreciable.mul_div(halves, total_halves)
+        };
+        expenses.push(expense);
+        remaining -= expense;
+        halves_left -= halves;
+    }
+}
+
+/// Write `amount` off the carrying amount in the year at `index` and scale the
+/// expense of the years after it down to the book value left over salvage,
+/// keeping their pattern. The last year takes what rounding leaves.
+fn impair(
+    asset: &Asset,
+    expenses: &mut Vec<Money>,
+    losses: &mut Vec<Money>,
+    index: usize,
+    amount: Money,
+    salvage: Money,
+) {
+    if expenses.len() <= index {
+        expenses.resize(index + 1, Money::ZERO);
+    }
+    if losses.len() <= index {
+        losses.resize(index + 1, Money::ZERO);
+    }
+    losses[index] += amount;
+
+    let taken: Money = expenses[..=index].iter().copied().sum();
+    let left = (asset.cost - taken - amount - salvage).max(Money::ZERO);
+    let scheduled: Money = expenses[index + 1..].iter().copied().sum();
+    if scheduled > left {
+        rescale(expenses, index, left);
+    }
+}
+
+/// Restate the carrying amount at the end of the year at `index` to
+/// `fair_value` and scale the expense of the years after it to the revalued
+/// amount over salvage, up or down, keeping their pattern. Once the schedule
+/// has run out the revalued amount is carried without further depreciation.
+fn revalue(
+    asset: &Asset,
+    expenses: &mut Vec<Money>,
+    adjustments: &mut Vec<Money>,
+    index: usize,
+    fair_value: Money,
+    salvage: Money,
+) {
+    if expenses.len() <= index {
+        expenses.resize(index + 1, Money::ZERO);
+    }
+    if adjustments.len() <= index {
+        adjustments.resize(index + 1, Money::ZERO);
+    }
+
+    let taken: Money = expenses[..=index].iter().copied().sum();
+    adjustments[index] += fair_value - (asset.cost - taken);
+    rescale(expenses, index, (fair_value - salvage).max(Money::ZERO));
+}
+
+/// Scale the expense of the years after `index` to add up to `left`, keeping
+/// their pattern. The last year takes what rounding leaves, and years left
+/// with nothing are dropped from the end.
+fn rescale(expenses: &mut Vec<Money>, index: usize, left: Money) {
+    let scheduled: Money = expenses[index + 1..].iter().copied().sum();
+    if scheduled == Money::ZERO {
+        return;
+    }
+
+    let mut remaining = left;
+    let last = expenses.len() - 1;
+    for (i, expense) in expenses.iter_mut().enumerate().skip(index + 1) {
+        let scaled = if i == last {
+            remaining
+        } else {
+            expense.mul_div(left.cents(), scheduled.cents())
+        };
+        *expense = scaled;
+        remaining -= scaled;
+    }
+    while expenses.len() > index + 1 && expenses.last() == Some(&Money::ZERO) {
+        expenses.pop();
+    }
+}
+
+/// Cut yearly expenses off at disposal: nothing after the fiscal year of
+/// disposal, and in that year only the part of the year the schedule provides
+/// for that falls before the convention's disposal point. Units-of-production
+/// follows the usage recorded for the year, so its last year is left whole.
+fn prorate_disposal_year(
+    asset: &Asset,
+    expenses: &mut Vec<Money>,
+    (start_year, month): (i32, u32),
+    (disposal_year, disposal_month): (i32, u32),
+) {
+    let Ok(index) = usize::try_from(disposal_year - start_year) else {
+        expenses.clear();
+        return;
+    };
+    if index >= expenses.len() {
+        return;
+    }
+    let final_year = index == expenses.len() - 1;
+    expenses.truncate(index + 1);
+    if asset.method.as_deref() == Some(METHOD_UNITS_OF_PRODUCTION) {
+        return;
+    }
+
+    // Half months of the year the scheduled amount covers
+    let convention = effective_convention(asset);
+    let service = convention_point(convention, month);
+    let (from, to) = if index == 0 {
+        (service, 24)
+    } else if final_year && service > 0 {
+        (0, service)
+    } else {
+        (0, 24)
+    };
+    if to <= from {
+        return;
+    }
+
+    let held = (convention_point(convention, disposal_month).min(to) - from).max(0);
+    expenses[index] = expenses[index].mul_div(held, to - from);
+}
+
+/// Turn yearly expense amounts, impairment losses and revaluation
+/// adjustments into schedule rows starting in `start_year`
+fn build_schedule(
+    asset: &Asset,
+    expenses: &[Money],
+    losses: &[Money],
+    adjustments: &[Money],
+    start_year: i32,
+) -> Vec<DepreciationEntry> {
+    let asset_id = asset.id.unwrap_or(0);
+
+    let mut schedule = Vec::new();
+    let mut accumulated = Money::ZERO;
+    let mut book_value = asset.cost;
 
-        // Round to 2 decimal places
-        let expense = (expense * 100.0).round() / 100.0;
-        let accumulated = (accumulated * 100.0).round() / 100.0;
-        let ending_value = (book_value * 100.0).round() / 100.0;
+    let years = expenses.len().max(losses.len()).max(adjustments.len());
+    for i in 0..years {
+        let beginning_value = book_value;
+        let expense = expenses.get(i).copied().unwrap_or(Money::ZERO);
+        let loss = losses.get(i).copied().unwrap_or(Money::ZERO);
+        let adjustment = adjustments.get(i).copied().unwrap_or(Money::ZERO);
+
+        accumulated += expense;
+        book_value = book_value - expense - loss + adjustment;
 
         schedule.push(DepreciationEntry {
             id: None,
             asset_id,
-            year,
-            beginning_book_value: (beginning_value * 100.0).round() / 100.0,
+            year: start_year + i as i32,
+            beginning_book_value: beginning_value,
             depreciation_expense: expense,
+            impairment_loss: loss,
+            revaluation_adjustment: adjustment,
             accumulated_depreciation: accumulated,
-            ending_book_value: ending_value,
+            ending_book_value: book_value,
         });
     }
 
     schedule
 }
 
-/// Calculate current book value for an asset as of a given year
-pub fn current_book_value(asset: &Asset, as_of_year: i32) -> f64 {
-    // Guard against division by zero
-    if asset.useful_life_years < 1 {
-        return asset.cost;
+/// Spread each year of a schedule over its fiscal months. The first year runs
+/// from the month placed in service, with that month at half weight under the
+/// mid-month convention. A final partial year left by the convention covers
+/// what the first year didn't, from the start of the year, and the year of
+/// disposal runs through the month of disposal. Months are rounded to the
+/// cent and each year's last month takes the remainder.
+pub fn monthly_schedule(
+    asset: &Asset,
+    schedule: &[DepreciationEntry],
+    fiscal: FiscalYear,
+) -> Vec<DepreciationPeriod> {
+    let asset_id = asset.id.unwrap_or(0);
+    let (_, month) = service_period(asset, fiscal);
+    let convention = effective_convention(asset);
+    let disposal = disposal_period(asset, fiscal);
+
+    // Month weights are counted in half months
+    let first_year_halves = if asset.method.as_deref() == Some(METHOD_UNITS_OF_PRODUCTION) {
+        24
+    } else {
+        24 - convention_point(convention, month)
+    };
+
+    let mut periods = Vec::new();
+    for (i, entry) in schedule.iter().enumerate() {
+        let disposal_month = disposal
+            .filter(|(year, _)| *year == entry.year)
+            .map(|(_, month)| month);
+
+        let mut weights: Vec<(u32, i64)> = (1..=12)
+            .map(|period| {
+                let weight = if i == 0 {
+                    if period < month {
+                        0
+                    } else if period == month && convention == Some(CONVENTION_MID_MONTH) {
+                        1
+                    } else {
+                        2
+                    }
+                } else if disposal_month.is_none()
+                    && i == schedule.len() - 1
+                    && first_year_halves < 24
+                {
+                    (24 - first_year_halves - 2 * (period as i64 - 1)).clamp(0, 2)
+                } else {
+                    2
+                };
+                let held = match disposal_month {
+                    Some(disposed) if period > disposed => 0,
+                    Some(disposed) if period == disposed => match convention {
+                        Some(CONVENTION_MID_MONTH) => 1,
+                        Some(CONVENTION_FULL_MONTH) => 0,
+                        _ => 2,
+                    },
+                    _ => 2,
+                };
+                (period, weight.min(held))
+            })
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+
+        // Disposed before the first month with a weight: the year's amount
+        // goes in the month of disposal
+        if weights.is_empty() {
+            weights.push((disposal_month.unwrap_or(month), 1));
+        }
+
+        let total: i64 = weights.iter().map(|(_, weight)| weight).sum();
+        let mut allocated = Money::ZERO;
+        for (index, (period, weight)) in weights.iter().enumerate() {
+            let expense = if index == weights.len() - 1 {
+                entry.depreciation_expense - allocated
+            } else {
+                entry.depreciation_expense.mul_div(*weight, total)
+            };
+            allocated += expense;
+
+            periods.push(DepreciationPeriod {
+                id: None,
+                asset_id,
+                year: entry.year,
+                period: *period,
+                period_start: fiscal.period_start(entry.year, *period),
+                depreciation_expense: expense,
+            });
+        }
     }
 
-    let start_year: i32 = asset
-        .date_placed_in_service
-        .split('-')
-        .next()
-        .and_then(|y| y.parse().ok())
-        .unwrap_or(2024);
+    periods
+}
 
-    let years_depreciated = (as_of_year - start_year + 1).max(0).min(asset.useful_life_years);
-    let annual_depreciation = (asset.cost - asset.salvage_value) / asset.useful_life_years as f64;
-    let accumulated = annual_depreciation * years_depreciated as f64;
+/// Roll an asset's schedule up with the schedules of its improvements, year
+/// by year, under the first asset's ID. Each counts from its first year. Once
+/// a schedule has run out, what it leaves is carried into later years, unless
+/// that asset has been disposed of.
+pub fn combine_schedules(schedules: &[(&Asset, Vec<DepreciationEntry>)]) -> Vec<DepreciationEntry> {
+    let years = schedules
+        .iter()
+        .flat_map(|(_, schedule)| schedule.iter().map(|entry| entry.year));
+    let (Some(first_year), Some(last_year)) = (years.clone().min(), years.max()) else {
+        return Vec::new();
+    };
+    let asset_id = schedules
+        .first()
+        .and_then(|(asset, _)| asset.id)
+        .unwrap_or(0);
 
-    ((asset.cost - accumulated).max(asset.salvage_value) * 100.0).round() / 100.0
+    (first_year..=last_year)
+        .map(|year| {
+            let mut combined = DepreciationEntry {
+                id: None,
+                asset_id,
+                year,
+                beginning_book_value: Money::ZERO,
+                depreciation_expense: Money::ZERO,
+                impairment_loss: Money::ZERO,
+                revaluation_adjustment: Money::ZERO,
+                accumulated_depreciation: Money::ZERO,
+                ending_book_value: Money::ZERO,
+            };
+            for (asset, schedule) in schedules {
+                if let Some(entry) = schedule.iter().find(|entry| entry.year == year) {
+                    combined.beginning_book_value += entry.beginning_book_value;
+                    combined.depreciation_expense += entry.depreciation_expense;
+                    combined.impairment_loss += entry.impairment_loss;
+                    combined.revaluation_adjustment += entry.revaluation_adjustment;
+                    combined.accumulated_depreciation += entry.accumulated_depreciation;
+                    combined.ending_book_value += entry.ending_book_value;
+                } else if let Some(last) = schedule
+                    .last()
+                    .filter(|last| last.year < year && asset.disposed_date.is_none())
+                {
+                    combined.beginning_book_value += last.ending_book_value;
+                    combined.accumulated_depreciation += last.accumulated_depreciation;
+                    combined.ending_book_value += last.ending_book_value;
+                }
+            }
+            combined
+        })
+        .collect()
 }
 
-/// Get depreciation expense for a specific year
-pub fn depreciation_for_year(asset: &Asset, year: i32) -> f64 {
-    // Guard against division by zero
-    if asset.useful_life_years < 1 {
-        return 0.0;
+/// Financial book schedules for an asset split into components: first what
+/// is left of the cost after the components, under the asset's own settings,
+/// elections and usage, then each component over its own life with its own
+/// method. Each part is returned as an asset of its own with the asset's ID
+/// and dates.
+///
+/// Impairments and revaluations of the asset are allocated across the parts
+/// in date order, pro rata to each part's carrying amount at the end of the
+/// fiscal year before the revision.
+pub fn component_schedules(
+    asset: &Asset,
+    components: &[AssetComponent],
+    usage: &[UsageEntry],
+    business_use: &[BusinessUseEntry],
+    impairments: &[Impairment],
+    revaluations: &[Revaluation],
+    fiscal: FiscalYear,
+) -> Vec<(Asset, Vec<DepreciationEntry>)> {
+    let component_cost: Money = components.iter().map(|c| c.cost).sum();
+    let component_salvage: Money = components.iter().map(|c| c.salvage_value).sum();
+
+    // Each part with the usage it depreciates by
+    let mut parts: Vec<(Asset, &[UsageEntry])> = Vec::new();
+    if asset.cost > component_cost {
+        let mut remainder = asset.clone();
+        remainder.cost = asset.cost - component_cost;
+        remainder.salvage_value =
+            (asset.salvage_value - component_salvage).clamp(Money::ZERO, remainder.cost);
+        parts.push((remainder, usage));
     }
 
-    let start_year: i32 = asset
-        .date_placed_in_service
-        .split('-')
-        .next()
-        .and_then(|y| y.parse().ok())
-        .unwrap_or(2024);
+    for component in components {
+        let mut part = asset.clone();
+        part.name = component.name.clone();
+        part.cost = component.cost;
+        part.salvage_value = component.salvage_value;
+        part.useful_life_years = component.useful_life_years;
+        part.method = component.method.clone();
+        part.convention = component.convention.clone().or(part.convention);
+        part.total_units = None;
+        part.section_179_amount = None;
+        part.bonus_percentage = Some(0.0);
+        parts.push((part, &[]));
+    }
 
-    let end_year = start_year + asset.useful_life_years - 1;
+    let mut part_impairments: Vec<Vec<Impairment>> = vec![Vec::new(); parts.len()];
+    let mut part_revaluations: Vec<Vec<Revaluation>> = vec![Vec::new(); parts.len()];
+    let mut revisions: Vec<(&str, Revision)> = impairments
+        .iter()
+        .map(|impairment| {
+            (
+                impairment.impairment_date.as_str(),
+                Revision::Impairment(impairment),
+            )
+        })
+        .chain(revaluations.iter().map(|revaluation| {
+            (
+                revaluation.revaluation_date.as_str(),
+                Revision::Revaluation(revaluation),
+            )
+        }))
+        .collect();
+    revisions.sort_by(|a, b| a.0.cmp(b.0));
 
-    if year < start_year || year > end_year {
-        return 0.0;
+    for (date, revision) in revisions {
+        let Some(year) = fiscal.year_of(date) else {
+            continue;
+        };
+        let carrying_amounts: Vec<Money> = part_schedules(
+            &parts,
+            business_use,
+            &part_impairments,
+            &part_revaluations,
+            fiscal,
+        )
+        .iter()
+        .zip(&parts)
+        .map(|(schedule, (part, _))| current_book_value(part, schedule, year))
+        .collect();
+        match revision {
+            Revision::Impairment(impairment) => {
+                for (i, amount) in allocate(impairment.amount, &carrying_amounts)
+                    .into_iter()
+                    .enumerate()
+                {
+                    part_impairments[i].push(Impairment {
+                        amount,
+                        ..impairment.clone()
+                    });
+                }
+            }
+            Revision::Revaluation(revaluation) => {
+                for (i, fair_value) in allocate(revaluation.fair_value, &carrying_amounts)
+                    .into_iter()
+                    .enumerate()
+                {
+                    part_revaluations[i].push(Revaluation {
+                        fair_value,
+                        ..revaluation.clone()
+                    });
+                }
+            }
+            Revision::Estimate(_) => {}
+        }
     }
 
-    // Check if disposed before this year
-    if let Some(ref disposed) = asset.disposed_date {
-        let disposed_year: i32 = disposed
-            .split('-')
-            .next()
-            .and_then(|y| y.parse().ok())
-            .unwrap_or(9999);
-        if year > disposed_year {
-            return 0.0;
-        }
+    let schedules = part_schedules(
+        &parts,
+        business_use,
+        &part_impairments,
+        &part_revaluations,
+        fiscal,
+    );
+    parts
+        .into_iter()
+        .map(|(part, _)| part)
+        .zip(schedules)
+        .collect()
+}
+
+/// Schedules of an asset's parts with the impairments and revaluations
+/// allocated to each so far
+fn part_schedules(
+    parts: &[(Asset, &[UsageEntry])],
+    business_use: &[BusinessUseEntry],
+    impairments: &[Vec<Impairment>],
+    revaluations: &[Vec<Revaluation>],
+    fiscal: FiscalYear,
+) -> Vec<Vec<DepreciationEntry>> {
+    parts
+        .iter()
+        .zip(impairments.iter().zip(revaluations))
+        .map(|((part, usage), (impairments, revaluations))| {
+            generate_revised_schedule(
+                part,
+                usage,
+                business_use,
+                &[],
+                impairments,
+                revaluations,
+                fiscal,
+            )
+        })
+        .collect()
+}
+
+/// Share an amount out pro rata to `weights`, rounded to the cent with the
+/// last share taking up the rounding. Equal shares when the weights are all
+/// zero.
+fn allocate(amount: Money, weights: &[Money]) -> Vec<Money> {
+    let total: Money = weights.iter().copied().sum();
+    let mut shares: Vec<Money> = weights
+        .iter()
+        .map(|weight| {
+            if total > Money::ZERO {
+                amount.mul_div(weight.cents(), total.cents())
+            } else {
+                amount.mul_div(1, weights.len() as i64)
+            }
+        })
+        .collect();
+    let allocated: Money = shares.iter().copied().sum();
+    if let Some(last) = shares.last_mut() {
+        *last += amount - allocated;
     }
+    shares
+}
 
-    let annual = (asset.cost - asset.salvage_value) / asset.useful_life_years as f64;
-    (annual * 100.0).round() / 100.0
+/// Add up the monthly periods of several schedules by fiscal year and month
+pub fn combine_periods(
+    periods: impl IntoIterator<Item = DepreciationPeriod>,
+) -> Vec<DepreciationPeriod> {
+    let mut combined: BTreeMap<(i32, u32), DepreciationPeriod> = BTreeMap::new();
+    for period in periods {
+        combined
+            .entry((period.year, period.period))
+            .and_modify(|total| total.depreciation_expense += period.depreciation_expense)
+            .or_insert(period);
+    }
+    combined.into_values().collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Work out how each revaluation was posted, from the financial book schedule
+/// it was applied to. An increase first reverses decreases that went to
+/// profit or loss before and credits the rest to the revaluation reserve; a
+/// decrease first uses up the reserve and takes the rest to profit or loss.
+pub fn post_revaluations(
+    revaluations: &[Revaluation],
+    schedule: &[DepreciationEntry],
+    fiscal: FiscalYear,
+) -> Vec<Revaluation> {
+    let mut reserve = Money::ZERO;
+    let mut deficit = Money::ZERO; // Decreases in profit or loss not yet reversed
 
-    #[test]
-    fn test_generate_schedule() {
-        let asset = Asset {
-            id: Some(1),
-            name: "Test Computer".to_string(),
-            description: None,
-            category_id: None,
-            date_placed_in_service: "2024-01-15".to_string(),
-            cost: 2000.0,
-            salvage_value: 200.0,
-            useful_life_years: 5,
-            property_class: Some("5".to_string()),
-            notes: None,
-            disposed_date: None,
-            disposed_value: None,
-            created_at: None,
-            updated_at: None,
+    let mut posted: Vec<Revaluation> = revaluations.to_vec();
+    posted.sort_by(|a, b| a.revaluation_date.cmp(&b.revaluation_date));
+    for revaluation in &mut posted {
+        let Some(entry) = fiscal
+            .year_of(&revaluation.revaluation_date)
+            .and_then(|year| schedule.iter().find(|entry| entry.year == year))
+        else {
+            continue;
         };
+        let change = entry.revaluation_adjustment;
 
-        let schedule = generate_schedule(&asset);
+        let (reserve_change, profit_loss) = if change >= Money::ZERO {
+            let reversal = change.min(deficit);
+            (change - reversal, reversal)
+        } else {
+            let from_reserve = (-change).min(reserve);
+            (-from_reserve, change + from_reserve)
+        };
+        reserve += reserve_change;
+        deficit -= profit_loss;
+
+        revaluation.carrying_amount = Some(entry.ending_book_value - change);
+        revaluation.reserve_change = Some(reserve_change);
+        revaluation.profit_loss = Some(profit_loss);
+    }
+
+    posted
+}
+
+/// Calculate current book value for an asset from its schedule as of a given year
+pub fn current_book_value(asset: &Asset, schedule: &[DepreciationEntry], as_of_year: i32) -> Money {
+    schedule
+        .iter()
+        .rev()
+        .find(|entry| entry.year <= as_of_year)
+        .map(|entry| entry.ending_book_value)
+        .unwrap_or(asset.cost)
+}
+
+/// Net book value when the asset was disposed of. Schedules stop at
+/// disposal, so this is where the schedule ends.
+pub fn book_value_at_disposal(asset: &Asset, schedule: &[DepreciationEntry]) -> Money {
+    schedule
+        .last()
+        .map(|entry| entry.ending_book_value)
+        .unwrap_or(asset.cost)
+}
+
+/// Gain (positive) or loss on disposal: the proceeds less selling costs and
+/// the net book value at disposal
+pub fn gain_loss_on_disposal(asset: &Asset, schedule: &[DepreciationEntry]) -> Money {
+    asset.disposed_value.unwrap_or(Money::ZERO)
+        - asset.selling_costs.unwrap_or(Money::ZERO)
+        - book_value_at_disposal(asset, schedule)
+}
+
+/// Depreciation taken on listed property before fiscal `year`, the year its
+/// business use dropped to 50% or less, and what ADS straight-line without
+/// Section 179 or bonus would have allowed over the same years at the
+/// business use recorded for them. The excess
+/// is recaptured as ordinary income under §280F(b)(2).
+pub fn excess_depreciation(
+    tax_asset: &Asset,
+    tax_schedule: &[DepreciationEntry],
+    business_use: &[BusinessUseEntry],
+    year: i32,
+    fiscal: FiscalYear,
+) -> (Money, Money) {
+    let ads = generate_revised_schedule(
+        &ads_from_start(tax_asset),
+        &[],
+        business_use,
+        &[],
+        &[],
+        &[],
+        fiscal,
+    );
+
+    let before = |schedule: &[DepreciationEntry]| -> Money {
+        schedule
+            .iter()
+            .filter(|entry| entry.year < year)
+            .map(|entry| entry.depreciation_expense)
+            .sum()
+    };
+    (before(tax_schedule), before(&ads))
+}
+
+/// Split the tax gain on disposal, from the asset's tax book schedule. Up to
+/// the depreciation taken, a gain on personal property is recaptured as
+/// ordinary income under §1245. Real property is depreciated straight-line,
+/// so nothing is recaptured as ordinary income and that part of the gain is
+/// unrecaptured §1250 gain instead.
+pub fn recapture(asset: &Asset, tax_schedule: &[DepreciationEntry]) -> Recapture {
+    let tax_basis = book_value_at_disposal(asset, tax_schedule);
+    let accumulated_depreciation = asset.cost - tax_basis;
+    let amount_realized =
+        asset.disposed_value.unwrap_or(Money::ZERO) - asset.selling_costs.unwrap_or(Money::ZERO);
+    let gain_loss = amount_realized - tax_basis;
+    let recaptured = gain_loss.clamp(Money::ZERO, accumulated_depreciation.max(Money::ZERO));
+
+    let real_property = is_real_property(asset);
+    let (ordinary_income, unrecaptured_1250) = if real_property {
+        (Money::ZERO, recaptured)
+    } else {
+        (recaptured, Money::ZERO)
+    };
+
+    Recapture {
+        asset_id: asset.id.unwrap_or(0),
+        name: asset.name.clone(),
+        disposed_date: asset.disposed_date.clone().unwrap_or_default(),
+        section: if real_property { "1250" } else { "1245" }.to_string(),
+        amount_realized,
+        tax_basis,
+        accumulated_depreciation,
+        gain_loss,
+        ordinary_income,
+        unrecaptured_1250,
+        capital_gain: gain_loss - ordinary_income,
+    }
+}
+
+/// Share of an asset a partial disposal takes, as a fraction
+/// (numerator, denominator): a quantity out of the asset's quantity, or a
+/// percentage to four decimal places
+pub fn disposal_share(asset: &Asset, quantity: Option<i32>, percentage: Option<f64>) -> (i64, i64) {
+    match (quantity, asset.quantity) {
+        (Some(quantity), Some(total)) if total > 0 => (i64::from(quantity), i64::from(total)),
+        _ => (
+            (percentage.unwrap_or(0.0) * 10_000.0).round() as i64,
+            1_000_000,
+        ),
+    }
+}
+
+/// Split the disposed part off an asset, by quantity or percentage. Cost,
+/// salvage value, Section 179 and total units are shared out with the part
+/// rounded to the cent and the rest left on the original, so the two always
+/// add back up. Returns the remaining asset and the new part, which links
+/// back to it.
+pub fn split_asset(
+    asset: &Asset,
+    quantity: Option<i32>,
+    percentage: Option<f64>,
+) -> (Asset, Asset) {
+    let (numerator, denominator) = disposal_share(asset, quantity, percentage);
+    let fraction = numerator as f64 / denominator as f64;
+
+    let mut part = asset.clone();
+    part.id = None;
+    part.parent_asset_id = asset.id;
+    part.name = match (quantity, asset.quantity) {
+        (Some(quantity), Some(total)) => format!("{} ({} of {})", asset.name, quantity, total),
+        _ => format!("{} ({}%)", asset.name, percentage.unwrap_or(0.0)),
+    };
+    part.notes = None;
+    part.cost = asset.cost.mul_div(numerator, denominator);
+    part.salvage_value = asset.salvage_value.mul_div(numerator, denominator);
+    part.section_179_amount = asset
+        .section_179_amount
+        .map(|amount| amount.mul_div(numerator, denominator));
+    part.total_units = asset.total_units.map(|units| units * fraction);
+    part.quantity = quantity.filter(|_| asset.quantity.is_some());
+
+    let mut remaining = asset.clone();
+    remaining.cost = asset.cost - part.cost;
+    remaining.salvage_value = asset.salvage_value - part.salvage_value;
+    remaining.section_179_amount = asset
+        .section_179_amount
+        .zip(part.section_179_amount)
+        .map(|(amount, split)| amount - split);
+    remaining.total_units = asset
+        .total_units
+        .zip(part.total_units)
+        .map(|(units, split)| units - split);
+    // A split by percentage leaves the original's quantity as it was
+    remaining.quantity = match part.quantity {
+        Some(split) => asset.quantity.map(|quantity| quantity - split),
+        None => asset.quantity,
+    };
+
+    (remaining, part)
+}
+
+/// Financial book carrying amount less tax basis at the end of `year`.
+/// Positive when tax deductions have run ahead of book depreciation.
+pub fn temporary_difference(
+    asset: &Asset,
+    book: &[DepreciationEntry],
+    tax: &[DepreciationEntry],
+    year: i32,
+) -> Money {
+    current_book_value(asset, book, year) - current_book_value(asset, tax, year)
+}
+
+/// Deferred tax on a temporary difference at `tax_rate` percent
+pub fn deferred_tax(temporary_difference: Money, tax_rate: f64) -> Money {
+    temporary_difference.percent(tax_rate)
+}
+
+/// Get depreciation expense for a specific fiscal year from an asset's schedule
+pub fn depreciation_for_year(
+    asset: &Asset,
+    schedule: &[DepreciationEntry],
+    year: i32,
+    fiscal: FiscalYear,
+) -> Money {
+    // Check if disposed before this year
+    if let Some(ref disposed) = asset.disposed_date {
+        let disposed_year = fiscal.year_of(disposed).unwrap_or(9999);
+        if year > disposed_year {
+            return Money::ZERO;
+        }
+    }
+
+    schedule
+        .iter()
+        .find(|entry| entry.year == year)
+        .map(|entry| entry.depreciation_expense)
+        .unwrap_or(Money::ZERO)
+}
+
+/// Fiscal year and month the asset was placed in service
+fn service_period(asset: &Asset, fiscal: FiscalYear) -> (i32, u32) {
+    fiscal
+        .period_of_date(&asset.date_placed_in_service)
+        .unwrap_or((2024, 1))
+}
+
+/// Fiscal year and month the asset was disposed of, if it has been
+fn disposal_period(asset: &Asset, fiscal: FiscalYear) -> Option<(i32, u32)> {
+    asset
+        .disposed_date
+        .as_deref()
+        .and_then(|date| fiscal.period_of_date(date))
+}
+
+/// Quarter (1-4) of the year containing `month`
+fn service_quarter(month: u32) -> u32 {
+    (month.clamp(1, 12) - 1) / 3 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_asset() -> Asset {
+        Asset {
+            id: Some(1),
+            name: "Test Computer".to_string(),
+            description: None,
+            category_id: None,
+            date_placed_in_service: "2024-01-15".to_string(),
+            cost: Money::from_dollars(2000.0),
+            salvage_value: Money::from_dollars(200.0),
+            useful_life_years: 5,
+            property_class: Some("5".to_string()),
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+            business_use_percentage: None,
+            passenger_auto: false,
+            asset_kind: None,
+            notes: None,
+            quantity: None,
+            disposed_date: None,
+            disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+            parent_asset_id: None,
+            improved_asset_id: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_schedule() {
+        let asset = test_asset();
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
 
         assert_eq!(schedule.len(), 5);
         assert_eq!(schedule[0].year, 2024);
-        assert_eq!(schedule[0].beginning_book_value, 2000.0);
-        assert_eq!(schedule[0].depreciation_expense, 360.0);
-        assert_eq!(schedule[4].ending_book_value, 200.0);
+        assert_eq!(schedule[0].beginning_book_value.to_dollars(), 2000.0);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 360.0);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_rounding_remainder_goes_to_final_year() {
+        let mut asset = test_asset();
+        asset.cost = Money::from_dollars(1000.0);
+        asset.salvage_value = Money::ZERO;
+        asset.useful_life_years = 3;
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![333.33, 333.33, 333.34]);
+        assert_eq!(
+            schedule.iter().map(|e| e.depreciation_expense).sum::<Money>(),
+            asset.cost
+        );
+        assert_eq!(schedule[2].ending_book_value, Money::ZERO);
+    }
+
+    #[test]
+    fn test_half_year_convention_adds_a_year() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        assert_eq!(expenses, vec![180.0, 360.0, 360.0, 360.0, 360.0, 180.0]);
+        assert_eq!(schedule[5].year, 2029);
+        assert_eq!(schedule[5].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_mid_quarter_convention() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        asset.date_placed_in_service = "2024-11-20".to_string();
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        // Fourth quarter: 1.5 of 12 months in the first year
+        assert_eq!(schedule.len(), 6);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 45.0);
+        assert_eq!(schedule[5].depreciation_expense.to_dollars(), 315.0);
+    }
+
+    #[test]
+    fn test_mid_month_and_full_month_conventions() {
+        let mut asset = test_asset();
+        asset.date_placed_in_service = "2024-04-02".to_string();
+
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
+        assert_eq!(
+            generate_schedule(&asset, &[], FiscalYear::CALENDAR)[0].depreciation_expense.to_dollars(),
+            255.0
+        );
+
+        asset.convention = Some(CONVENTION_FULL_MONTH.to_string());
+        assert_eq!(
+            generate_schedule(&asset, &[], FiscalYear::CALENDAR)[0].depreciation_expense.to_dollars(),
+            270.0
+        );
+    }
+
+    #[test]
+    fn test_fiscal_year_drives_periods_and_conventions() {
+        let fiscal = FiscalYear::ending_in(6);
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_FULL_MONTH.to_string());
+
+        // January is month 7 of FY2024, leaving 6 months in the first year
+        let schedule = generate_schedule(&asset, &[], fiscal);
+        assert_eq!(schedule[0].year, 2024);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 180.0);
+        assert_eq!(schedule.len(), 6);
+
+        // September falls in the first quarter of FY2025
+        asset.date_placed_in_service = "2024-09-10".to_string();
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        let schedule = generate_schedule(&asset, &[], fiscal);
+        assert_eq!(schedule[0].year, 2025);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 315.0);
+
+        // Disposed in August 2026, which is FY2027
+        asset.disposed_date = Some("2026-08-01".to_string());
+        assert_eq!(
+            depreciation_for_year(&asset, &schedule, 2027, fiscal).to_dollars(),
+            360.0
+        );
+        assert_eq!(
+            depreciation_for_year(&asset, &schedule, 2028, fiscal),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_convention_applies_to_book_value_and_year_expense() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert_eq!(depreciation_for_year(&asset, &schedule, 2024, FiscalYear::CALENDAR).to_dollars(), 180.0);
+        assert_eq!(depreciation_for_year(&asset, &schedule, 2029, FiscalYear::CALENDAR).to_dollars(), 180.0);
+        assert_eq!(current_book_value(&asset, &schedule, 2024).to_dollars(), 1820.0);
+        assert_eq!(current_book_value(&asset, &schedule, 2028).to_dollars(), 380.0);
+    }
+
+    #[test]
+    fn test_macrs_mid_quarter() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        asset.date_placed_in_service = "2024-10-01".to_string();
+        asset.cost = Money::from_dollars(10000.0);
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![500.0, 3800.0, 2280.0, 1368.0, 1094.0, 958.0]);
+    }
+
+    #[test]
+    fn test_double_declining_switches_to_straight_line() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_DECLINING_200.to_string());
+        asset.cost = Money::from_dollars(10000.0);
+        asset.salvage_value = Money::from_dollars(0.0);
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        // Straight-line over the remaining two years beats 40% of 2,160
+        assert_eq!(expenses, vec![4000.0, 2400.0, 1440.0, 1080.0, 1080.0]);
+    }
+
+    #[test]
+    fn test_declining_balance_honours_salvage() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_DECLINING_200.to_string());
+        asset.cost = Money::from_dollars(10000.0);
+        asset.salvage_value = Money::from_dollars(1000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        assert_eq!(expenses, vec![4000.0, 2400.0, 1440.0, 864.0, 296.0]);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 1000.0);
+    }
+
+    #[test]
+    fn test_150_declining_balance_with_half_year() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_DECLINING_150.to_string());
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        asset.cost = Money::from_dollars(10000.0);
+        asset.salvage_value = Money::from_dollars(0.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        assert_eq!(
+            expenses,
+            vec![1500.0, 2550.0, 1785.0, 1666.0, 1666.0, 833.0]
+        );
+        assert_eq!(schedule[5].ending_book_value.to_dollars(), 0.0);
+    }
+
+    #[test]
+    fn test_sum_of_years_digits() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_SUM_OF_YEARS_DIGITS.to_string());
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        // 1800 spread 5/15, 4/15, 3/15, 2/15, 1/15
+        assert_eq!(expenses, vec![600.0, 480.0, 360.0, 240.0, 120.0]);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_sum_of_years_digits_half_year() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_SUM_OF_YEARS_DIGITS.to_string());
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![300.0, 540.0, 420.0, 300.0, 180.0, 60.0]);
+    }
+
+    #[test]
+    fn test_macrs_five_year_half_year() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(10000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        // Salvage is ignored and recovery runs one year past the class life
+        assert_eq!(
+            expenses,
+            vec![2000.0, 3200.0, 1920.0, 1152.0, 1152.0, 576.0]
+        );
+        assert_eq!(schedule[5].year, 2029);
+        assert_eq!(schedule[5].ending_book_value.to_dollars(), 0.0);
+    }
+
+    #[test]
+    fn test_macrs_tables_sum_to_full_basis() {
+        for table in std::iter::once(MACRS_HALF_YEAR).chain(MACRS_MID_QUARTER) {
+            for (class, rates) in table {
+                let total: f64 = rates.iter().sum();
+                assert!(
+                    (total - 100.0).abs() < 1e-6,
+                    "class {} sums to {}",
+                    class,
+                    total
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_macrs_residential_rental() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.property_class = Some("27.5".to_string());
+        asset.date_placed_in_service = "2024-01-10".to_string();
+        asset.cost = Money::from_dollars(100000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert_eq!(schedule.len(), 28);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 3485.0);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 3636.0);
+        assert_eq!(schedule[9].depreciation_expense.to_dollars(), 3637.0);
+        assert_eq!(schedule[27].depreciation_expense.to_dollars(), 1970.0);
+        assert_eq!(schedule[27].ending_book_value.to_dollars(), 0.0);
+    }
+
+    #[test]
+    fn test_macrs_nonresidential_real_property() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.property_class = Some("39".to_string());
+        asset.date_placed_in_service = "2024-07-01".to_string();
+        asset.cost = Money::from_dollars(100000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert_eq!(schedule.len(), 40);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 1177.0);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 2564.0);
+        assert_eq!(schedule[39].depreciation_expense.to_dollars(), 1391.0);
+    }
+
+    #[test]
+    fn test_macrs_without_property_class_has_no_schedule() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.property_class = None;
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert!(schedule.is_empty());
+        assert_eq!(current_book_value(&asset, &schedule, 2030), asset.cost);
+    }
+
+    #[test]
+    fn test_business_use_scales_basis() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(10000.0);
+        asset.business_use_percentage = Some(60.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        // Only the business share is depreciated; the rest stays on the books
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 1200.0);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 1920.0);
+        assert_eq!(schedule.last().unwrap().ending_book_value.to_dollars(), 4000.0);
+    }
+
+    fn business_use(year: i32, percentage: f64) -> BusinessUseEntry {
+        BusinessUseEntry {
+            id: None,
+            asset_id: 1,
+            year,
+            percentage,
+            notes: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_recorded_business_use_by_year() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(10000.0);
+        asset.business_use_percentage = Some(100.0);
+
+        // 80% in the second year carries on to the years after it
+        let schedule = generate_revised_schedule(
+            &asset,
+            &[],
+            &[business_use(2025, 80.0)],
+            &[],
+            &[],
+            &[],
+            FiscalYear::CALENDAR,
+        );
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+        assert_eq!(expenses, vec![2000.0, 2560.0, 1536.0, 921.6, 921.6, 460.8]);
+
+        // Dropping to 40% switches to ADS from that year, as though from the
+        // start, and the excess recaptured is added back to the basis
+        let uses = [business_use(2025, 80.0), business_use(2026, 40.0)];
+        let schedule =
+            generate_revised_schedule(&asset, &[], &uses, &[], &[], &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+        assert_eq!(expenses, vec![2000.0, 2560.0, 800.0, 800.0, 800.0, 400.0]);
+        assert_eq!(schedule[2].revaluation_adjustment.to_dollars(), 1960.0);
+        assert_eq!(schedule.last().unwrap().ending_book_value.to_dollars(), 4600.0);
+
+        let (taken, ads) = excess_depreciation(&asset, &schedule, &uses, 2026, FiscalYear::CALENDAR);
+        assert_eq!((taken - ads).to_dollars(), 1960.0);
+    }
+
+    #[test]
+    fn test_passenger_auto_limits() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(80000.0);
+        asset.passenger_auto = true;
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        // 2024 limits, with what they hold back taken after the recovery period
+        assert_eq!(
+            expenses,
+            vec![12400.0, 19800.0, 11900.0, 7160.0, 7160.0, 4608.0, 7160.0, 7160.0, 2652.0]
+        );
+        assert_eq!(schedule.last().unwrap().ending_book_value, Money::ZERO);
+
+        // The limits are scaled by business use
+        asset.business_use_percentage = Some(75.0);
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 9300.0);
+    }
+
+    #[test]
+    fn test_excess_depreciation_over_ads() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(10000.0);
+        asset.business_use_percentage = Some(100.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let (taken, ads) = excess_depreciation(&asset, &schedule, &[], 2026, FiscalYear::CALENDAR);
+
+        // Two years of GDS against ADS straight-line over five years
+        assert_eq!(taken.to_dollars(), 5200.0);
+        assert_eq!(ads.to_dollars(), 3000.0);
+    }
+
+    #[test]
+    fn test_macrs_ads_straight_line_over_recovery_period() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_ADS.to_string());
+        asset.property_class = Some("7".to_string());
+        asset.cost = Money::from_dollars(10000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        // 7-year property recovers over 10 years under the half-year
+        // convention, with no salvage and no bonus
+        assert_eq!(expenses.len(), 11);
+        assert_eq!(expenses[0], 500.0);
+        assert_eq!(expenses[1], 1000.0);
+        assert_eq!(expenses[10], 500.0);
+        assert_eq!(schedule[10].ending_book_value.to_dollars(), 0.0);
+
+        asset.property_class = Some("27.5".to_string());
+        asset.date_placed_in_service = "2024-01-10".to_string();
+        asset.cost = Money::from_dollars(120000.0);
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 31);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 3833.33);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 4000.0);
+
+        // Residential rental placed in service before 2018 keeps 40 years
+        asset.date_placed_in_service = "2017-06-01".to_string();
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 41);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 3000.0);
+    }
+
+    #[test]
+    fn test_intangible_amortized_from_month_acquired() {
+        let mut asset = test_asset();
+        asset.asset_kind = Some(KIND_INTANGIBLE.to_string());
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.date_placed_in_service = "2024-04-10".to_string();
+        asset.cost = Money::from_dollars(18000.0);
+        asset.useful_life_years = 15;
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        // 15 years straight-line to zero, starting in April: nine months in
+        // the first year and the last three in the sixteenth
+        assert_eq!(schedule.len(), 16);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 900.0);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 1200.0);
+        assert_eq!(schedule[15].depreciation_expense.to_dollars(), 300.0);
+        assert_eq!(schedule[15].ending_book_value.to_dollars(), 0.0);
+
+        let periods = monthly_schedule(&asset, &schedule, FiscalYear::CALENDAR);
+        assert_eq!(periods[0].period, 4);
+        assert_eq!(periods[0].depreciation_expense.to_dollars(), 100.0);
+
+        // A custom period applies to the financial book, while the federal
+        // book keeps to 15 years unless it has a life of its own
+        asset.useful_life_years = 5;
+        assert_eq!(generate_schedule(&asset, &[], FiscalYear::CALENDAR).len(), 6);
+        let mut federal = AssetBook {
+            asset_id: 1,
+            book: BOOK_FEDERAL.to_string(),
+            method: None,
+            convention: None,
+            useful_life_years: None,
+            property_class: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+        };
+        let federal_asset = apply_book(&asset, &federal);
+        assert_eq!(generate_schedule(&federal_asset, &[], FiscalYear::CALENDAR).len(), 16);
+        federal.useful_life_years = Some(10);
+        assert_eq!(apply_book(&asset, &federal).useful_life_years, 10);
+        federal.book = BOOK_STATE.to_string();
+        federal.useful_life_years = None;
+        assert_eq!(apply_book(&asset, &federal).useful_life_years, 5);
+    }
+
+    #[test]
+    fn test_book_value_and_year_expense_follow_method() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert_eq!(depreciation_for_year(&asset, &schedule, 2025, FiscalYear::CALENDAR).to_dollars(), 640.0);
+        assert_eq!(current_book_value(&asset, &schedule, 2025).to_dollars(), 960.0);
+        assert_eq!(current_book_value(&asset, &schedule, 2023).to_dollars(), 2000.0);
+        assert_eq!(current_book_value(&asset, &schedule, 2040).to_dollars(), 0.0);
+    }
+
+    fn usage(year: i32, units: f64) -> UsageEntry {
+        UsageEntry {
+            id: None,
+            asset_id: 1,
+            year,
+            units,
+            notes: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_units_of_production_projects_evenly_without_usage() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_UNITS_OF_PRODUCTION.to_string());
+        asset.total_units = Some(10000.0);
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![360.0, 360.0, 360.0, 360.0, 360.0]);
+    }
+
+    #[test]
+    fn test_units_of_production_uses_actual_then_average_usage() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_UNITS_OF_PRODUCTION.to_string());
+        asset.total_units = Some(10000.0);
+
+        // 0.18 per unit; 2,000 units a year on average after two years
+        let schedule = generate_schedule(&asset, &[usage(2024, 3000.0), usage(2025, 1000.0)], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        assert_eq!(expenses, vec![540.0, 180.0, 360.0, 360.0, 360.0]);
+        assert_eq!(schedule[4].year, 2028);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_units_of_production_stops_at_salvage() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_UNITS_OF_PRODUCTION.to_string());
+        asset.total_units = Some(10000.0);
+
+        let schedule = generate_schedule(&asset, &[usage(2024, 12000.0)], FiscalYear::CALENDAR);
+
+        assert_eq!(schedule.len(), 1);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 1800.0);
+        assert_eq!(schedule[0].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_units_of_production_without_total_units_has_no_schedule() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_UNITS_OF_PRODUCTION.to_string());
+
+        assert!(generate_schedule(&asset, &[], FiscalYear::CALENDAR).is_empty());
+    }
+
+    #[test]
+    fn test_section_179_comes_off_basis_in_first_year() {
+        let mut asset = test_asset();
+        asset.section_179_amount = Some(Money::from_dollars(1000.0));
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let expenses: Vec<f64> = schedule.iter().map(|e| e.depreciation_expense.to_dollars()).collect();
+
+        // (2000 - 1000 - 200) / 5 = 160 a year, plus the 1000 expensed up front
+        assert_eq!(expenses, vec![1160.0, 160.0, 160.0, 160.0, 160.0]);
+        assert_eq!(schedule[0].beginning_book_value.to_dollars(), 2000.0);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 200.0);
+    }
+
+    #[test]
+    fn test_section_179_with_macrs() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.cost = Money::from_dollars(10000.0);
+        asset.section_179_amount = Some(Money::from_dollars(4000.0));
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![5200.0, 1920.0, 1152.0, 691.2, 691.2, 345.6]);
+    }
+
+    #[test]
+    fn test_section_179_full_cost_is_a_single_year() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.section_179_amount = Some(Money::from_dollars(2000.0));
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        assert_eq!(schedule.len(), 1);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 2000.0);
+        assert_eq!(schedule[0].ending_book_value.to_dollars(), 0.0);
+    }
+
+    #[test]
+    fn test_section_179_allowed_limit_phases_out() {
+        assert_eq!(
+            section_179_allowed_limit(
+                Money::from_dollars(1_220_000.0),
+                Money::from_dollars(3_050_000.0),
+                Money::from_dollars(1_000_000.0),
+            )
+            .to_dollars(),
+            1_220_000.0
+        );
+        assert_eq!(
+            section_179_allowed_limit(
+                Money::from_dollars(1_220_000.0),
+                Money::from_dollars(3_050_000.0),
+                Money::from_dollars(3_300_000.0),
+            )
+            .to_dollars(),
+            970_000.0
+        );
+        assert_eq!(
+            section_179_allowed_limit(
+                Money::from_dollars(1_220_000.0),
+                Money::from_dollars(3_050_000.0),
+                Money::from_dollars(5_000_000.0),
+            )
+            .to_dollars(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_bonus_defaults_to_phase_down_for_macrs() {
+        let mut asset = test_asset();
+        assert_eq!(bonus_rate(&asset), 0.0);
+
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.date_placed_in_service = "2022-06-01".to_string();
+        assert_eq!(bonus_rate(&asset), 100.0);
+        asset.date_placed_in_service = "2023-06-01".to_string();
+        assert_eq!(bonus_rate(&asset), 80.0);
+        asset.date_placed_in_service = "2024-06-01".to_string();
+        assert_eq!(bonus_rate(&asset), 60.0);
+        asset.date_placed_in_service = "2025-01-10".to_string();
+        assert_eq!(bonus_rate(&asset), 40.0);
+        asset.date_placed_in_service = "2025-06-01".to_string();
+        assert_eq!(bonus_rate(&asset), 100.0);
+
+        asset.property_class = Some("39".to_string());
+        assert_eq!(bonus_rate(&asset), 0.0);
+    }
+
+    #[test]
+    fn test_bonus_before_macrs() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.cost = Money::from_dollars(10000.0);
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        // 60% bonus in 2024, then the 5-year table on the remaining 4,000
+        assert_eq!(expenses, vec![6800.0, 1280.0, 768.0, 460.8, 460.8, 230.4]);
+    }
+
+    #[test]
+    fn test_bonus_after_section_179() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.cost = Money::from_dollars(10000.0);
+        asset.section_179_amount = Some(Money::from_dollars(4000.0));
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+
+        // 4,000 + 60% of 6,000 + 20% of the remaining 2,400
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 8080.0);
+        assert_eq!(schedule.last().unwrap().ending_book_value.to_dollars(), 0.0);
+    }
+
+    #[test]
+    fn test_bonus_percentage_before_straight_line() {
+        let mut asset = test_asset();
+        asset.bonus_percentage = Some(50.0);
+
+        let expenses: Vec<f64> = generate_schedule(&asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+
+        assert_eq!(expenses, vec![1080.0, 180.0, 180.0, 180.0, 180.0]);
+    }
+
+    #[test]
+    fn test_zero_bonus_percentage_opts_out() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+
+        assert_eq!(
+            generate_schedule(&asset, &[], FiscalYear::CALENDAR)[0].depreciation_expense.to_dollars(),
+            400.0
+        );
+    }
+
+    #[test]
+    fn test_apply_book_overrides_and_inherits() {
+        let mut asset = test_asset();
+        asset.bonus_percentage = Some(100.0);
+        let state = AssetBook {
+            asset_id: 1,
+            book: BOOK_STATE.to_string(),
+            method: Some(METHOD_MACRS_GDS.to_string()),
+            convention: Some(CONVENTION_HALF_YEAR.to_string()),
+            useful_life_years: None,
+            property_class: Some("5".to_string()),
+            section_179_amount: None,
+            bonus_percentage: Some(0.0),
+        };
+
+        let book_asset = apply_book(&asset, &state);
+
+        assert_eq!(book_asset.method.as_deref(), Some(METHOD_MACRS_GDS));
+        assert_eq!(book_asset.useful_life_years, asset.useful_life_years);
+        assert_eq!(book_asset.cost, asset.cost);
+        // The state book decouples from bonus while the asset keeps it
+        assert_eq!(
+            generate_schedule(&book_asset, &[], FiscalYear::CALENDAR)[0].depreciation_expense.to_dollars(),
+            400.0
+        );
+        assert_eq!(
+            generate_schedule(&asset, &[], FiscalYear::CALENDAR)[0].depreciation_expense.to_dollars(),
+            1800.0
+        );
+    }
+
+    fn yearly_expenses(asset: &Asset) -> Vec<(i32, f64)> {
+        generate_schedule(asset, &[], FiscalYear::CALENDAR)
+            .iter()
+            .map(|e| (e.year, e.depreciation_expense.to_dollars()))
+            .collect()
+    }
+
+    #[test]
+    fn test_disposal_year_follows_convention() {
+        let mut asset = test_asset();
+        asset.disposed_date = Some("2026-04-20".to_string());
+
+        // Full year convention: nothing in the year of disposal
+        assert_eq!(
+            yearly_expenses(&asset),
+            vec![(2024, 360.0), (2025, 360.0), (2026, 0.0)]
+        );
+
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        assert_eq!(yearly_expenses(&asset)[2], (2026, 180.0));
+
+        // Three and a half months
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
+        assert_eq!(yearly_expenses(&asset)[2], (2026, 105.0));
+
+        // Through mid-May, the middle of the second quarter
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        assert_eq!(yearly_expenses(&asset)[2], (2026, 135.0));
+
+        // January to March, none for April
+        asset.convention = Some(CONVENTION_FULL_MONTH.to_string());
+        assert_eq!(yearly_expenses(&asset)[2], (2026, 90.0));
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 3);
+        assert_eq!(schedule[2].ending_book_value.to_dollars(), 1190.0);
+    }
+
+    #[test]
+    fn test_disposal_in_first_and_final_years() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
+        asset.date_placed_in_service = "2024-03-10".to_string();
+
+        // Mid-March to mid-August is five months
+        asset.disposed_date = Some("2024-08-05".to_string());
+        assert_eq!(yearly_expenses(&asset), vec![(2024, 150.0)]);
+
+        // The final year only ever ran to mid-March
+        asset.disposed_date = Some("2029-11-01".to_string());
+        assert_eq!(yearly_expenses(&asset).last(), Some(&(2029, 75.0)));
+
+        // Disposed of after it was fully depreciated
+        asset.disposed_date = Some("2031-01-01".to_string());
+        assert_eq!(yearly_expenses(&asset).len(), 6);
+
+        // MACRS property disposed of in the year placed in service gets nothing
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.convention = None;
+        asset.bonus_percentage = Some(0.0);
+        asset.disposed_date = Some("2024-11-30".to_string());
+        assert_eq!(yearly_expenses(&asset), vec![(2024, 0.0)]);
+    }
+
+    #[test]
+    fn test_monthly_schedule_stops_at_disposal() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
+        asset.disposed_date = Some("2026-04-20".to_string());
+
+        assert_eq!(
+            monthly_expenses(&asset, 2026),
+            vec![(1, 30.0), (2, 30.0), (3, 30.0), (4, 15.0)]
+        );
+        assert!(monthly_expenses(&asset, 2027).is_empty());
+
+        // Half-year spreads the half year it allows over the months held
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        assert_eq!(
+            monthly_expenses(&asset, 2026),
+            vec![(1, 45.0), (2, 45.0), (3, 45.0), (4, 45.0)]
+        );
+    }
+
+    #[test]
+    fn test_gain_loss_on_disposal() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        asset.disposed_date = Some("2026-04-20".to_string());
+        asset.disposed_value = Some(Money::from_dollars(1500.0));
+        asset.selling_costs = Some(Money::from_dollars(100.0));
+
+        // 180 + 360 + 180 taken, leaving 1,280
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(book_value_at_disposal(&asset, &schedule).to_dollars(), 1280.0);
+        assert_eq!(gain_loss_on_disposal(&asset, &schedule).to_dollars(), 120.0);
+
+        // Scrapped for nothing
+        asset.disposed_value = None;
+        asset.selling_costs = None;
+        assert_eq!(gain_loss_on_disposal(&asset, &schedule).to_dollars(), -1280.0);
+
+        // No schedule at all leaves the full cost
+        assert_eq!(book_value_at_disposal(&asset, &[]).to_dollars(), 2000.0);
+    }
+
+    fn estimate_change(
+        effective_date: &str,
+        useful_life_years: i32,
+        salvage: f64,
+    ) -> EstimateChange {
+        EstimateChange {
+            id: None,
+            asset_id: 1,
+            effective_date: effective_date.to_string(),
+            useful_life_years,
+            salvage_value: Money::from_dollars(salvage),
+            previous_useful_life_years: None,
+            previous_salvage_value: None,
+            reason: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_change_in_estimate_is_prospective() {
+        let asset = test_asset();
+        let estimates = [estimate_change("2026-03-01", 8, 0.0)];
+        let schedule =
+            generate_revised_schedule(&asset, &[], &[], &estimates, &[], &[], FiscalYear::CALENDAR);
+
+        // 2024 and 2025 stand at 360; the 1,280 left is spread over the six
+        // years remaining of the new eight-year life
+        let expenses: Vec<f64> = schedule
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+        assert_eq!(
+            expenses,
+            vec![360.0, 360.0, 213.33, 213.33, 213.33, 213.33, 213.33, 213.35]
+        );
+        assert_eq!(schedule.last().unwrap().year, 2031);
+        assert_eq!(schedule.last().unwrap().ending_book_value, Money::ZERO);
+    }
+
+    #[test]
+    fn test_change_in_estimate_under_half_year_convention() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+
+        // A year and a half of a now three-year life has passed by 2026, so
+        // the 1,260 above salvage goes two thirds in 2026 and the rest in 2027
+        let estimates = [estimate_change("2026-06-30", 3, 200.0)];
+        let schedule =
+            generate_revised_schedule(&asset, &[], &[], &estimates, &[], &[], FiscalYear::CALENDAR);
+        let expenses: Vec<(i32, f64)> = schedule
+            .iter()
+            .map(|e| (e.year, e.depreciation_expense.to_dollars()))
+            .collect();
+        assert_eq!(
+            expenses,
+            vec![(2024, 180.0), (2025, 360.0), (2026, 840.0), (2027, 420.0)]
+        );
+        assert_eq!(schedule[3].ending_book_value.to_dollars(), 200.0);
+
+        // A life that has already run out takes the rest in the effective year
+        let estimates = [estimate_change("2026-06-30", 1, 200.0)];
+        let schedule =
+            generate_revised_schedule(&asset, &[], &[], &estimates, &[], &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 3);
+        assert_eq!(schedule[2].depreciation_expense.to_dollars(), 1260.0);
+    }
+
+    #[test]
+    fn test_impairment_writes_down_and_rescales() {
+        let asset = test_asset();
+        let impairment = Impairment {
+            id: None,
+            asset_id: 1,
+            impairment_date: "2025-06-30".to_string(),
+            amount: Money::from_dollars(500.0),
+            reason: None,
+            created_at: None,
+        };
+
+        // 2,000 less two years of 360 and the 500 loss leaves 780, and the
+        // 580 above salvage is spread over the three years left
+        let schedule = generate_revised_schedule(
+            &asset,
+            &[],
+            &[],
+            &[],
+            std::slice::from_ref(&impairment),
+            &[],
+            FiscalYear::CALENDAR,
+        );
+        let rows: Vec<(f64, f64)> = schedule
+            .iter()
+            .map(|e| (e.depreciation_expense.to_dollars(), e.impairment_loss.to_dollars()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (360.0, 0.0),
+                (360.0, 500.0),
+                (193.33, 0.0),
+                (193.33, 0.0),
+                (193.34, 0.0)
+            ]
+        );
+        assert_eq!(schedule[1].ending_book_value.to_dollars(), 780.0);
+        assert_eq!(schedule[1].accumulated_depreciation.to_dollars(), 720.0);
+        assert_eq!(schedule[4].ending_book_value.to_dollars(), 200.0);
+
+        // Written down to salvage, nothing is left to depreciate
+        let impairment = Impairment {
+            amount: Money::from_dollars(1080.0),
+            ..impairment
+        };
+        let schedule =
+            generate_revised_schedule(&asset, &[], &[], &[], &[impairment], &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[1].ending_book_value.to_dollars(), 200.0);
+    }
+
+    fn revaluation(revaluation_date: &str, fair_value: f64) -> Revaluation {
+        Revaluation {
+            id: None,
+            asset_id: 1,
+            revaluation_date: revaluation_date.to_string(),
+            fair_value: Money::from_dollars(fair_value),
+            reason: None,
+            created_at: None,
+            carrying_amount: None,
+            reserve_change: None,
+            profit_loss: None,
+        }
+    }
+
+    #[test]
+    fn test_revaluation_restates_and_rescales() {
+        let asset = test_asset();
+        let revaluations = vec![
+            revaluation("2025-06-30", 2000.0),
+            revaluation("2026-12-31", 1000.0),
+            revaluation("2027-03-01", 100.0),
+        ];
+
+        // Revalued from 1,280 up to 2,000 at the end of 2025, the 1,800
+        // above salvage is spread over the three years left. Down to 1,000
+        // at the end of 2026, then below salvage, which leaves nothing more
+        // to depreciate.
+        let schedule = generate_revised_schedule(
+            &asset,
+            &[],
+            &[],
+            &[],
+            &[],
+            &revaluations,
+            FiscalYear::CALENDAR,
+        );
+        let rows: Vec<(f64, f64, f64)> = schedule
+            .iter()
+            .map(|e| {
+                (
+                    e.depreciation_expense.to_dollars(),
+                    e.revaluation_adjustment.to_dollars(),
+                    e.ending_book_value.to_dollars(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (360.0, 0.0, 1640.0),
+                (360.0, 720.0, 2000.0),
+                (600.0, -400.0, 1000.0),
+                (400.0, -500.0, 100.0),
+            ]
+        );
+
+        // The increase goes to the reserve, the first decrease comes out of
+        // it and the second uses up the rest before reaching profit or loss
+        let posted = post_revaluations(&revaluations, &schedule, FiscalYear::CALENDAR);
+        let postings: Vec<(f64, f64, f64)> = posted
+            .iter()
+            .map(|r| {
+                (
+                    r.carrying_amount.unwrap().to_dollars(),
+                    r.reserve_change.unwrap().to_dollars(),
+                    r.profit_loss.unwrap().to_dollars(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            postings,
+            vec![
+                (1280.0, 720.0, 0.0),
+                (1400.0, -400.0, 0.0),
+                (600.0, -320.0, -180.0),
+            ]
+        );
+
+        // A later increase reverses the loss before crediting the reserve
+        let mut revaluations = revaluations;
+        revaluations.push(revaluation("2028-06-30", 400.0));
+        let schedule = generate_revised_schedule(
+            &asset,
+            &[],
+            &[],
+            &[],
+            &[],
+            &revaluations,
+            FiscalYear::CALENDAR,
+        );
+        assert_eq!(schedule[4].revaluation_adjustment.to_dollars(), 300.0);
+        let posted = post_revaluations(&revaluations, &schedule, FiscalYear::CALENDAR);
+        assert_eq!(posted[3].profit_loss.unwrap().to_dollars(), 180.0);
+        assert_eq!(posted[3].reserve_change.unwrap().to_dollars(), 120.0);
+    }
+
+    #[test]
+    fn test_combine_schedules_with_improvement() {
+        let asset = test_asset();
+        let mut improvement = test_asset();
+        improvement.id = Some(2);
+        improvement.date_placed_in_service = "2026-03-01".to_string();
+        improvement.cost = Money::from_dollars(600.0);
+        improvement.salvage_value = Money::ZERO;
+        improvement.useful_life_years = 3;
+
+        let fiscal = FiscalYear::CALENDAR;
+        let combined = combine_schedules(&[
+            (&asset, generate_schedule(&asset, &[], fiscal)),
+            (&improvement, generate_schedule(&improvement, &[], fiscal)),
+        ]);
+
+        // 2024-2028 for the asset, 2026-2028 for the improvement
+        let expenses: Vec<(i32, f64)> = combined
+            .iter()
+            .map(|e| (e.year, e.depreciation_expense.to_dollars()))
+            .collect();
+        assert_eq!(
+            expenses,
+            vec![(2024, 360.0), (2025, 360.0), (2026, 560.0), (2027, 560.0), (2028, 560.0)]
+        );
+        assert_eq!(combined[0].asset_id, 1);
+        assert_eq!(combined[2].beginning_book_value.to_dollars(), 1280.0 + 600.0);
+        assert_eq!(combined[4].ending_book_value.to_dollars(), 200.0);
+
+        // A shorter-lived asset's salvage value carries past its last year
+        let mut short = improvement.clone();
+        short.date_placed_in_service = "2024-01-01".to_string();
+        short.salvage_value = Money::from_dollars(60.0);
+        let combined = combine_schedules(&[
+            (&asset, generate_schedule(&asset, &[], fiscal)),
+            (&short, generate_schedule(&short, &[], fiscal)),
+        ]);
+        assert_eq!(combined[4].beginning_book_value.to_dollars(), 560.0 + 60.0);
+        assert_eq!(combined[4].ending_book_value.to_dollars(), 200.0 + 60.0);
+    }
+
+    #[test]
+    fn test_component_schedules() {
+        let asset = test_asset();
+        let roof = AssetComponent {
+            id: Some(7),
+            asset_id: 1,
+            name: "Roof".to_string(),
+            cost: Money::from_dollars(500.0),
+            salvage_value: Money::ZERO,
+            useful_life_years: 2,
+            method: None,
+            convention: None,
+        };
+
+        let fiscal = FiscalYear::CALENDAR;
+        let parts = component_schedules(&asset, &[roof], &[], &[], &[], &[], fiscal);
+        assert_eq!(parts.len(), 2);
+
+        // The rest of the asset: 1500 less 200 salvage over 5 years
+        let (remainder, schedule) = &parts[0];
+        assert_eq!(remainder.cost.to_dollars(), 1500.0);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 260.0);
+        assert_eq!(schedule.len(), 5);
+
+        // The roof over its own 2 years
+        let (part, schedule) = &parts[1];
+        assert_eq!(part.name, "Roof");
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 250.0);
+
+        let refs: Vec<(&Asset, Vec<DepreciationEntry>)> = parts
+            .iter()
+            .map(|(part, schedule)| (part, schedule.clone()))
+            .collect();
+        let combined = combine_schedules(&refs);
+        let expenses: Vec<f64> = combined
+            .iter()
+            .map(|e| e.depreciation_expense.to_dollars())
+            .collect();
+        assert_eq!(expenses, vec![510.0, 510.0, 260.0, 260.0, 260.0]);
+        assert_eq!(combined[4].ending_book_value.to_dollars(), 200.0);
+
+        let periods = combine_periods(
+            parts
+                .iter()
+                .flat_map(|(part, schedule)| monthly_schedule(part, schedule, fiscal)),
+        );
+        let first_year: Money = periods
+            .iter()
+            .filter(|p| p.year == 2024)
+            .map(|p| p.depreciation_expense)
+            .sum();
+        assert_eq!(first_year.to_dollars(), 510.0);
+    }
+
+    #[test]
+    fn test_component_schedules_allocate_revisions() {
+        let asset = test_asset();
+        let roof = AssetComponent {
+            id: Some(7),
+            asset_id: 1,
+            name: "Roof".to_string(),
+            cost: Money::from_dollars(500.0),
+            salvage_value: Money::ZERO,
+            useful_life_years: 4,
+            method: None,
+            convention: None,
+        };
+        let impairment = Impairment {
+            id: None,
+            asset_id: 1,
+            impairment_date: "2025-06-30".to_string(),
+            amount: Money::from_dollars(300.0),
+            reason: None,
+            created_at: None,
+        };
+
+        // At the end of 2025 the rest carries 980 and the roof 250, so the
+        // roof takes 250/1230 of the loss
+        let parts = component_schedules(
+            &asset,
+            std::slice::from_ref(&roof),
+            &[],
+            &[],
+            std::slice::from_ref(&impairment),
+            &[],
+            FiscalYear::CALENDAR,
+        );
+        assert_eq!(parts[0].1[1].impairment_loss.to_dollars(), 239.02);
+        assert_eq!(parts[1].1[1].impairment_loss.to_dollars(), 60.98);
+
+        // A revaluation restates the parts to a fair value that adds up
+        let parts = component_schedules(
+            &asset,
+            &[roof],
+            &[],
+            &[],
+            &[],
+            &[revaluation("2025-12-31", 1000.0)],
+            FiscalYear::CALENDAR,
+        );
+        let refs: Vec<(&Asset, Vec<DepreciationEntry>)> = parts
+            .iter()
+            .map(|(part, schedule)| (part, schedule.clone()))
+            .collect();
+        let combined = combine_schedules(&refs);
+        assert_eq!(combined[1].ending_book_value.to_dollars(), 1000.0);
+        assert_eq!(parts[1].1[1].ending_book_value.to_dollars(), 203.25);
+    }
+
+    #[test]
+    fn test_split_asset_by_quantity() {
+        let mut asset = test_asset();
+        asset.quantity = Some(3);
+        asset.section_179_amount = Some(Money::from_dollars(1000.0));
+
+        assert_eq!(disposal_share(&asset, Some(1), None), (1, 3));
+        let (remaining, part) = split_asset(&asset, Some(1), None);
+
+        assert_eq!(part.name, "Test Computer (1 of 3)");
+        assert_eq!(part.parent_asset_id, Some(1));
+        assert_eq!(part.id, None);
+        assert_eq!(part.quantity, Some(1));
+        assert_eq!(remaining.quantity, Some(2));
+
+        // A third of 2,000 rounds to 666.67; the rest keeps the odd cent
+        assert_eq!(part.cost.to_dollars(), 666.67);
+        assert_eq!(remaining.cost.to_dollars(), 1333.33);
+        assert_eq!(part.salvage_value + remaining.salvage_value, asset.salvage_value);
+        assert_eq!(
+            part.section_179_amount.unwrap() + remaining.section_179_amount.unwrap(),
+            Money::from_dollars(1000.0)
+        );
+    }
+
+    #[test]
+    fn test_split_asset_by_percentage() {
+        let mut asset = test_asset();
+        asset.total_units = Some(10_000.0);
+        asset.quantity = Some(10);
+
+        let (remaining, part) = split_asset(&asset, None, Some(25.0));
+        assert_eq!(part.name, "Test Computer (25%)");
+        assert_eq!(part.cost.to_dollars(), 500.0);
+        assert_eq!(remaining.cost.to_dollars(), 1500.0);
+        assert_eq!(part.total_units, Some(2500.0));
+        assert_eq!(remaining.total_units, Some(7500.0));
+        assert_eq!(part.quantity, None);
+        assert_eq!(remaining.quantity, Some(10));
+
+        // Each part depreciates in step, so together they match the whole
+        let whole = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let rest = generate_schedule(&remaining, &[], FiscalYear::CALENDAR);
+        let split = generate_schedule(&part, &[], FiscalYear::CALENDAR);
+        for ((whole, rest), split) in whole.iter().zip(&rest).zip(&split) {
+            assert_eq!(
+                rest.depreciation_expense + split.depreciation_expense,
+                whole.depreciation_expense
+            );
+        }
+    }
+
+    #[test]
+    fn test_recapture_ignores_book_impairment() {
+        let mut asset = test_asset();
+        asset.disposed_date = Some("2027-03-31".to_string());
+        asset.disposed_value = Some(Money::from_dollars(1500.0));
+        let impairment = Impairment {
+            id: None,
+            asset_id: 1,
+            impairment_date: "2025-06-30".to_string(),
+            amount: Money::from_dollars(500.0),
+            reason: None,
+            created_at: None,
+        };
+
+        // The impairment is a book write-down, not depreciation, so the tax
+        // basis comes from the unrevised schedule: 2,000 less three years of 360
+        let tax = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let split = recapture(&asset, &tax);
+        assert_eq!(split.tax_basis.to_dollars(), 920.0);
+        assert_eq!(split.accumulated_depreciation.to_dollars(), 1080.0);
+        assert_eq!(split.ordinary_income.to_dollars(), 580.0);
+
+        // Read from the impaired book schedule it would overstate recapture
+        let book = generate_revised_schedule(
+            &asset,
+            &[],
+            &[],
+            &[],
+            std::slice::from_ref(&impairment),
+            &[],
+            FiscalYear::CALENDAR,
+        );
+        assert!(recapture(&asset, &book).ordinary_income > split.ordinary_income);
+    }
+
+    #[test]
+    fn test_section_1245_recapture() {
+        let mut asset = test_asset();
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.bonus_percentage = Some(0.0);
+        asset.disposed_date = Some("2026-04-20".to_string());
+        asset.disposed_value = Some(Money::from_dollars(2600.0));
+        asset.selling_costs = Some(Money::from_dollars(100.0));
+
+        // 20% + 32% + half of 19.2% taken, leaving a basis of 768
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let split = recapture(&asset, &schedule);
+        assert_eq!(split.section, "1245");
+        assert_eq!(split.tax_basis.to_dollars(), 768.0);
+        assert_eq!(split.gain_loss.to_dollars(), 1732.0);
+        assert_eq!(split.ordinary_income.to_dollars(), 1232.0);
+        assert_eq!(split.capital_gain.to_dollars(), 500.0);
+
+        // A gain under the depreciation taken is all recaptured
+        asset.disposed_value = Some(Money::from_dollars(1600.0));
+        let split = recapture(&asset, &schedule);
+        assert_eq!(split.ordinary_income.to_dollars(), 732.0);
+        assert_eq!(split.capital_gain, Money::ZERO);
+
+        // Nothing to recapture on a loss
+        asset.disposed_value = Some(Money::from_dollars(500.0));
+        let split = recapture(&asset, &schedule);
+        assert_eq!(split.ordinary_income, Money::ZERO);
+        assert_eq!(split.capital_gain.to_dollars(), -368.0);
+    }
+
+    #[test]
+    fn test_unrecaptured_section_1250_gain() {
+        let mut asset = test_asset();
+        asset.property_class = Some("39".to_string());
+        asset.disposed_date = Some("2026-04-20".to_string());
+        asset.disposed_value = Some(Money::from_dollars(2500.0));
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let split = recapture(&asset, &schedule);
+        assert_eq!(split.section, "1250");
+        assert_eq!(split.gain_loss.to_dollars(), 1220.0);
+        assert_eq!(split.ordinary_income, Money::ZERO);
+        assert_eq!(split.unrecaptured_1250.to_dollars(), 720.0);
+        assert_eq!(split.capital_gain.to_dollars(), 1220.0);
+    }
+
+    fn monthly_expenses(asset: &Asset, year: i32) -> Vec<(u32, f64)> {
+        let schedule = generate_schedule(asset, &[], FiscalYear::CALENDAR);
+        monthly_schedule(asset, &schedule, FiscalYear::CALENDAR)
+            .iter()
+            .filter(|p| p.year == year)
+            .map(|p| (p.period, p.depreciation_expense.to_dollars()))
+            .collect()
+    }
+
+    #[test]
+    fn test_monthly_schedule_full_years() {
+        let asset = test_asset();
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let periods = monthly_schedule(&asset, &schedule, FiscalYear::CALENDAR);
+
+        assert_eq!(periods.len(), 60);
+        assert!(periods.iter().all(|p| p.depreciation_expense.to_dollars() == 30.0));
+        assert_eq!(periods[0].period_start, "2024-01-01");
+        assert_eq!(periods[59].period_start, "2028-12-01");
+    }
+
+    #[test]
+    fn test_monthly_schedule_mid_month_partial_years() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
+        asset.date_placed_in_service = "2024-04-02".to_string();
+
+        // Half of April, then full months: 15 + 8 x 30 = 255
+        let first = monthly_expenses(&asset, 2024);
+        assert_eq!(first.len(), 9);
+        assert_eq!(first[0], (4, 15.0));
+        assert_eq!(first[8], (12, 30.0));
+
+        // The final year picks up January to mid-April: 3 x 30 + 15 = 105
+        let last = monthly_expenses(&asset, 2029);
+        assert_eq!(last, vec![(1, 30.0), (2, 30.0), (3, 30.0), (4, 15.0)]);
+    }
+
+    #[test]
+    fn test_monthly_schedule_half_year() {
+        let mut asset = test_asset();
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+
+        assert!(monthly_expenses(&asset, 2024).iter().all(|(_, e)| *e == 15.0));
+        assert_eq!(monthly_expenses(&asset, 2029).len(), 6);
+        assert!(monthly_expenses(&asset, 2029).iter().all(|(_, e)| *e == 30.0));
+    }
+
+    #[test]
+    fn test_monthly_schedule_ties_to_annual_amounts() {
+        let mut asset = test_asset();
+        asset.cost = Money::from_dollars(1000.0);
+        asset.salvage_value = Money::ZERO;
+        asset.useful_life_years = 3;
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        asset.date_placed_in_service = "2024-05-20".to_string();
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let periods = monthly_schedule(&asset, &schedule, FiscalYear::CALENDAR);
+
+        for entry in &schedule {
+            let months: Money = periods
+                .iter()
+                .filter(|p| p.year == entry.year)
+                .map(|p| p.depreciation_expense)
+                .sum();
+            assert_eq!(months, entry.depreciation_expense);
+        }
+    }
+
+    #[test]
+    fn test_monthly_schedule_fiscal_periods() {
+        let fiscal = FiscalYear::ending_in(6);
+        let asset = test_asset();
+        let schedule = generate_schedule(&asset, &[], fiscal);
+        let periods = monthly_schedule(&asset, &schedule, fiscal);
+
+        // January 2024 is month 7 of FY2024
+        assert_eq!(periods[0].year, 2024);
+        assert_eq!(periods[0].period, 7);
+        assert_eq!(periods[0].period_start, "2024-01-01");
+        assert_eq!(periods[6].period_start, "2024-07-01");
+    }
+
+    #[test]
+    fn test_temporary_difference_and_deferred_tax() {
+        let asset = test_asset();
+        let book = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        let mut tax_asset = asset.clone();
+        tax_asset.method = Some(METHOD_MACRS_GDS.to_string());
+        tax_asset.bonus_percentage = Some(0.0);
+        let tax = generate_schedule(&tax_asset, &[], FiscalYear::CALENDAR);
+
+        // Book 1,640 against tax 1,600 after the first year
+        assert_eq!(temporary_difference(&asset, &book, &tax, 2024).to_dollars(), 40.0);
+        assert_eq!(deferred_tax(Money::from_dollars(40.0), 21.0).to_dollars(), 8.4);
+        // Tax reaches zero in 2029 while the book holds salvage value
+        assert_eq!(temporary_difference(&asset, &book, &tax, 2030).to_dollars(), 200.0);
+        // Nothing before the asset is in service
+        assert_eq!(temporary_difference(&asset, &book, &tax, 2023).to_dollars(), 0.0);
+        // A tax basis above book value gives a deferred tax asset
+        assert_eq!(deferred_tax(Money::from_dollars(-100.0), 25.0).to_dollars(), -25.0);
     }
 }
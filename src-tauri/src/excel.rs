@@ -1,11 +1,21 @@
-use crate::db::Database;
-use crate::depreciation::generate_schedule;
+use crate::commands::{
+    book_tax_difference, book_tax_schedules, get_all_leases, get_schedule_for_asset,
+    in_service_during, save_schedule, selected_book, selected_tax_book,
+};
+use crate::db::{
+    asset_from_row, category_default_method, deferred_tax_rate, fiscal_year, Database,
+};
+use crate::depreciation::{
+    BOOK_FINANCIAL, CONVENTION_FULL_YEAR, KIND_INTANGIBLE, KIND_TANGIBLE, METHOD_STRAIGHT_LINE,
+};
+use crate::fiscal::FiscalYear;
+use crate::lease::liability_schedule;
 use crate::models::*;
+use crate::money::Money;
 use crate::validation;
 use calamine::{open_workbook, DataType, Reader, Xlsx};
-use chrono::Datelike;
 use rusqlite::params;
-use rust_xlsxwriter::{Format, Workbook};
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
 use std::path::Path;
 use tauri::State;
 
@@ -123,11 +133,18 @@ fn parse_asset_row(row: &[calamine::Data], _row_num: usize) -> Result<AssetImpor
         _ => return Err("Invalid date format".to_string()),
     };
 
-    let cost = get_float(4).ok_or("Cost is required")?;
-    let salvage_value = get_float(5);
+    let cost = get_float(4)
+        .map(Money::from_dollars)
+        .ok_or("Cost is required")?;
+    let salvage_value = get_float(5).map(Money::from_dollars);
     let useful_life_years = get_int(6).ok_or("Useful Life is required")?;
     let property_class = get_string(7);
     let notes = get_string(8);
+    let method = get_string(9);
+    let convention = get_string(10);
+    let total_units = get_float(11);
+    let section_179_amount = get_float(12).map(Money::from_dollars);
+    let bonus_percentage = get_float(13);
 
     Ok(AssetImport {
         name,
@@ -138,6 +155,11 @@ fn parse_asset_row(row: &[calamine::Data], _row_num: usize) -> Result<AssetImpor
         salvage_value,
         useful_life_years,
         property_class,
+        method,
+        convention,
+        total_units,
+        section_179_amount,
+        bonus_percentage,
         notes,
     })
 }
@@ -166,61 +188,74 @@ fn insert_imported_asset(db: &State<Database>, import: AssetImport) -> Result<i6
         None
     };
 
-    conn.execute(
-        "INSERT INTO assets (name, description, category_id, date_placed_in_service, cost, salvage_value, useful_life_years, property_class, notes)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-        params![
-            import.name,
-            import.description,
-            category_id,
-            import.date_placed_in_service,
-            import.cost,
-            import.salvage_value.unwrap_or(0.0),
-            import.useful_life_years,
-            import.property_class,
-            import.notes,
-        ],
-    )
-    .map_err(map_err)?;
-
-    let id = conn.last_insert_rowid();
-
-    // Generate schedule
-    let asset = Asset {
-        id: Some(id),
+    let mut asset = Asset {
+        id: None,
         name: import.name,
         description: import.description,
         category_id,
         date_placed_in_service: import.date_placed_in_service,
         cost: import.cost,
-        salvage_value: import.salvage_value.unwrap_or(0.0),
+        salvage_value: import.salvage_value.unwrap_or(Money::ZERO),
         useful_life_years: import.useful_life_years,
         property_class: import.property_class,
+        method: import.method,
+        convention: import.convention,
+        total_units: import.total_units,
+        section_179_amount: import.section_179_amount,
+        bonus_percentage: import.bonus_percentage,
+        business_use_percentage: None,
+        passenger_auto: false,
+        asset_kind: None,
         notes: import.notes,
+        quantity: None,
         disposed_date: None,
         disposed_value: None,
+        selling_costs: None,
+        proceeds_type: None,
+        parent_asset_id: None,
+        improved_asset_id: None,
         created_at: None,
         updated_at: None,
     };
 
-    let schedule = generate_schedule(&asset);
-    for entry in schedule {
-        conn.execute(
-            "INSERT INTO depreciation_schedule
-             (asset_id, year, beginning_book_value, depreciation_expense, accumulated_depreciation, ending_book_value)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![
-                id,
-                entry.year,
-                entry.beginning_book_value,
-                entry.depreciation_expense,
-                entry.accumulated_depreciation,
-                entry.ending_book_value,
-            ],
-        )
-        .map_err(map_err)?;
+    // Fall back to the category's default method
+    if asset.method.is_none() {
+        if let Some(category_id) = category_id {
+            asset.method = category_default_method(&conn, category_id)
+                .map_err(map_err)?
+                .ok_or("Category not found")?;
+            validation::validate_asset(&asset).map_err(map_err)?;
+        }
     }
 
+    conn.execute(
+        "INSERT INTO assets (name, description, category_id, date_placed_in_service, cost, salvage_value, useful_life_years, property_class, method, convention, total_units, section_179_amount, bonus_percentage, notes)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            asset.name,
+            asset.description,
+            asset.category_id,
+            asset.date_placed_in_service,
+            asset.cost,
+            asset.salvage_value,
+            asset.useful_life_years,
+            asset.property_class,
+            asset.method,
+            asset.convention,
+            asset.total_units,
+            asset.section_179_amount,
+            asset.bonus_percentage,
+            asset.notes,
+        ],
+    )
+    .map_err(map_err)?;
+
+    let id = conn.last_insert_rowid();
+    asset.id = Some(id);
+
+    // Generate schedule
+    save_schedule(&conn, &asset)?;
+
     Ok(id)
 }
 
@@ -241,6 +276,11 @@ pub fn export_template(file_path: String) -> Result<()> {
         "Useful Life (Years)",
         "Property Class",
         "Notes",
+        "Method",
+        "Convention",
+        "Total Units",
+        "Section 179",
+        "Bonus %",
     ];
 
     for (col, header) in headers.iter().enumerate() {
@@ -267,6 +307,12 @@ pub fn export_template(file_path: String) -> Result<()> {
     worksheet
         .write_string(1, 8, "Main office")
         .map_err(map_err)?;
+    worksheet
+        .write_string(1, 9, "straight_line")
+        .map_err(map_err)?;
+    worksheet
+        .write_string(1, 10, "half_year")
+        .map_err(map_err)?;
 
     // Set column widths
     worksheet.set_column_width(0, 25).map_err(map_err)?; // Asset Name
@@ -278,19 +324,30 @@ pub fn export_template(file_path: String) -> Result<()> {
     worksheet.set_column_width(6, 20).map_err(map_err)?; // Useful Life (Years)
     worksheet.set_column_width(7, 15).map_err(map_err)?; // Property Class
     worksheet.set_column_width(8, 35).map_err(map_err)?; // Notes
+    worksheet.set_column_width(9, 15).map_err(map_err)?; // Method
+    worksheet.set_column_width(10, 15).map_err(map_err)?; // Convention
+    worksheet.set_column_width(11, 15).map_err(map_err)?; // Total Units
+    worksheet.set_column_width(12, 15).map_err(map_err)?; // Section 179
+    worksheet.set_column_width(13, 10).map_err(map_err)?; // Bonus %
 
     workbook.save(&file_path).map_err(map_err)?;
     Ok(())
 }
 
 #[tauri::command]
-pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Result<()> {
+pub fn export_depreciation_report(
+    db: State<Database>,
+    file_path: String,
+    book: Option<String>,
+) -> Result<()> {
+    let book = selected_book(book)?;
     let conn = db.conn.lock().map_err(map_err)?;
     let mut workbook = Workbook::new();
 
     let header_format = Format::new().set_bold();
     let money_format = Format::new().set_num_format("$#,##0.00");
-    let current_year = chrono::Local::now().year();
+    let fiscal = fiscal_year(&conn).map_err(map_err)?;
+    let current_year = fiscal.current_year();
 
     // Sheet 1: Asset List
     {
@@ -306,6 +363,12 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
             "Service Date",
             "Current Book Value",
             "Status",
+            "Method",
+            "Convention",
+            "Total Units",
+            "Section 179",
+            "Bonus %",
+            "Kind",
         ];
         for (col, header) in headers.iter().enumerate() {
             worksheet
@@ -320,23 +383,8 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
         let assets: Vec<(Asset, Option<String>)> = stmt
             .query_map([], |row| {
                 Ok((
-                    Asset {
-                        id: row.get(0)?,
-                        name: row.get(1)?,
-                        description: row.get(2)?,
-                        category_id: row.get(3)?,
-                        date_placed_in_service: row.get(4)?,
-                        cost: row.get(5)?,
-                        salvage_value: row.get(6)?,
-                        useful_life_years: row.get(7)?,
-                        property_class: row.get(8)?,
-                        notes: row.get(9)?,
-                        disposed_date: row.get(10)?,
-                        disposed_value: row.get(11)?,
-                        created_at: row.get(12)?,
-                        updated_at: row.get(13)?,
-                    },
-                    row.get(14)?,
+                    asset_from_row(row)?,
+                    row.get("category_name")?,
                 ))
             })
             .map_err(map_err)?
@@ -352,10 +400,10 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
                 .write_string(row, 1, cat_name.as_deref().unwrap_or(""))
                 .map_err(map_err)?;
             worksheet
-                .write_number_with_format(row, 2, asset.cost, &money_format)
+                .write_number_with_format(row, 2, asset.cost.to_dollars(), &money_format)
                 .map_err(map_err)?;
             worksheet
-                .write_number_with_format(row, 3, asset.salvage_value, &money_format)
+                .write_number_with_format(row, 3, asset.salvage_value.to_dollars(), &money_format)
                 .map_err(map_err)?;
             worksheet
                 .write_number(row, 4, asset.useful_life_years as f64)
@@ -364,9 +412,11 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
                 .write_string(row, 5, &asset.date_placed_in_service)
                 .map_err(map_err)?;
 
-            let book_value = crate::depreciation::current_book_value(asset, current_year);
+            let schedule = get_schedule_for_asset(&conn, asset.id.unwrap(), &book)?;
+            let book_value =
+                crate::depreciation::current_book_value(asset, &schedule, current_year);
             worksheet
-                .write_number_with_format(row, 6, book_value, &money_format)
+                .write_number_with_format(row, 6, book_value.to_dollars(), &money_format)
                 .map_err(map_err)?;
 
             let status = if asset.disposed_date.is_some() {
@@ -375,6 +425,42 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
                 "Active"
             };
             worksheet.write_string(row, 7, status).map_err(map_err)?;
+            worksheet
+                .write_string(
+                    row,
+                    8,
+                    asset.method.as_deref().unwrap_or(METHOD_STRAIGHT_LINE),
+                )
+                .map_err(map_err)?;
+            worksheet
+                .write_string(
+                    row,
+                    9,
+                    asset.convention.as_deref().unwrap_or(CONVENTION_FULL_YEAR),
+                )
+                .map_err(map_err)?;
+            if let Some(total_units) = asset.total_units {
+                worksheet
+                    .write_number(row, 10, total_units)
+                    .map_err(map_err)?;
+            }
+            if let Some(amount) = asset.section_179_amount {
+                worksheet
+                    .write_number_with_format(row, 11, amount.to_dollars(), &money_format)
+                    .map_err(map_err)?;
+            }
+            if let Some(percentage) = asset.bonus_percentage {
+                worksheet
+                    .write_number(row, 12, percentage)
+                    .map_err(map_err)?;
+            }
+            worksheet
+                .write_string(
+                    row,
+                    13,
+                    asset.asset_kind.as_deref().unwrap_or(KIND_TANGIBLE),
+                )
+                .map_err(map_err)?;
         }
 
         worksheet.set_column_width(0, 30).map_err(map_err)?; // Asset Name
@@ -385,6 +471,12 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
         worksheet.set_column_width(5, 15).map_err(map_err)?; // Service Date
         worksheet.set_column_width(6, 20).map_err(map_err)?; // Current Book Value
         worksheet.set_column_width(7, 12).map_err(map_err)?; // Status
+        worksheet.set_column_width(8, 15).map_err(map_err)?; // Method
+        worksheet.set_column_width(9, 15).map_err(map_err)?; // Convention
+        worksheet.set_column_width(10, 15).map_err(map_err)?; // Total Units
+        worksheet.set_column_width(11, 15).map_err(map_err)?; // Section 179
+        worksheet.set_column_width(12, 10).map_err(map_err)?; // Bonus %
+        worksheet.set_column_width(13, 12).map_err(map_err)?; // Kind
     }
 
     // Sheet 2: Depreciation Schedule
@@ -399,6 +491,8 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
             "Year",
             "Beginning Value",
             "Depreciation",
+            "Impairment",
+            "Revaluation",
             "Accumulated",
             "Ending Value",
         ];
@@ -409,53 +503,57 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
         }
 
         let mut stmt = conn.prepare(
-            "SELECT a.name, ds.year, ds.beginning_book_value, ds.depreciation_expense, ds.accumulated_depreciation, ds.ending_book_value
+            "SELECT a.name, ds.year, ds.beginning_book_value, ds.depreciation_expense, ds.impairment_loss, ds.revaluation_adjustment, ds.accumulated_depreciation, ds.ending_book_value
              FROM depreciation_schedule ds
              JOIN assets a ON ds.asset_id = a.id
+             WHERE ds.book = ?1
              ORDER BY a.name, ds.year"
         ).map_err(map_err)?;
 
-        let rows: Vec<(String, i32, f64, f64, f64, f64)> = stmt
-            .query_map([], |row| {
+        // Beginning value through ending value, in column order
+        let rows: Vec<(String, i32, [Money; 6])> = stmt
+            .query_map([&book], |row| {
                 Ok((
                     row.get(0)?,
                     row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                    row.get(5)?,
+                    [
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                        row.get(6)?,
+                        row.get(7)?,
+                    ],
                 ))
             })
             .map_err(map_err)?
             .filter_map(|r| r.ok())
             .collect();
 
-        for (row_idx, (name, year, begin, expense, accum, end)) in rows.iter().enumerate() {
+        for (row_idx, (name, year, amounts)) in rows.iter().enumerate() {
             let row = (row_idx + 1) as u32;
             worksheet.write_string(row, 0, name).map_err(map_err)?;
-            worksheet
-                .write_number(row, 1, *year as f64)
-                .map_err(map_err)?;
-            worksheet
-                .write_number_with_format(row, 2, *begin, &money_format)
-                .map_err(map_err)?;
-            worksheet
-                .write_number_with_format(row, 3, *expense, &money_format)
-                .map_err(map_err)?;
-            worksheet
-                .write_number_with_format(row, 4, *accum, &money_format)
-                .map_err(map_err)?;
-            worksheet
-                .write_number_with_format(row, 5, *end, &money_format)
-                .map_err(map_err)?;
+            write_year(worksheet, row, 1, *year, fiscal)?;
+            for (offset, amount) in amounts.iter().enumerate() {
+                worksheet
+                    .write_number_with_format(
+                        row,
+                        2 + offset as u16,
+                        amount.to_dollars(),
+                        &money_format,
+                    )
+                    .map_err(map_err)?;
+            }
         }
 
         worksheet.set_column_width(0, 30).map_err(map_err)?; // Asset Name
         worksheet.set_column_width(1, 10).map_err(map_err)?; // Year
         worksheet.set_column_width(2, 18).map_err(map_err)?; // Beginning Value
         worksheet.set_column_width(3, 15).map_err(map_err)?; // Depreciation
-        worksheet.set_column_width(4, 15).map_err(map_err)?; // Accumulated
-        worksheet.set_column_width(5, 15).map_err(map_err)?; // Ending Value
+        worksheet.set_column_width(4, 15).map_err(map_err)?; // Impairment
+        worksheet.set_column_width(5, 15).map_err(map_err)?; // Revaluation
+        worksheet.set_column_width(6, 15).map_err(map_err)?; // Accumulated
+        worksheet.set_column_width(7, 15).map_err(map_err)?; // Ending Value
     }
 
     // Sheet 3: Annual Summary
@@ -463,7 +561,12 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
         let worksheet = workbook.add_worksheet();
         worksheet.set_name("Annual Summary").map_err(map_err)?;
 
-        let headers = ["Year", "Total Depreciation", "Asset Count"];
+        let headers = [
+            "Year",
+            "Total Depreciation",
+            "Total Amortization",
+            "Asset Count",
+        ];
         for (col, header) in headers.iter().enumerate() {
             worksheet
                 .write_string_with_format(0, col as u16, *header, &header_format)
@@ -472,37 +575,200 @@ pub fn export_depreciation_report(db: State<Database>, file_path: String) -> Res
 
         let mut stmt = conn
             .prepare(
-                "SELECT year, SUM(depreciation_expense), COUNT(DISTINCT asset_id)
-             FROM depreciation_schedule
-             GROUP BY year
-             ORDER BY year",
+                "SELECT ds.year,
+                    SUM(CASE WHEN a.asset_kind = ?2 THEN 0 ELSE ds.depreciation_expense END),
+                    SUM(CASE WHEN a.asset_kind = ?2 THEN ds.depreciation_expense ELSE 0 END),
+                    COUNT(DISTINCT ds.asset_id)
+             FROM depreciation_schedule ds
+             JOIN assets a ON ds.asset_id = a.id
+             WHERE ds.book = ?1
+             GROUP BY ds.year
+             ORDER BY ds.year",
             )
             .map_err(map_err)?;
 
-        let rows: Vec<(i32, f64, i64)> = stmt
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        let rows: Vec<(i32, Money, Money, i64)> = stmt
+            .query_map(params![book, KIND_INTANGIBLE], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })
             .map_err(map_err)?
             .filter_map(|r| r.ok())
             .collect();
 
-        for (row_idx, (year, total, count)) in rows.iter().enumerate() {
+        for (row_idx, (year, total, amortization, count)) in rows.iter().enumerate() {
             let row = (row_idx + 1) as u32;
+            write_year(worksheet, row, 0, *year, fiscal)?;
             worksheet
-                .write_number(row, 0, *year as f64)
+                .write_number_with_format(row, 1, total.to_dollars(), &money_format)
                 .map_err(map_err)?;
             worksheet
-                .write_number_with_format(row, 1, *total, &money_format)
+                .write_number_with_format(row, 2, amortization.to_dollars(), &money_format)
                 .map_err(map_err)?;
             worksheet
-                .write_number(row, 2, *count as f64)
+                .write_number(row, 3, *count as f64)
                 .map_err(map_err)?;
         }
 
         worksheet.set_column_width(0, 10).map_err(map_err)?; // Year
         worksheet.set_column_width(1, 20).map_err(map_err)?; // Total Depreciation
-        worksheet.set_column_width(2, 15).map_err(map_err)?; // Asset Count
+        worksheet.set_column_width(2, 20).map_err(map_err)?; // Total Amortization
+        worksheet.set_column_width(3, 15).map_err(map_err)?; // Asset Count
+    }
+
+    // Sheet 4: Book-Tax Differences, against the exported tax book or federal
+    {
+        let worksheet = workbook.add_worksheet();
+        worksheet
+            .set_name("Book-Tax Differences")
+            .map_err(map_err)?;
+
+        let tax_book = selected_tax_book((book != BOOK_FINANCIAL).then(|| book.clone()))?;
+        let tax_rate = deferred_tax_rate(&conn).map_err(map_err)?;
+
+        let headers = [
+            "Asset Name",
+            "Year",
+            "Book Depreciation",
+            "Tax Depreciation",
+            "Book Value",
+            "Tax Basis",
+            "Temporary Difference",
+            "Deferred Tax",
+        ];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet
+                .write_string_with_format(0, col as u16, *header, &header_format)
+                .map_err(map_err)?;
+        }
+
+        let mut row = 1;
+        for (asset, book_schedule, tax_schedule) in book_tax_schedules(&conn, &tax_book)? {
+            let years = book_schedule.iter().chain(&tax_schedule).map(|e| e.year);
+            let (Some(first), Some(last)) = (years.clone().min(), years.max()) else {
+                continue;
+            };
+
+            for year in (first..=last).filter(|year| in_service_during(&asset, *year, fiscal)) {
+                let difference = book_tax_difference(
+                    &asset,
+                    &book_schedule,
+                    &tax_schedule,
+                    year,
+                    tax_rate,
+                    fiscal,
+                );
+                let amounts = [
+                    difference.book_depreciation,
+                    difference.tax_depreciation,
+                    difference.book_value,
+                    difference.tax_basis,
+                    difference.temporary_difference,
+                    difference.deferred_tax,
+                ];
+
+                worksheet
+                    .write_string(row, 0, &difference.name)
+                    .map_err(map_err)?;
+                write_year(worksheet, row, 1, year, fiscal)?;
+                for (col, amount) in amounts.iter().enumerate() {
+                    worksheet
+                        .write_number_with_format(
+                            row,
+                            (col + 2) as u16,
+                            amount.to_dollars(),
+                            &money_format,
+                        )
+                        .map_err(map_err)?;
+                }
+                row += 1;
+            }
+        }
+
+        worksheet.set_column_width(0, 30).map_err(map_err)?; // Asset Name
+        worksheet.set_column_width(1, 10).map_err(map_err)?; // Year
+        worksheet.set_column_width(2, 18).map_err(map_err)?; // Book Depreciation
+        worksheet.set_column_width(3, 18).map_err(map_err)?; // Tax Depreciation
+        worksheet.set_column_width(4, 15).map_err(map_err)?; // Book Value
+        worksheet.set_column_width(5, 15).map_err(map_err)?; // Tax Basis
+        worksheet.set_column_width(6, 22).map_err(map_err)?; // Temporary Difference
+        worksheet.set_column_width(7, 15).map_err(map_err)?; // Deferred Tax
+    }
+
+    // Sheet 5: Lease Liabilities; the right-of-use assets are on the first two sheets
+    {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name("Lease Liabilities").map_err(map_err)?;
+
+        let headers = [
+            "Lease",
+            "Year",
+            "Beginning Balance",
+            "Interest",
+            "Payments",
+            "Ending Balance",
+        ];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet
+                .write_string_with_format(0, col as u16, *header, &header_format)
+                .map_err(map_err)?;
+        }
+
+        let mut row = 1u32;
+        for lease in get_all_leases(&conn)? {
+            for entry in liability_schedule(&lease, fiscal) {
+                worksheet
+                    .write_string(row, 0, &lease.name)
+                    .map_err(map_err)?;
+                write_year(worksheet, row, 1, entry.year, fiscal)?;
+                let amounts = [
+                    entry.beginning_balance,
+                    entry.interest,
+                    entry.payments,
+                    entry.ending_balance,
+                ];
+                for (offset, amount) in amounts.iter().enumerate() {
+                    worksheet
+                        .write_number_with_format(
+                            row,
+                            2 + offset as u16,
+                            amount.to_dollars(),
+                            &money_format,
+                        )
+                        .map_err(map_err)?;
+                }
+                row += 1;
+            }
+        }
+
+        worksheet.set_column_width(0, 30).map_err(map_err)?; // Lease
+        worksheet.set_column_width(1, 10).map_err(map_err)?; // Year
+        worksheet.set_column_width(2, 18).map_err(map_err)?; // Beginning Balance
+        worksheet.set_column_width(3, 15).map_err(map_err)?; // Interest
+        worksheet.set_column_width(4, 15).map_err(map_err)?; // Payments
+        worksheet.set_column_width(5, 18).map_err(map_err)?; // Ending Balance
     }
 
     workbook.save(&file_path).map_err(map_err)?;
     Ok(())
 }
+
+/// Year column: a plain number for calendar years, an FY2026-style label
+/// for fiscal years
+fn write_year(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    year: i32,
+    fiscal: FiscalYear,
+) -> Result<()> {
+    if fiscal.is_calendar() {
+        worksheet
+            .write_number(row, col, year as f64)
+            .map_err(map_err)?;
+    } else {
+        worksheet
+            .write_string(row, col, fiscal.label(year))
+            .map_err(map_err)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use chrono::{Datelike, NaiveDate};
+
+/// The company's fiscal year, identified by the calendar month it ends in.
+/// A fiscal year is numbered by the calendar year it ends in, so with a
+/// June year end July 2025 through June 2026 is FY2026.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalYear {
+    end_month: u32,
+}
+
+impl FiscalYear {
+    pub const CALENDAR: FiscalYear = FiscalYear { end_month: 12 };
+
+    /// A fiscal year ending in `end_month` (1-12). Out-of-range months fall
+    /// back to the calendar year.
+    pub fn ending_in(end_month: u32) -> Self {
+        if (1..=12).contains(&end_month) {
+            FiscalYear { end_month }
+        } else {
+            Self::CALENDAR
+        }
+    }
+
+    pub fn end_month(self) -> u32 {
+        self.end_month
+    }
+
+    pub fn is_calendar(self) -> bool {
+        self.end_month == 12
+    }
+
+    /// Fiscal year and month (1-12 from the start of the fiscal year) of a
+    /// calendar year and month
+    pub fn period_of(self, year: i32, month: u32) -> (i32, u32) {
+        let month = month.clamp(1, 12);
+        let fiscal_year = if month > self.end_month {
+            year + 1
+        } else {
+            year
+        };
+        let fiscal_month = (month + 11 - self.end_month) % 12 + 1;
+        (fiscal_year, fiscal_month)
+    }
+
+    /// Calendar year and month of fiscal month `period` (1-12) of fiscal `year`
+    pub fn calendar_month(self, year: i32, period: u32) -> (i32, u32) {
+        let month = (self.end_month + period.clamp(1, 12) - 1) % 12 + 1;
+        if month > self.end_month {
+            (year - 1, month)
+        } else {
+            (year, month)
+        }
+    }
+
+    /// First day (YYYY-MM-DD) of fiscal month `period` of fiscal `year`
+    pub fn period_start(self, year: i32, period: u32) -> String {
+        let (year, month) = self.calendar_month(year, period);
+        format!("{:04}-{:02}-01", year, month)
+    }
+
+    /// Fiscal year and month of a YYYY-MM-DD date, if it parses
+    pub fn period_of_date(self, date: &str) -> Option<(i32, u32)> {
+        let mut parts = date.trim().split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts
+            .next()
+            .and_then(|m| m.parse().ok())
+            .filter(|m| (1..=12).contains(m))?;
+        Some(self.period_of(year, month))
+    }
+
+    /// Fiscal year containing a YYYY-MM-DD date, if it parses
+    pub fn year_of(self, date: &str) -> Option<i32> {
+        self.period_of_date(date).map(|(year, _)| year)
+    }
+
+    /// Fiscal year containing today
+    pub fn current_year(self) -> i32 {
+        let today = chrono::Local::now().date_naive();
+        self.period_of(today.year(), today.month()).0
+    }
+
+    /// How a fiscal year is shown in reports: the plain year for calendar
+    /// years, FY2026 otherwise
+    pub fn label(self, year: i32) -> String {
+        if self.is_calendar() {
+            year.to_string()
+        } else {
+            format!("FY{}", year)
+        }
+    }
+}
+
+impl Default for FiscalYear {
+    fn default() -> Self {
+        Self::CALENDAR
+    }
+}
+
+/// Bounds on a month's period start for the whole months from the start
+/// date's month through the end date: the first of the start month and the
+/// end date, both as YYYY-MM-DD, or `None` if either date doesn't parse
+pub fn month_range(start_date: &str, end_date: &str) -> Option<(String, String)> {
+    let start = NaiveDate::parse_from_str(start_date.trim(), "%Y-%m-%d").ok()?;
+    let end = NaiveDate::parse_from_str(end_date.trim(), "%Y-%m-%d").ok()?;
+    let first_month = start.with_day(1)?;
+    Some((
+        first_month.format("%Y-%m-%d").to_string(),
+        end.format("%Y-%m-%d").to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_year_is_unchanged() {
+        let fiscal = FiscalYear::CALENDAR;
+        assert_eq!(fiscal.period_of(2024, 1), (2024, 1));
+        assert_eq!(fiscal.period_of(2024, 12), (2024, 12));
+        assert_eq!(fiscal.label(2024), "2024");
+    }
+
+    #[test]
+    fn test_june_year_end() {
+        let fiscal = FiscalYear::ending_in(6);
+        assert_eq!(fiscal.period_of(2025, 7), (2026, 1));
+        assert_eq!(fiscal.period_of(2026, 1), (2026, 7));
+        assert_eq!(fiscal.period_of(2026, 6), (2026, 12));
+        assert_eq!(fiscal.year_of("2025-09-15"), Some(2026));
+        assert_eq!(fiscal.year_of("2025-06-30"), Some(2025));
+        assert_eq!(fiscal.label(2026), "FY2026");
+    }
+
+    #[test]
+    fn test_calendar_month_round_trips() {
+        for end_month in 1..=12 {
+            let fiscal = FiscalYear::ending_in(end_month);
+            for period in 1..=12 {
+                let (year, month) = fiscal.calendar_month(2026, period);
+                assert_eq!(fiscal.period_of(year, month), (2026, period));
+            }
+        }
+        assert_eq!(FiscalYear::ending_in(6).period_start(2026, 1), "2025-07-01");
+        assert_eq!(FiscalYear::CALENDAR.period_start(2026, 12), "2026-12-01");
+    }
+
+    #[test]
+    fn test_unparseable_dates_and_months() {
+        assert_eq!(FiscalYear::CALENDAR.period_of_date("not a date"), None);
+        assert_eq!(FiscalYear::CALENDAR.period_of_date("2024-13-01"), None);
+        assert_eq!(FiscalYear::ending_in(0), FiscalYear::CALENDAR);
+        assert_eq!(FiscalYear::ending_in(13), FiscalYear::CALENDAR);
+    }
+
+    #[test]
+    fn test_month_range_pads_dates() {
+        assert_eq!(
+            month_range("2024-03-15", "2024-06-30"),
+            Some(("2024-03-01".to_string(), "2024-06-30".to_string()))
+        );
+        // Dates without zero padding compare the same as padded ones
+        assert_eq!(
+            month_range("2024-1-5", "2024-2-9"),
+            Some(("2024-01-01".to_string(), "2024-02-09".to_string()))
+        );
+        assert_eq!(
+            month_range("1-1-1", "1-1-1"),
+            Some(("0001-01-01".to_string(), "0001-01-01".to_string()))
+        );
+        assert_eq!(month_range("2024-01", "2024-02-01"), None);
+    }
+}
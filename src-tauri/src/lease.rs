@@ -0,0 +1,207 @@
+use crate::depreciation::{CONVENTION_FULL_MONTH, METHOD_STRAIGHT_LINE};
+use crate::fiscal::FiscalYear;
+use crate::models::{Asset, Lease, LeaseLiabilityEntry};
+use crate::money::Money;
+
+/// Monthly discount rate for the lease's annual rate
+fn monthly_rate(lease: &Lease) -> f64 {
+    lease.discount_rate / 100.0 / 12.0
+}
+
+/// Number of monthly payments over the lease term
+fn payment_count(lease: &Lease) -> i32 {
+    lease.term_years.max(0) * 12
+}
+
+/// Lease liability at commencement: the monthly payments discounted at the
+/// lease's rate, as an annuity in arrears or, for payments in advance, an
+/// annuity due
+pub fn initial_liability(lease: &Lease) -> Money {
+    let months = payment_count(lease);
+    let rate = monthly_rate(lease);
+    if rate <= 0.0 {
+        return lease.monthly_payment.mul_div(months as i64, 1);
+    }
+
+    let mut factor = (1.0 - (1.0 + rate).powi(-months)) / rate;
+    if lease.payments_in_advance {
+        factor *= 1.0 + rate;
+    }
+    lease.monthly_payment.scale(factor)
+}
+
+/// Right-of-use asset for a lease: the initial liability plus initial direct
+/// costs, depreciated straight-line to zero over the lease term from the
+/// month of commencement
+pub fn right_of_use_asset(lease: &Lease) -> Asset {
+    Asset {
+        id: lease.asset_id,
+        name: lease.name.trim().to_string(),
+        description: Some("Right-of-use asset".to_string()),
+        category_id: None,
+        date_placed_in_service: lease.commencement_date.trim().to_string(),
+        cost: initial_liability(lease) + lease.initial_direct_costs.unwrap_or(Money::ZERO),
+        salvage_value: Money::ZERO,
+        useful_life_years: lease.term_years,
+        property_class: None,
+        method: Some(METHOD_STRAIGHT_LINE.to_string()),
+        convention: Some(CONVENTION_FULL_MONTH.to_string()),
+        total_units: None,
+        section_179_amount: None,
+        bonus_percentage: None,
+        business_use_percentage: None,
+        passenger_auto: false,
+        asset_kind: None,
+        notes: None,
+        quantity: None,
+        disposed_date: None,
+        disposed_value: None,
+        selling_costs: None,
+        proceeds_type: None,
+        parent_asset_id: None,
+        improved_asset_id: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+/// Amortize the lease liability month by month under the effective interest
+/// method and total it by fiscal year. Interest is rounded to the cent each
+/// month, and the last month's interest takes up the rounding so the
+/// liability ends at zero.
+pub fn liability_schedule(lease: &Lease, fiscal: FiscalYear) -> Vec<LeaseLiabilityEntry> {
+    let Some((mut year, mut period)) = fiscal.period_of_date(&lease.commencement_date) else {
+        return Vec::new();
+    };
+    let months = payment_count(lease);
+    let rate = monthly_rate(lease);
+    let payment = lease.monthly_payment;
+
+    let mut schedule: Vec<LeaseLiabilityEntry> = Vec::new();
+    let mut balance = initial_liability(lease);
+    for month in 0..months {
+        if schedule.last().map(|entry| entry.year) != Some(year) {
+            schedule.push(LeaseLiabilityEntry {
+                year,
+                beginning_balance: balance,
+                interest: Money::ZERO,
+                payments: Money::ZERO,
+                ending_balance: balance,
+            });
+        }
+
+        let interest = if month == months - 1 {
+            payment - balance
+        } else if lease.payments_in_advance {
+            (balance - payment).scale(rate)
+        } else {
+            balance.scale(rate)
+        };
+        balance = balance + interest - payment;
+
+        if let Some(entry) = schedule.last_mut() {
+            entry.interest += interest;
+            entry.payments += payment;
+            entry.ending_balance = balance;
+        }
+
+        period += 1;
+        if period > 12 {
+            period = 1;
+            year += 1;
+        }
+    }
+
+    schedule
+}
+
+/// Principal of the liability paid down in the fiscal year after `year`,
+/// which is shown as the current portion at the end of `year`
+pub fn current_liability(liability: &[LeaseLiabilityEntry], year: i32) -> Money {
+    liability
+        .iter()
+        .find(|entry| entry.year == year + 1)
+        .map(|entry| entry.beginning_balance - entry.ending_balance)
+        .unwrap_or(Money::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::depreciation::generate_schedule;
+
+    fn test_lease() -> Lease {
+        Lease {
+            id: Some(1),
+            asset_id: Some(1),
+            name: "Warehouse".to_string(),
+            commencement_date: "2024-04-01".to_string(),
+            term_years: 5,
+            monthly_payment: Money::from_dollars(1000.0),
+            payments_in_advance: false,
+            discount_rate: 6.0,
+            initial_direct_costs: None,
+            notes: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_initial_liability_discounts_payments() {
+        let mut lease = test_lease();
+        assert_eq!(initial_liability(&lease).to_dollars(), 51725.56);
+
+        lease.payments_in_advance = true;
+        assert_eq!(initial_liability(&lease).to_dollars(), 51984.19);
+
+        lease.discount_rate = 0.0;
+        assert_eq!(initial_liability(&lease).to_dollars(), 60000.0);
+    }
+
+    #[test]
+    fn test_liability_amortized_by_fiscal_year() {
+        let lease = test_lease();
+        let liability = liability_schedule(&lease, FiscalYear::CALENDAR);
+
+        // Nine payments in the first year and the last three in the sixth
+        assert_eq!(liability.len(), 6);
+        assert_eq!(liability[0].beginning_balance.to_dollars(), 51725.56);
+        assert_eq!(liability[0].payments.to_dollars(), 9000.0);
+        assert_eq!(liability[0].interest.to_dollars(), 2192.65);
+        assert_eq!(liability[0].ending_balance.to_dollars(), 44918.21);
+        assert_eq!(liability[5].payments.to_dollars(), 3000.0);
+        assert_eq!(liability[5].ending_balance, Money::ZERO);
+
+        let interest: Money = liability.iter().map(|entry| entry.interest).sum();
+        assert_eq!(interest.to_dollars(), 8274.44);
+
+        assert_eq!(
+            current_liability(&liability, 2024),
+            liability[1].beginning_balance - liability[1].ending_balance
+        );
+        assert_eq!(current_liability(&liability, 2029), Money::ZERO);
+
+        // Payments in advance leave nothing owed after the last one
+        let mut lease = test_lease();
+        lease.payments_in_advance = true;
+        let liability = liability_schedule(&lease, FiscalYear::ending_in(6));
+        assert_eq!(liability[0].year, 2024);
+        assert_eq!(liability[0].payments.to_dollars(), 3000.0);
+        assert_eq!(liability.last().unwrap().ending_balance, Money::ZERO);
+    }
+
+    #[test]
+    fn test_right_of_use_asset_depreciated_over_term() {
+        let mut lease = test_lease();
+        lease.initial_direct_costs = Some(Money::from_dollars(1274.44));
+        let asset = right_of_use_asset(&lease);
+        assert_eq!(asset.cost.to_dollars(), 53000.0);
+
+        let schedule = generate_schedule(&asset, &[], FiscalYear::CALENDAR);
+        assert_eq!(schedule.len(), 6);
+        assert_eq!(schedule[0].depreciation_expense.to_dollars(), 7950.0);
+        assert_eq!(schedule[1].depreciation_expense.to_dollars(), 10600.0);
+        assert_eq!(schedule[5].ending_book_value, Money::ZERO);
+    }
+}
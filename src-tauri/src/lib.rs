@@ -2,7 +2,10 @@ mod commands;
 mod db;
 mod depreciation;
 mod excel;
+mod fiscal;
+mod lease;
 mod models;
+mod money;
 mod validation;
 
 use db::Database;
@@ -10,6 +13,7 @@ use db::Database;
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let database = Database::new().expect("Failed to initialize database");
+    commands::upgrade_schedules(&database).expect("Failed to upgrade depreciation schedules");
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
@@ -32,8 +36,60 @@ pub fn run() {
             commands::update_asset,
             commands::delete_asset,
             commands::dispose_asset,
+            commands::partial_dispose_asset,
+            commands::get_asset_parts,
+            // Usage
+            commands::get_asset_usage,
+            commands::record_usage,
+            commands::delete_usage,
+            commands::get_business_use,
+            commands::record_business_use,
+            commands::delete_business_use,
+            // Books
+            commands::get_asset_books,
+            commands::save_asset_book,
+            commands::delete_asset_book,
+            // Changes in estimate
+            commands::get_estimate_changes,
+            commands::revise_estimate,
+            // Impairments
+            commands::get_impairments,
+            commands::record_impairment,
+            // Revaluations
+            commands::get_revaluations,
+            commands::record_revaluation,
+            // Components
+            commands::get_asset_components,
+            commands::save_asset_component,
+            commands::delete_asset_component,
+            // Leases
+            commands::get_leases,
+            commands::get_lease,
+            commands::create_lease,
+            commands::update_lease,
+            commands::delete_lease,
             // Reports
             commands::get_annual_summary,
+            commands::get_period_summary,
+            commands::get_depreciation_for_range,
+            commands::get_disposal,
+            commands::get_disposal_report,
+            commands::get_recapture_report,
+            commands::get_listed_property_report,
+            commands::get_impairment_report,
+            commands::get_revaluation_reserve_report,
+            commands::get_lease_report,
+            commands::get_section_179_report,
+            commands::get_book_tax_report,
+            // Settings
+            commands::get_section_179_limits,
+            commands::update_section_179_limit,
+            commands::get_bonus_opt_outs,
+            commands::set_bonus_opt_out,
+            commands::get_fiscal_year,
+            commands::set_fiscal_year_end,
+            commands::get_deferred_tax_rate,
+            commands::set_deferred_tax_rate,
             // Excel
             excel::import_assets_from_excel,
             excel::export_template,
@@ -1,3 +1,4 @@
+use crate::money::Money;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,6 +7,8 @@ pub struct Category {
     pub name: String,
     pub default_useful_life: Option<i32>,
     pub default_property_class: Option<String>,
+    pub default_method: Option<String>,
+    pub revaluation_model: bool, // Carry assets at revalued amounts (IAS 16) instead of cost
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -16,6 +19,8 @@ pub struct CategoryWithCount {
     pub name: String,
     pub default_useful_life: Option<i32>,
     pub default_property_class: Option<String>,
+    pub default_method: Option<String>,
+    pub revaluation_model: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub asset_count: i64,
@@ -28,13 +33,27 @@ pub struct Asset {
     pub description: Option<String>,
     pub category_id: Option<i64>,
     pub date_placed_in_service: String, // YYYY-MM-DD
-    pub cost: f64,
-    pub salvage_value: f64,
+    pub cost: Money,
+    pub salvage_value: Money,
     pub useful_life_years: i32,
     pub property_class: Option<String>,
+    pub method: Option<String>, // "straight_line" (default), "macrs_gds", "declining_200", ...
+    pub convention: Option<String>, // "full_year" (default), "half_year", "mid_quarter", ...
+    pub total_units: Option<f64>,   // Expected lifetime units for "units_of_production"
+    pub section_179_amount: Option<Money>, // Expensed in the first year under IRC §179
+    pub bonus_percentage: Option<f64>,   // None uses the phase-down rate for MACRS property
+    pub business_use_percentage: Option<f64>, // Listed property's business use; 50% or less requires ADS
+    #[serde(default)]
+    pub passenger_auto: bool, // Held to the §280F limits for passenger automobiles
+    pub asset_kind: Option<String>, // "tangible" (default) or "intangible" (amortized under §197)
     pub notes: Option<String>,
+    pub quantity: Option<i32>, // Identical units the asset is made up of
     pub disposed_date: Option<String>,
-    pub disposed_value: Option<f64>,
+    pub disposed_value: Option<Money>,
+    pub selling_costs: Option<Money>, // Costs of disposal, netted against the proceeds
+    pub proceeds_type: Option<String>, // "sale" (default), "trade_in", "scrap" or "casualty"
+    pub parent_asset_id: Option<i64>, // Asset this part was split off from when disposed of
+    pub improved_asset_id: Option<i64>, // Asset this is a capital improvement or addition to
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -44,34 +63,435 @@ pub struct DepreciationEntry {
     pub id: Option<i64>,
     pub asset_id: i64,
     pub year: i32,
-    pub beginning_book_value: f64,
-    pub depreciation_expense: f64,
-    pub accumulated_depreciation: f64,
-    pub ending_book_value: f64,
+    pub beginning_book_value: Money,
+    pub depreciation_expense: Money,
+    pub impairment_loss: Money, // Written off the carrying amount, apart from depreciation
+    pub revaluation_adjustment: Money, // Restates the carrying amount to fair value; negative for a decrease
+    pub accumulated_depreciation: Money,
+    pub ending_book_value: Money,
+}
+
+/// One fiscal month of an asset's depreciation, spread from its annual schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepreciationPeriod {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    /// Fiscal year
+    pub year: i32,
+    /// Fiscal month, 1-12
+    pub period: u32,
+    /// First day of the calendar month (YYYY-MM-DD)
+    pub period_start: String,
+    pub depreciation_expense: Money,
+}
+
+/// An asset's settings in a book other than the financial one. Fields left
+/// as None inherit the asset's own value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetBook {
+    pub asset_id: i64,
+    pub book: String, // "federal", "state" or "amt"
+    pub method: Option<String>,
+    pub convention: Option<String>,
+    pub useful_life_years: Option<i32>,
+    pub property_class: Option<String>,
+    pub section_179_amount: Option<Money>,
+    pub bonus_percentage: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageEntry {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub year: i32,
+    pub units: f64,
+    pub notes: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessUseEntry {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub year: i32,
+    pub percentage: f64, // Business use of listed property in the fiscal year
+    pub notes: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// A lease the company is the lessee of. Its right-of-use asset is kept on
+/// the asset register and depreciated like any other asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lease {
+    pub id: Option<i64>,
+    pub asset_id: Option<i64>, // Right-of-use asset, created with the lease
+    pub name: String,
+    pub commencement_date: String, // YYYY-MM-DD
+    pub term_years: i32,
+    pub monthly_payment: Money,
+    #[serde(default)]
+    pub payments_in_advance: bool, // Paid at the start of each month instead of the end
+    pub discount_rate: f64, // Annual rate implicit in the lease or incremental borrowing rate
+    pub initial_direct_costs: Option<Money>, // Added to the right-of-use asset
+    pub notes: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// A fiscal year of a lease liability's amortization under the effective
+/// interest method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaseLiabilityEntry {
+    pub year: i32,
+    pub beginning_balance: Money,
+    pub interest: Money,
+    pub payments: Money,
+    pub ending_balance: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaseWithSchedule {
+    pub lease: Lease,
+    pub asset: Asset,
+    pub schedule: Vec<DepreciationEntry>, // Right-of-use asset, financial book
+    pub liability: Vec<LeaseLiabilityEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetWithSchedule {
     pub asset: Asset,
-    pub schedule: Vec<DepreciationEntry>,
+    pub schedule: Vec<DepreciationEntry>, // Rolled up with the improvements'
     pub category_name: Option<String>,
+    pub improvements: Vec<Asset>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DashboardStats {
     pub total_assets: i64,
-    pub total_cost: f64,
-    pub total_book_value: f64,
-    pub current_year_depreciation: f64,
+    pub total_cost: Money,
+    pub total_book_value: Money,
+    pub current_year_depreciation: Money,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnnualSummary {
     pub year: i32,
-    pub total_depreciation: f64,
+    pub total_depreciation: Money,
+    pub total_amortization: Money, // Intangibles, reported apart from depreciation
     pub asset_count: i64,
 }
 
+/// Depreciation for a fiscal month or quarter across all assets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodSummary {
+    pub year: i32,
+    /// Fiscal month (1-12) or quarter (1-4)
+    pub period: u32,
+    /// First day of the period's first calendar month
+    pub period_start: String,
+    pub total_depreciation: Money,
+    pub asset_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetDepreciation {
+    pub asset_id: i64,
+    pub name: String,
+    pub depreciation_expense: Money,
+}
+
+/// Depreciation expense booked in the whole months from `start_date` to `end_date`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepreciationRange {
+    pub start_date: String,
+    pub end_date: String,
+    pub book: String,
+    pub total_depreciation: Money,
+    pub assets: Vec<AssetDepreciation>,
+}
+
+/// A prospective revision of an asset's useful life and salvage value in the
+/// financial book, effective from the fiscal year containing `effective_date`.
+/// The estimate it replaced is kept for the audit trail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateChange {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub effective_date: String,
+    pub useful_life_years: i32, // New total useful life, from the date placed in service
+    pub salvage_value: Money,
+    pub previous_useful_life_years: Option<i32>,
+    pub previous_salvage_value: Option<Money>,
+    pub reason: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// An impairment loss written off an asset's carrying amount in the financial
+/// book, in the fiscal year containing `impairment_date`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Impairment {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub impairment_date: String,
+    pub amount: Money,
+    pub reason: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// A fair-value revaluation of an asset in a category on the revaluation
+/// model. The financial book restates the carrying amount to `fair_value` at
+/// the end of the fiscal year containing `revaluation_date`. The carrying
+/// amount it replaced and how the change was posted are worked out from the
+/// schedule when revaluations are read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Revaluation {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub revaluation_date: String,
+    pub fair_value: Money,
+    pub reason: Option<String>,
+    pub created_at: Option<String>,
+    pub carrying_amount: Option<Money>,
+    pub reserve_change: Option<Money>, // Surplus (or deficit) posted to the revaluation reserve
+    pub profit_loss: Option<Money>,    // Recognized in profit or loss; negative is a loss
+}
+
+/// A significant part of an asset, such as a building's roof or HVAC, that
+/// the financial book depreciates separately over its own life. What is left
+/// of the asset's cost is depreciated under the asset's own settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetComponent {
+    pub id: Option<i64>,
+    pub asset_id: i64,
+    pub name: String,
+    pub cost: Money,
+    pub salvage_value: Money,
+    pub useful_life_years: i32,
+    pub method: Option<String>,
+    pub convention: Option<String>, // Inherits the asset's if None
+}
+
+/// A component with its own financial book schedule. The rest of the asset
+/// is reported as a component without an ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentWithSchedule {
+    pub component: AssetComponent,
+    pub schedule: Vec<DepreciationEntry>,
+}
+
+/// Disposal of part of an asset, by a quantity of its units or a percentage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialDisposal {
+    pub asset_id: i64,
+    pub disposed_date: String,
+    pub quantity: Option<i32>,
+    pub percentage: Option<f64>,
+    pub disposed_value: Option<Money>,
+    pub selling_costs: Option<Money>,
+    pub proceeds_type: Option<String>,
+}
+
+/// A disposed asset with its net book value and gain or loss at disposal,
+/// from the financial book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Disposal {
+    pub asset_id: i64,
+    pub name: String,
+    pub disposed_date: String,
+    pub proceeds_type: String,
+    pub cost: Money,
+    pub accumulated_depreciation: Money,
+    pub book_value: Money,
+    pub proceeds: Money,
+    pub selling_costs: Money,
+    pub gain_loss: Money, // Positive is a gain, negative a loss
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisposalReport {
+    pub year: i32,
+    pub total_proceeds: Money,
+    pub total_book_value: Money,
+    pub total_gain_loss: Money,
+    pub disposals: Vec<Disposal>,
+}
+
+/// An impairment with the asset it was recorded against and the carrying
+/// amount the asset was left with at the end of that fiscal year
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpairmentReportEntry {
+    pub asset_id: i64,
+    pub name: String,
+    pub impairment_date: String,
+    pub amount: Money,
+    pub carrying_amount: Money,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpairmentReport {
+    pub year: i32,
+    pub total_amount: Money,
+    pub impairments: Vec<ImpairmentReportEntry>,
+}
+
+/// Movement in an asset's revaluation reserve over a fiscal year. What is
+/// left of the reserve when the asset is disposed of is transferred to
+/// retained earnings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevaluationReserveEntry {
+    pub asset_id: i64,
+    pub name: String,
+    pub opening_balance: Money,
+    pub increase: Money,
+    pub decrease: Money,
+    pub transferred: Money,
+    pub closing_balance: Money,
+    pub profit_loss: Money, // Revaluation gains and losses in profit or loss
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevaluationReserveReport {
+    pub year: i32,
+    pub total_opening_balance: Money,
+    pub total_closing_balance: Money,
+    pub total_profit_loss: Money,
+    pub entries: Vec<RevaluationReserveEntry>,
+}
+
+/// The taxable gain or loss on a disposal, split for Form 4797
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recapture {
+    pub asset_id: i64,
+    pub name: String,
+    pub disposed_date: String,
+    pub section: String, // "1245" for personal property, "1250" for real property
+    pub amount_realized: Money, // Proceeds less selling costs
+    pub tax_basis: Money, // Adjusted basis in the tax book at disposal
+    pub accumulated_depreciation: Money,
+    pub gain_loss: Money,
+    pub ordinary_income: Money,   // §1245 recapture
+    pub unrecaptured_1250: Money, // Part of the capital gain taxed at up to 25%
+    pub capital_gain: Money,      // §1231 gain after recapture, or the loss
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecaptureReport {
+    pub year: i32,
+    pub tax_book: String,
+    pub total_gain_loss: Money,
+    pub total_ordinary_income: Money,
+    pub total_unrecaptured_1250: Money,
+    pub total_capital_gain: Money,
+    pub recaptures: Vec<Recapture>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListedPropertyRecapture {
+    pub asset_id: i64,
+    pub name: String,
+    pub placed_in_service_use: f64, // Business use percentage in the year placed in service
+    pub business_use: f64,          // Business use percentage in the year it dropped
+    pub depreciation_taken: Money,  // Tax book depreciation before the year it dropped
+    pub ads_depreciation: Money,    // What ADS would have allowed over the same years
+    pub recapture: Money,           // Excess depreciation included in income
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListedPropertyReport {
+    pub year: i32,
+    pub tax_book: String,
+    pub total_recapture: Money,
+    pub assets: Vec<ListedPropertyRecapture>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section179Limit {
+    pub year: i32,
+    pub dollar_limit: Money,
+    pub phase_out_threshold: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section179Election {
+    pub asset_id: i64,
+    pub name: String,
+    pub date_placed_in_service: String,
+    pub cost: Money,
+    pub amount: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section179Report {
+    pub year: i32,
+    pub dollar_limit: Money,
+    pub phase_out_threshold: Money,
+    pub qualifying_cost: Money, // Cost of §179 property placed in service in the year
+    pub allowed_limit: Money,   // Dollar limit after the phase-out reduction
+    pub total_elected: Money,
+    pub excess: Money, // Elections over the allowed limit
+    pub elections: Vec<Section179Election>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BonusOptOut {
+    pub year: i32,
+    pub property_class: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookTaxDifference {
+    pub asset_id: i64,
+    pub name: String,
+    pub book_depreciation: Money,
+    pub tax_depreciation: Money,
+    pub book_value: Money, // Financial book carrying amount at year end
+    pub tax_basis: Money,  // Remaining basis in the tax book at year end
+    pub temporary_difference: Money,
+    pub deferred_tax: Money, // Positive is a liability, negative an asset
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookTaxReport {
+    pub year: i32,
+    pub tax_book: String,
+    pub tax_rate: f64,
+    pub total_difference: Money,
+    pub total_deferred_tax: Money,
+    pub differences: Vec<BookTaxDifference>,
+}
+
+/// A lease's right-of-use asset and liability for a fiscal year. The current
+/// liability is the principal paid down over the next fiscal year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaseReportEntry {
+    pub lease_id: i64,
+    pub asset_id: i64,
+    pub name: String,
+    pub asset_depreciation: Money,
+    pub asset_book_value: Money,
+    pub interest: Money,
+    pub payments: Money,
+    pub liability: Money,
+    pub current_liability: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaseReport {
+    pub year: i32,
+    pub total_asset_book_value: Money,
+    pub total_liability: Money,
+    pub total_current_liability: Money,
+    pub leases: Vec<LeaseReportEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiscalYearSettings {
+    /// Calendar month (1-12) the fiscal year ends in
+    pub end_month: u32,
+    pub current_year: i32,
+}
+
 // For Excel import
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetImport {
@@ -79,10 +499,15 @@ pub struct AssetImport {
     pub description: Option<String>,
     pub category: Option<String>,
     pub date_placed_in_service: String,
-    pub cost: f64,
-    pub salvage_value: Option<f64>,
+    pub cost: Money,
+    pub salvage_value: Option<Money>,
     pub useful_life_years: i32,
     pub property_class: Option<String>,
+    pub method: Option<String>,
+    pub convention: Option<String>,
+    pub total_units: Option<f64>,
+    pub section_179_amount: Option<Money>,
+    pub bonus_percentage: Option<f64>,
     pub notes: Option<String>,
 }
 
@@ -0,0 +1,195 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// An amount of money held as a whole number of cents, so sums and
+/// differences are exact. Stored in SQLite as an INTEGER and sent to the
+/// frontend as a dollar amount.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn from_cents(cents: i64) -> Self {
+        Money(cents)
+    }
+
+    /// The nearest cent to a dollar amount
+    pub fn from_dollars(dollars: f64) -> Self {
+        Money((dollars * 100.0).round() as i64)
+    }
+
+    pub const fn cents(self) -> i64 {
+        self.0
+    }
+
+    pub fn to_dollars(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// `self * numerator / denominator`, rounded half away from zero to the cent
+    pub fn mul_div(self, numerator: i64, denominator: i64) -> Money {
+        if denominator == 0 {
+            return Money::ZERO;
+        }
+        let product = self.0 as i128 * numerator as i128;
+        let denominator = denominator as i128;
+        let half = denominator.abs() / 2;
+        let rounded = if (product < 0) == (denominator < 0) {
+            (product.abs() + half) / denominator.abs()
+        } else {
+            -((product.abs() + half) / denominator.abs())
+        };
+        Money(rounded as i64)
+    }
+
+    /// `percent` percent of the amount, rounded to the cent. Percentages are
+    /// taken to six decimal places, which covers every published rate table.
+    pub fn percent(self, percent: f64) -> Money {
+        self.mul_div((percent * 1_000_000.0).round() as i64, 100_000_000)
+    }
+
+    /// The amount scaled by a fraction such as a partial year, rounded to the cent
+    pub fn scale(self, factor: f64) -> Money {
+        Money((self.0 as f64 * factor).round() as i64)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let cents = self.0.unsigned_abs();
+        write!(f, "{}{}.{:02}", sign, cents / 100, cents % 100)
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.to_dollars())
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Money::from_dollars)
+    }
+}
+
+impl ToSql for Money {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0))
+    }
+}
+
+impl FromSql for Money {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(cents) => Ok(Money(cents)),
+            // Columns created as REAL before the switch to cents store whole
+            // cents as floating point
+            ValueRef::Real(cents) => Ok(Money(cents.round() as i64)),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_dollars_rounds_to_the_cent() {
+        assert_eq!(Money::from_dollars(0.1 + 0.2).cents(), 30);
+        assert_eq!(Money::from_dollars(19.999).cents(), 2000);
+        assert_eq!(Money::from_dollars(-2.5).cents(), -250);
+    }
+
+    #[test]
+    fn test_sums_are_exact() {
+        let total: Money = std::iter::repeat_n(Money::from_dollars(0.1), 1000).sum();
+        assert_eq!(total, Money::from_dollars(100.0));
+    }
+
+    #[test]
+    fn test_mul_div_rounds_half_away_from_zero() {
+        assert_eq!(Money::from_cents(100).mul_div(1, 3), Money::from_cents(33));
+        assert_eq!(Money::from_cents(5).mul_div(1, 2), Money::from_cents(3));
+        assert_eq!(Money::from_cents(-5).mul_div(1, 2), Money::from_cents(-3));
+        assert_eq!(Money::from_cents(100).mul_div(1, 0), Money::ZERO);
+    }
+
+    #[test]
+    fn test_percent() {
+        let cost = Money::from_dollars(100000.0);
+        assert_eq!(cost.percent(3.636), Money::from_dollars(3636.0));
+        assert_eq!(
+            Money::from_dollars(2000.0).percent(14.29),
+            Money::from_dollars(285.8)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Money::from_cents(123456).to_string(), "1234.56");
+        assert_eq!(Money::from_cents(-5).to_string(), "-0.05");
+    }
+
+    #[test]
+    fn test_serializes_as_dollars() {
+        let json = serde_json::to_string(&Money::from_cents(199)).unwrap();
+        assert_eq!(json, "1.99");
+        let money: Money = serde_json::from_str("2000").unwrap();
+        assert_eq!(money.cents(), 200000);
+    }
+}
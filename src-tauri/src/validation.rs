@@ -1,9 +1,44 @@
-use crate::models::{Asset, AssetImport, Category};
-use chrono::NaiveDate;
+use crate::depreciation::{
+    apply_book, is_macrs, ASSET_KINDS, BOOKS, BOOK_FINANCIAL, CONVENTION_FULL_MONTH,
+    CONVENTION_FULL_YEAR, CONVENTION_HALF_YEAR, CONVENTION_MID_MONTH, CONVENTION_MID_QUARTER,
+    KIND_INTANGIBLE, METHOD_DECLINING_150, METHOD_DECLINING_200, METHOD_MACRS_ADS,
+    METHOD_MACRS_GDS, METHOD_STRAIGHT_LINE, METHOD_SUM_OF_YEARS_DIGITS, METHOD_UNITS_OF_PRODUCTION,
+    PERIOD_MONTH, PERIOD_QUARTER, PROCEEDS_TYPES,
+};
+use crate::fiscal::FiscalYear;
+use crate::models::{
+    Asset, AssetBook, AssetComponent, AssetImport, BusinessUseEntry, Category, EstimateChange,
+    Impairment, Lease, PartialDisposal, Revaluation, Section179Limit, UsageEntry,
+};
+use crate::money::Money;
+use chrono::{Datelike, NaiveDate};
 
 /// Valid property classes for IRS depreciation
 const VALID_PROPERTY_CLASSES: &[&str] = &["3", "5", "7", "10", "15", "20", "27.5", "39"];
 
+/// Valid depreciation methods
+const VALID_METHODS: &[&str] = &[
+    METHOD_STRAIGHT_LINE,
+    METHOD_MACRS_GDS,
+    METHOD_MACRS_ADS,
+    METHOD_DECLINING_200,
+    METHOD_DECLINING_150,
+    METHOD_SUM_OF_YEARS_DIGITS,
+    METHOD_UNITS_OF_PRODUCTION,
+];
+
+/// Valid depreciation conventions
+const VALID_CONVENTIONS: &[&str] = &[
+    CONVENTION_FULL_YEAR,
+    CONVENTION_HALF_YEAR,
+    CONVENTION_MID_QUARTER,
+    CONVENTION_MID_MONTH,
+    CONVENTION_FULL_MONTH,
+];
+
+/// Property classes depreciated as real property (mid-month convention under MACRS)
+const REAL_PROPERTY_CLASSES: &[&str] = &["27.5", "39"];
+
 /// Validation error with field-specific messages
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
@@ -47,12 +82,12 @@ pub fn validate_asset(asset: &Asset) -> Result<()> {
     }
 
     // Cost validation
-    if asset.cost <= 0.0 {
+    if asset.cost <= Money::ZERO {
         errors.push("Cost must be greater than $0".to_string());
     }
 
     // Salvage value validation
-    if asset.salvage_value < 0.0 {
+    if asset.salvage_value < Money::ZERO {
         errors.push("Salvage value cannot be negative".to_string());
     } else if asset.salvage_value > asset.cost {
         errors.push("Salvage value cannot exceed cost".to_string());
@@ -71,6 +106,58 @@ pub fn validate_asset(asset: &Asset) -> Result<()> {
         }
     }
 
+    // Depreciation method (optional, defaults to straight-line)
+    if let Some(error) = check_method(asset.method.as_deref(), asset.property_class.as_deref()) {
+        errors.push(error);
+    }
+
+    // Convention (optional, defaults to full-year)
+    if let Some(error) = check_convention(
+        asset.convention.as_deref(),
+        asset.method.as_deref(),
+        asset.property_class.as_deref(),
+    ) {
+        errors.push(error);
+    }
+
+    // Total units (required for units-of-production)
+    if let Some(error) = check_total_units(asset.method.as_deref(), asset.total_units) {
+        errors.push(error);
+    }
+
+    // Section 179 election (optional)
+    if let Some(error) = check_section_179(
+        asset.section_179_amount,
+        asset.cost,
+        asset.salvage_value,
+        asset.method.as_deref(),
+        asset.property_class.as_deref(),
+        asset.business_use_percentage,
+    ) {
+        errors.push(error);
+    }
+
+    // Bonus percentage (optional, defaults to the phase-down rate)
+    if let Some(error) = check_bonus_percentage(asset.bonus_percentage) {
+        errors.push(error);
+    }
+
+    // Business use percentage (optional, listed property)
+    if let Some(error) = check_business_use(asset.method.as_deref(), asset.business_use_percentage)
+    {
+        errors.push(error);
+    }
+
+    // Asset kind (optional, defaults to tangible)
+    if let Some(error) = check_asset_kind(asset) {
+        errors.push(error);
+    }
+
+    // Quantity (optional)
+    if asset.quantity.is_some_and(|quantity| quantity < 1) {
+        errors.push("Quantity must be at least 1".to_string());
+    }
+
     // Description length
     if let Some(ref desc) = asset.description {
         if desc.len() > 500 {
@@ -111,11 +198,16 @@ pub fn validate_asset(asset: &Asset) -> Result<()> {
 
     // Disposed value validation (if present)
     if let Some(disposed_value) = asset.disposed_value {
-        if disposed_value < 0.0 {
+        if disposed_value < Money::ZERO {
             errors.push("Disposal value cannot be negative".to_string());
         }
     }
 
+    errors.extend(check_disposal_terms(
+        asset.selling_costs,
+        asset.proceeds_type.as_deref(),
+    ));
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -150,6 +242,14 @@ pub fn validate_category(category: &Category) -> Result<()> {
         }
     }
 
+    // Default depreciation method (optional)
+    if let Some(error) = check_method(
+        category.default_method.as_deref(),
+        category.default_property_class.as_deref(),
+    ) {
+        errors.push(format!("Default method: {}", error));
+    }
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -183,17 +283,17 @@ pub fn validate_asset_import(import: &AssetImport, row_num: usize) -> Result<()>
     }
 
     // Cost validation
-    if import.cost <= 0.0 {
+    if import.cost <= Money::ZERO {
         errors.push(format!("{}: Cost must be greater than $0", prefix));
     }
 
     // Salvage value validation
     if let Some(salvage) = import.salvage_value {
-        if salvage < 0.0 {
+        if salvage < Money::ZERO {
             errors.push(format!("{}: Salvage value cannot be negative", prefix));
         } else if salvage > import.cost {
             errors.push(format!(
-                "{}: Salvage value (${:.2}) cannot exceed cost (${:.2})",
+                "{}: Salvage value (${}) cannot exceed cost (${})",
                 prefix, salvage, import.cost
             ));
         }
@@ -212,6 +312,345 @@ pub fn validate_asset_import(import: &AssetImport, row_num: usize) -> Result<()>
         }
     }
 
+    // Depreciation method (optional)
+    if let Some(error) = check_method(import.method.as_deref(), import.property_class.as_deref()) {
+        errors.push(format!("{}: {}", prefix, error));
+    }
+
+    // Convention (optional)
+    if let Some(error) = check_convention(
+        import.convention.as_deref(),
+        import.method.as_deref(),
+        import.property_class.as_deref(),
+    ) {
+        errors.push(format!("{}: {}", prefix, error));
+    }
+
+    // Total units (required for units-of-production)
+    if let Some(error) = check_total_units(import.method.as_deref(), import.total_units) {
+        errors.push(format!("{}: {}", prefix, error));
+    }
+
+    // Section 179 election (optional)
+    if let Some(error) = check_section_179(
+        import.section_179_amount,
+        import.cost,
+        import.salvage_value.unwrap_or(Money::ZERO),
+        import.method.as_deref(),
+        import.property_class.as_deref(),
+        None,
+    ) {
+        errors.push(format!("{}: {}", prefix, error));
+    }
+
+    // Bonus percentage (optional)
+    if let Some(error) = check_bonus_percentage(import.bonus_percentage) {
+        errors.push(format!("{}: {}", prefix, error));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a usage entry against the asset it is recorded for
+pub fn validate_usage(
+    entry: &UsageEntry,
+    date_placed_in_service: &str,
+    fiscal: FiscalYear,
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if entry.units < 0.0 {
+        errors.push("Units cannot be negative".to_string());
+    }
+
+    if let Ok(service_date) = parse_date(date_placed_in_service) {
+        let (service_year, _) = fiscal.period_of(service_date.year(), service_date.month());
+        if entry.year < service_year {
+            errors.push("Usage cannot be recorded before the year placed in service".to_string());
+        }
+    }
+    if entry.year > fiscal.current_year() {
+        errors.push("Usage cannot be recorded for a future year".to_string());
+    }
+
+    // Notes length
+    if let Some(ref notes) = entry.notes {
+        if notes.len() > 2000 {
+            errors.push("Notes must be 2000 characters or less".to_string());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate the business use recorded for listed property in a year after
+/// the one it was placed in service, which the asset's own percentage covers
+pub fn validate_business_use(
+    entry: &BusinessUseEntry,
+    date_placed_in_service: &str,
+    fiscal: FiscalYear,
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if !(0.0..=100.0).contains(&entry.percentage) {
+        errors.push("Business use percentage must be between 0 and 100".to_string());
+    }
+
+    if let Ok(service_date) = parse_date(date_placed_in_service) {
+        let (service_year, _) = fiscal.period_of(service_date.year(), service_date.month());
+        if entry.year <= service_year {
+            errors.push(
+                "Business use is recorded for the years after the one placed in service"
+                    .to_string(),
+            );
+        }
+    }
+    if entry.year > fiscal.current_year() {
+        errors.push("Business use cannot be recorded for a future year".to_string());
+    }
+
+    // Notes length
+    if let Some(ref notes) = entry.notes {
+        if notes.len() > 2000 {
+            errors.push("Notes must be 2000 characters or less".to_string());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a lease before create/update
+pub fn validate_lease(lease: &Lease) -> Result<()> {
+    let mut errors = Vec::new();
+
+    // Name validation
+    let name = lease.name.trim();
+    if name.is_empty() {
+        errors.push("Lease name is required".to_string());
+    } else if name.len() > 200 {
+        errors.push("Lease name must be 200 characters or less".to_string());
+    }
+
+    // Commencement date
+    let commencement_date = lease.commencement_date.trim();
+    if commencement_date.is_empty() {
+        errors.push("Commencement date is required".to_string());
+    } else if parse_date(commencement_date).is_err() {
+        errors.push("Invalid commencement date format (use YYYY-MM-DD)".to_string());
+    } else if is_future_date(commencement_date) {
+        errors.push("Commencement date cannot be in the future".to_string());
+    }
+
+    if lease.term_years < 1 {
+        errors.push("Lease term must be at least 1 year".to_string());
+    }
+    if lease.monthly_payment <= Money::ZERO {
+        errors.push("Monthly payment must be greater than $0".to_string());
+    }
+    if !(0.0..=100.0).contains(&lease.discount_rate) {
+        errors.push("Discount rate must be between 0 and 100".to_string());
+    }
+    if lease
+        .initial_direct_costs
+        .is_some_and(|costs| costs < Money::ZERO)
+    {
+        errors.push("Initial direct costs cannot be negative".to_string());
+    }
+
+    // Notes length
+    if let Some(ref notes) = lease.notes {
+        if notes.len() > 2000 {
+            errors.push("Notes must be 2000 characters or less".to_string());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a book name passed to a book selector
+pub fn validate_book(book: &str) -> Result<()> {
+    match check_book(book) {
+        Some(error) => Err(ValidationError::Single(error)),
+        None => Ok(()),
+    }
+}
+
+/// Validate an asset's overrides for a tax book against the asset they apply to
+pub fn validate_asset_book(book: &AssetBook, asset: &Asset) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if let Some(error) = check_book(&book.book) {
+        errors.push(error);
+    } else if book.book == BOOK_FINANCIAL {
+        errors.push("The financial book follows the asset itself".to_string());
+    }
+
+    if book.useful_life_years.is_some_and(|years| years < 1) {
+        errors.push("Useful life must be at least 1 year".to_string());
+    }
+
+    if let Some(ref pc) = book.property_class {
+        let pc_trimmed = pc.trim();
+        if !pc_trimmed.is_empty() && !VALID_PROPERTY_CLASSES.contains(&pc_trimmed) {
+            errors.push(format!("Invalid property class: {}", pc_trimmed));
+        }
+    }
+
+    // Method-dependent checks run on the settings the book ends up with
+    let book_asset = apply_book(asset, book);
+    if let Some(error) = check_method(
+        book_asset.method.as_deref(),
+        book_asset.property_class.as_deref(),
+    ) {
+        errors.push(error);
+    }
+    if let Some(error) = check_convention(
+        book_asset.convention.as_deref(),
+        book_asset.method.as_deref(),
+        book_asset.property_class.as_deref(),
+    ) {
+        errors.push(error);
+    }
+    if let Some(error) = check_total_units(book_asset.method.as_deref(), book_asset.total_units) {
+        errors.push(error);
+    }
+    if let Some(error) = check_section_179(
+        book.section_179_amount,
+        book_asset.cost,
+        book_asset.salvage_value,
+        book_asset.method.as_deref(),
+        book_asset.property_class.as_deref(),
+        book_asset.business_use_percentage,
+    ) {
+        errors.push(error);
+    }
+    if let Some(error) = check_bonus_percentage(book.bonus_percentage) {
+        errors.push(error);
+    }
+    if let Some(error) = check_business_use(
+        book_asset.method.as_deref(),
+        book_asset.business_use_percentage,
+    ) {
+        errors.push(error);
+    }
+    if let Some(error) = check_asset_kind(&book_asset) {
+        errors.push(error);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate the tax rate used for deferred tax, as a percentage
+pub fn validate_tax_rate(rate: f64) -> Result<()> {
+    if (0.0..=100.0).contains(&rate) {
+        Ok(())
+    } else {
+        Err(ValidationError::Single(
+            "Tax rate must be between 0 and 100".to_string(),
+        ))
+    }
+}
+
+/// Validate the period length a monthly summary is grouped by
+pub fn validate_period(period: &str) -> Result<()> {
+    if [PERIOD_MONTH, PERIOD_QUARTER].contains(&period) {
+        Ok(())
+    } else {
+        Err(ValidationError::Single(format!(
+            "Invalid period '{}'. Must be one of: {}, {}",
+            period, PERIOD_MONTH, PERIOD_QUARTER
+        )))
+    }
+}
+
+/// Validate the start and end dates of a depreciation query
+pub fn validate_date_range(start_date: &str, end_date: &str) -> Result<()> {
+    let mut errors = Vec::new();
+
+    let start = parse_date(start_date);
+    let end = parse_date(end_date);
+    if start.is_err() {
+        errors.push("Start date must be in YYYY-MM-DD format".to_string());
+    }
+    if end.is_err() {
+        errors.push("End date must be in YYYY-MM-DD format".to_string());
+    }
+    if let (Ok(start), Ok(end)) = (start, end) {
+        if start > end {
+            errors.push("Start date must be on or before the end date".to_string());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate the calendar month a fiscal year ends in
+pub fn validate_fiscal_year_end(end_month: u32) -> Result<()> {
+    if (1..=12).contains(&end_month) {
+        Ok(())
+    } else {
+        Err(ValidationError::Single(
+            "Fiscal year end must be a month from 1 to 12".to_string(),
+        ))
+    }
+}
+
+/// Validate a class and tax year elected out of bonus depreciation
+pub fn validate_bonus_opt_out(year: i32, property_class: &str) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if !(1900..=2100).contains(&year) {
+        errors.push("Tax year must be between 1900 and 2100".to_string());
+    }
+    if !VALID_PROPERTY_CLASSES.contains(&property_class.trim()) {
+        errors.push(format!("Invalid property class: {}", property_class.trim()));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a Section 179 limit before saving it
+pub fn validate_section_179_limit(limit: &Section179Limit) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if !(1900..=2100).contains(&limit.year) {
+        errors.push("Tax year must be between 1900 and 2100".to_string());
+    }
+    if limit.dollar_limit < Money::ZERO {
+        errors.push("Dollar limit cannot be negative".to_string());
+    }
+    if limit.phase_out_threshold < Money::ZERO {
+        errors.push("Phase-out threshold cannot be negative".to_string());
+    }
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -222,7 +661,7 @@ pub fn validate_asset_import(import: &AssetImport, row_num: usize) -> Result<()>
 /// Validate disposal operation
 pub fn validate_disposal(
     disposed_date: &str,
-    disposed_value: Option<f64>,
+    disposed_value: Option<Money>,
     date_placed_in_service: &str,
 ) -> Result<()> {
     let mut errors = Vec::new();
@@ -249,7 +688,7 @@ pub fn validate_disposal(
 
     // Disposed value validation
     if let Some(value) = disposed_value {
-        if value < 0.0 {
+        if value < Money::ZERO {
             errors.push("Disposal value cannot be negative".to_string());
         }
     }
@@ -261,8 +700,481 @@ pub fn validate_disposal(
     }
 }
 
+/// Validate the selling costs and proceeds type of a disposal
+pub fn validate_disposal_terms(
+    selling_costs: Option<Money>,
+    proceeds_type: Option<&str>,
+) -> Result<()> {
+    let errors = check_disposal_terms(selling_costs, proceeds_type);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a capital improvement or addition against the asset it improves.
+/// Improvements hang off a main asset only, one level deep, and go into
+/// service with or after it.
+pub fn validate_improvement(improvement: &Asset, improved: &Asset) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if improved.improved_asset_id.is_some() {
+        errors.push("Add the improvement to the main asset instead".to_string());
+    }
+    if improved.disposed_date.is_some() {
+        errors.push("Can't add an improvement to a disposed asset".to_string());
+    }
+    if improvement.id.is_some() && improvement.id == improved.id {
+        errors.push("An asset can't be an improvement to itself".to_string());
+    }
+    if improvement.date_placed_in_service.trim() < improved.date_placed_in_service.trim() {
+        errors.push(
+            "Improvement can't be placed in service before the asset it improves".to_string(),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate how much of an asset a partial disposal takes: either a
+/// quantity short of the asset's whole quantity, or a percentage under 100.
+/// Disposing of all of it is a regular disposal.
+pub fn validate_partial_disposal(disposal: &PartialDisposal, asset: &Asset) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if asset.disposed_date.is_some() {
+        errors.push("Asset has already been disposed of".to_string());
+    }
+
+    match (disposal.quantity, disposal.percentage) {
+        (Some(_), Some(_)) => {
+            errors.push("Enter either a quantity or a percentage, not both".to_string());
+        }
+        (None, None) => errors.push("Quantity or percentage is required".to_string()),
+        (Some(quantity), None) => match asset.quantity {
+            None => errors.push("Asset has no quantity to dispose of part of".to_string()),
+            Some(total) if quantity < 1 || quantity >= total => errors.push(format!(
+                "Quantity must be between 1 and {}; dispose of the whole asset instead",
+                total - 1
+            )),
+            Some(_) => {}
+        },
+        (None, Some(percentage)) => {
+            if !(percentage > 0.0 && percentage < 100.0) {
+                errors.push("Percentage must be greater than 0 and less than 100".to_string());
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a change in estimate. Revisions are prospective, so each takes
+/// effect no earlier than the one before it, and only while the asset is in
+/// service. MACRS recovery periods are fixed, and units-of-production assets
+/// are revised through their total units.
+pub fn validate_estimate_change(
+    change: &EstimateChange,
+    asset: &Asset,
+    latest: Option<&EstimateChange>,
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    match asset.method.as_deref() {
+        method if is_macrs(method) => {
+            errors.push("MACRS recovery periods can't be revised".to_string());
+        }
+        Some(METHOD_UNITS_OF_PRODUCTION) => {
+            errors.push("Revise total units instead for units-of-production assets".to_string())
+        }
+        _ => {}
+    }
+
+    let effective_date = change.effective_date.trim();
+    if effective_date.is_empty() {
+        errors.push("Effective date is required".to_string());
+    } else if parse_date(effective_date).is_err() {
+        errors.push("Invalid effective date format (use YYYY-MM-DD)".to_string());
+    } else {
+        if is_future_date(effective_date) {
+            errors.push("Effective date cannot be in the future".to_string());
+        }
+        if effective_date < asset.date_placed_in_service.trim() {
+            errors.push("Effective date is before the date placed in service".to_string());
+        }
+        if asset
+            .disposed_date
+            .as_deref()
+            .is_some_and(|disposed| effective_date > disposed.trim())
+        {
+            errors.push("Effective date must be on or before the disposal date".to_string());
+        }
+        if let Some(latest) = latest.filter(|latest| effective_date < latest.effective_date.trim())
+        {
+            errors.push(format!(
+                "Effective date must be on or after the last change, effective {}",
+                latest.effective_date
+            ));
+        }
+    }
+
+    if change.useful_life_years < 1 {
+        errors.push("Useful life must be at least 1 year".to_string());
+    }
+    if change.salvage_value < Money::ZERO {
+        errors.push("Salvage value cannot be negative".to_string());
+    } else if change.salvage_value > asset.cost {
+        errors.push("Salvage value cannot exceed cost".to_string());
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate an impairment against its asset: dated while the asset is in
+/// service and no earlier than the last one, and no more than the carrying
+/// amount the financial book shows at the end of that fiscal year
+pub fn validate_impairment(
+    impairment: &Impairment,
+    asset: &Asset,
+    latest: Option<&Impairment>,
+    carrying_amount: Money,
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    let impairment_date = impairment.impairment_date.trim();
+    if impairment_date.is_empty() {
+        errors.push("Impairment date is required".to_string());
+    } else if parse_date(impairment_date).is_err() {
+        errors.push("Invalid impairment date format (use YYYY-MM-DD)".to_string());
+    } else {
+        if is_future_date(impairment_date) {
+            errors.push("Impairment date cannot be in the future".to_string());
+        }
+        if impairment_date < asset.date_placed_in_service.trim() {
+            errors.push("Impairment date is before the date placed in service".to_string());
+        }
+        if asset
+            .disposed_date
+            .as_deref()
+            .is_some_and(|disposed| impairment_date > disposed.trim())
+        {
+            errors.push("Impairment date must be on or before the disposal date".to_string());
+        }
+        if let Some(latest) =
+            latest.filter(|latest| impairment_date < latest.impairment_date.trim())
+        {
+            errors.push(format!(
+                "Impairment date must be on or after the last impairment, on {}",
+                latest.impairment_date
+            ));
+        }
+    }
+
+    if impairment.amount <= Money::ZERO {
+        errors.push("Impairment loss must be greater than 0".to_string());
+    } else if impairment.amount > carrying_amount {
+        errors.push(format!(
+            "Impairment loss can't exceed the carrying amount of ${}",
+            carrying_amount
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a revaluation against the asset and its latest revaluation.
+/// The financial book takes a revaluation at the end of its fiscal year, so
+/// there can only be one a year.
+pub fn validate_revaluation(
+    revaluation: &Revaluation,
+    asset: &Asset,
+    latest: Option<&Revaluation>,
+    fiscal: FiscalYear,
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    let revaluation_date = revaluation.revaluation_date.trim();
+    if revaluation_date.is_empty() {
+        errors.push("Revaluation date is required".to_string());
+    } else if parse_date(revaluation_date).is_err() {
+        errors.push("Invalid revaluation date format (use YYYY-MM-DD)".to_string());
+    } else {
+        if is_future_date(revaluation_date) {
+            errors.push("Revaluation date cannot be in the future".to_string());
+        }
+        if revaluation_date < asset.date_placed_in_service.trim() {
+            errors.push("Revaluation date is before the date placed in service".to_string());
+        }
+        if asset
+            .disposed_date
+            .as_deref()
+            .is_some_and(|disposed| revaluation_date > disposed.trim())
+        {
+            errors.push("Revaluation date must be on or before the disposal date".to_string());
+        }
+        if let Some(latest) = latest.filter(|latest| {
+            fiscal.year_of(revaluation_date) <= fiscal.year_of(latest.revaluation_date.trim())
+        }) {
+            errors.push(format!(
+                "Revaluation date must be in a later fiscal year than the last revaluation, on {}",
+                latest.revaluation_date
+            ));
+        }
+    }
+
+    if revaluation.fair_value < Money::ZERO {
+        errors.push("Fair value cannot be negative".to_string());
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
+/// Validate a component of an asset against the asset and its other
+/// components, which together can't cost more than the asset. Components
+/// are depreciated in the financial book by one of the book methods.
+pub fn validate_component(
+    component: &AssetComponent,
+    asset: &Asset,
+    others: &[AssetComponent],
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    if component.name.trim().is_empty() {
+        errors.push("Component name is required".to_string());
+    }
+
+    if component.cost <= Money::ZERO {
+        errors.push("Cost must be greater than 0".to_string());
+    } else {
+        let others_cost: Money = others
+            .iter()
+            .filter(|other| other.id.is_none() || other.id != component.id)
+            .map(|other| other.cost)
+            .sum();
+        if others_cost + component.cost > asset.cost {
+            errors.push(format!(
+                "Components can't cost more than the asset's ${}",
+                asset.cost
+            ));
+        }
+    }
+    if component.salvage_value < Money::ZERO {
+        errors.push("Salvage value cannot be negative".to_string());
+    } else if component.salvage_value > component.cost {
+        errors.push("Salvage value cannot exceed cost".to_string());
+    }
+
+    if component.useful_life_years < 1 {
+        errors.push("Useful life must be at least 1 year".to_string());
+    }
+
+    match component.method.as_deref() {
+        method if is_macrs(method) => {
+            errors.push("MACRS applies to the whole asset, not its components".to_string());
+        }
+        Some(METHOD_UNITS_OF_PRODUCTION) => {
+            errors.push("Components can't be depreciated by units of production".to_string());
+        }
+        method => {
+            if let Some(error) = check_method(method, None) {
+                errors.push(error);
+            }
+        }
+    }
+    if let Some(error) = check_convention(
+        component.convention.as_deref(),
+        component.method.as_deref(),
+        None,
+    ) {
+        errors.push(error);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::from_errors(errors))
+    }
+}
+
 // Helper functions
 
+fn check_disposal_terms(selling_costs: Option<Money>, proceeds_type: Option<&str>) -> Vec<String> {
+    let mut errors = Vec::new();
+    if selling_costs.is_some_and(|costs| costs < Money::ZERO) {
+        errors.push("Selling costs cannot be negative".to_string());
+    }
+    if let Some(proceeds_type) = proceeds_type.filter(|t| !PROCEEDS_TYPES.contains(t)) {
+        errors.push(format!("Invalid proceeds type: {}", proceeds_type));
+    }
+    errors
+}
+
+fn check_book(book: &str) -> Option<String> {
+    if BOOKS.contains(&book) {
+        None
+    } else {
+        Some(format!("Unknown book: {}", book))
+    }
+}
+
+fn check_method(method: Option<&str>, property_class: Option<&str>) -> Option<String> {
+    let method = method.filter(|m| !m.is_empty())?;
+    if !VALID_METHODS.contains(&method) {
+        return Some(format!("Invalid depreciation method: {}", method));
+    }
+    let has_class = property_class.is_some_and(|pc| !pc.trim().is_empty());
+    if is_macrs(Some(method)) && !has_class {
+        return Some("MACRS depreciation requires a property class".to_string());
+    }
+    None
+}
+
+/// Check the expected lifetime units, which units-of-production requires
+fn check_total_units(method: Option<&str>, total_units: Option<f64>) -> Option<String> {
+    match total_units {
+        Some(units) if units <= 0.0 => Some("Total units must be greater than 0".to_string()),
+        None if method == Some(METHOD_UNITS_OF_PRODUCTION) => {
+            Some("Units-of-production depreciation requires total expected units".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Check a Section 179 election. MACRS ignores salvage value, so the
+/// election is capped at cost there. Real property doesn't qualify, and
+/// neither does listed property used 50% or less for business.
+fn check_section_179(
+    amount: Option<Money>,
+    cost: Money,
+    salvage_value: Money,
+    method: Option<&str>,
+    property_class: Option<&str>,
+    business_use: Option<f64>,
+) -> Option<String> {
+    let amount = amount?;
+    if amount < Money::ZERO {
+        Some("Section 179 amount cannot be negative".to_string())
+    } else if amount > Money::ZERO
+        && property_class.is_some_and(|pc| REAL_PROPERTY_CLASSES.contains(&pc.trim()))
+    {
+        Some(
+            "Residential rental and nonresidential real property don't qualify for Section 179"
+                .to_string(),
+        )
+    } else if amount > Money::ZERO && business_use.is_some_and(|p| p <= 50.0) {
+        Some(
+            "Listed property used 50% or less for business doesn't qualify for Section 179"
+                .to_string(),
+        )
+    } else if is_macrs(method) && amount > cost {
+        Some("Section 179 amount cannot exceed cost".to_string())
+    } else if !is_macrs(method) && amount > cost - salvage_value {
+        Some("Section 179 amount cannot exceed cost less salvage value".to_string())
+    } else {
+        None
+    }
+}
+
+fn check_bonus_percentage(percentage: Option<f64>) -> Option<String> {
+    match percentage {
+        Some(p) if !(0.0..=100.0).contains(&p) => {
+            Some("Bonus percentage must be between 0 and 100".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Check the business use percentage of listed property. Property used 50%
+/// or less for business doesn't qualify for MACRS GDS and must use ADS.
+fn check_business_use(method: Option<&str>, percentage: Option<f64>) -> Option<String> {
+    match percentage {
+        Some(p) if !(0.0..=100.0).contains(&p) => {
+            Some("Business use percentage must be between 0 and 100".to_string())
+        }
+        Some(p) if p <= 50.0 && method == Some(METHOD_MACRS_GDS) => Some(
+            "Listed property used 50% or less for business must be depreciated under MACRS ADS"
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+/// Check the asset kind. Intangibles are amortized straight-line to zero,
+/// and Section 179 doesn't apply to them.
+fn check_asset_kind(asset: &Asset) -> Option<String> {
+    let kind = asset.asset_kind.as_deref().filter(|k| !k.is_empty())?;
+    if !ASSET_KINDS.contains(&kind) {
+        return Some(format!("Invalid asset kind: {}", kind));
+    }
+    if kind != KIND_INTANGIBLE {
+        return None;
+    }
+    if asset
+        .method
+        .as_deref()
+        .is_some_and(|method| !method.is_empty() && method != METHOD_STRAIGHT_LINE)
+    {
+        Some("Intangibles are amortized straight-line".to_string())
+    } else if asset.salvage_value > Money::ZERO {
+        Some("Intangibles are amortized to zero and cannot have a salvage value".to_string())
+    } else if asset
+        .section_179_amount
+        .is_some_and(|amount| amount > Money::ZERO)
+    {
+        Some("Section 179 does not apply to intangibles".to_string())
+    } else {
+        None
+    }
+}
+
+fn check_convention(
+    convention: Option<&str>,
+    method: Option<&str>,
+    property_class: Option<&str>,
+) -> Option<String> {
+    let convention = convention.filter(|c| !c.is_empty())?;
+    if !VALID_CONVENTIONS.contains(&convention) {
+        return Some(format!("Invalid convention: {}", convention));
+    }
+    if is_macrs(method) {
+        let is_real_property =
+            property_class.is_some_and(|pc| REAL_PROPERTY_CLASSES.contains(&pc.trim()));
+        if is_real_property && convention != CONVENTION_MID_MONTH {
+            return Some("MACRS real property must use the mid-month convention".to_string());
+        }
+        if !is_real_property
+            && ![CONVENTION_HALF_YEAR, CONVENTION_MID_QUARTER].contains(&convention)
+        {
+            return Some(
+                "MACRS personal property must use the half-year or mid-quarter convention"
+                    .to_string(),
+            );
+        }
+    }
+    None
+}
+
 fn parse_date(date_str: &str) -> std::result::Result<NaiveDate, ()> {
     NaiveDate::parse_from_str(date_str.trim(), "%Y-%m-%d").map_err(|_| ())
 }
@@ -288,13 +1200,26 @@ mod tests {
             description: Some("A test asset".to_string()),
             category_id: None,
             date_placed_in_service: "2024-01-15".to_string(),
-            cost: 1000.0,
-            salvage_value: 100.0,
+            cost: Money::from_dollars(1000.0),
+            salvage_value: Money::from_dollars(100.0),
             useful_life_years: 5,
             property_class: Some("5".to_string()),
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+            business_use_percentage: None,
+            passenger_auto: false,
+            asset_kind: None,
             notes: None,
+            quantity: None,
             disposed_date: None,
             disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+            parent_asset_id: None,
+            improved_asset_id: None,
             created_at: None,
             updated_at: None,
         }
@@ -307,6 +1232,8 @@ mod tests {
             name: "Equipment".to_string(),
             default_useful_life: Some(5),
             default_property_class: Some("5".to_string()),
+            default_method: None,
+            revaluation_model: false,
             created_at: None,
             updated_at: None,
         }
@@ -319,10 +1246,15 @@ mod tests {
             description: Some("An imported asset".to_string()),
             category: Some("Equipment".to_string()),
             date_placed_in_service: "2024-01-15".to_string(),
-            cost: 5000.0,
-            salvage_value: Some(500.0),
+            cost: Money::from_dollars(5000.0),
+            salvage_value: Some(Money::from_dollars(500.0)),
             useful_life_years: 7,
             property_class: Some("7".to_string()),
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: None,
             notes: None,
         }
     }
@@ -342,13 +1274,26 @@ mod tests {
             description: None,
             category_id: None,
             date_placed_in_service: "2024-01-01".to_string(),
-            cost: 100.0,
-            salvage_value: 0.0,
+            cost: Money::from_dollars(100.0),
+            salvage_value: Money::from_dollars(0.0),
             useful_life_years: 1,
             property_class: None,
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+            business_use_percentage: None,
+            passenger_auto: false,
+            asset_kind: None,
             notes: None,
+            quantity: None,
             disposed_date: None,
             disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+            parent_asset_id: None,
+            improved_asset_id: None,
             created_at: None,
             updated_at: None,
         };
@@ -436,132 +1381,332 @@ mod tests {
         assert!(validate_asset(&asset).is_ok());
     }
 
-    // Cost validation tests
+    // Cost validation tests
+    #[test]
+    fn test_validate_asset_zero_cost() {
+        let mut asset = valid_asset();
+        asset.cost = Money::from_dollars(0.0);
+        asset.salvage_value = Money::from_dollars(0.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cost must be greater than"));
+    }
+
+    #[test]
+    fn test_validate_asset_negative_cost() {
+        let mut asset = valid_asset();
+        asset.cost = Money::from_dollars(-500.0);
+        asset.salvage_value = Money::from_dollars(0.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cost must be greater than"));
+    }
+
+    #[test]
+    fn test_validate_asset_small_positive_cost() {
+        let mut asset = valid_asset();
+        asset.cost = Money::from_dollars(0.01);
+        asset.salvage_value = Money::from_dollars(0.0);
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    // Salvage value validation tests
+    #[test]
+    fn test_validate_asset_negative_salvage() {
+        let mut asset = valid_asset();
+        asset.salvage_value = Money::from_dollars(-100.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Salvage value cannot be negative"));
+    }
+
+    #[test]
+    fn test_validate_asset_salvage_exceeds_cost() {
+        let mut asset = valid_asset();
+        asset.cost = Money::from_dollars(1000.0);
+        asset.salvage_value = Money::from_dollars(2000.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Salvage value cannot exceed cost"));
+    }
+
+    #[test]
+    fn test_validate_asset_salvage_equals_cost() {
+        let mut asset = valid_asset();
+        asset.cost = Money::from_dollars(1000.0);
+        asset.salvage_value = Money::from_dollars(1000.0);
+        // Salvage equal to cost is valid (no depreciation)
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    #[test]
+    fn test_validate_asset_zero_salvage() {
+        let mut asset = valid_asset();
+        asset.salvage_value = Money::from_dollars(0.0);
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    // Useful life validation tests
+    #[test]
+    fn test_validate_asset_zero_useful_life() {
+        let mut asset = valid_asset();
+        asset.useful_life_years = 0;
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("at least 1 year"));
+    }
+
+    #[test]
+    fn test_validate_asset_one_year_useful_life() {
+        let mut asset = valid_asset();
+        asset.useful_life_years = 1;
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    // Property class validation tests
+    #[test]
+    fn test_validate_asset_all_valid_property_classes() {
+        for class in VALID_PROPERTY_CLASSES {
+            let mut asset = valid_asset();
+            asset.property_class = Some(class.to_string());
+            assert!(
+                validate_asset(&asset).is_ok(),
+                "Property class '{}' should be valid",
+                class
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_asset_invalid_property_class() {
+        let mut asset = valid_asset();
+        asset.property_class = Some("6".to_string());
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid property class"));
+    }
+
+    #[test]
+    fn test_validate_asset_invalid_property_class_text() {
+        let mut asset = valid_asset();
+        asset.property_class = Some("invalid".to_string());
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid property class"));
+    }
+
+    #[test]
+    fn test_validate_asset_empty_property_class_is_valid() {
+        let mut asset = valid_asset();
+        asset.property_class = Some("".to_string());
+        // Empty string is treated as no property class
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    #[test]
+    fn test_validate_asset_none_property_class_is_valid() {
+        let mut asset = valid_asset();
+        asset.property_class = None;
+        assert!(validate_asset(&asset).is_ok());
+    }
+
+    // Depreciation method validation tests
+    #[test]
+    fn test_validate_asset_all_valid_methods() {
+        for method in VALID_METHODS {
+            let mut asset = valid_asset();
+            asset.method = Some(method.to_string());
+            asset.total_units = Some(10000.0);
+            assert!(
+                validate_asset(&asset).is_ok(),
+                "Method '{}' should be valid",
+                method
+            );
+        }
+    }
+
     #[test]
-    fn test_validate_asset_zero_cost() {
+    fn test_validate_asset_invalid_method() {
         let mut asset = valid_asset();
-        asset.cost = 0.0;
-        asset.salvage_value = 0.0;
+        asset.method = Some("accelerated".to_string());
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Cost must be greater than"));
+        assert!(result.unwrap_err().to_string().contains("Invalid depreciation method"));
     }
 
     #[test]
-    fn test_validate_asset_negative_cost() {
+    fn test_validate_asset_macrs_requires_property_class() {
         let mut asset = valid_asset();
-        asset.cost = -500.0;
-        asset.salvage_value = 0.0;
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.property_class = None;
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Cost must be greater than"));
+        assert!(result.unwrap_err().to_string().contains("requires a property class"));
     }
 
     #[test]
-    fn test_validate_asset_small_positive_cost() {
+    fn test_validate_asset_business_use_forces_ads() {
         let mut asset = valid_asset();
-        asset.cost = 0.01;
-        asset.salvage_value = 0.0;
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.business_use_percentage = Some(60.0);
+        assert!(validate_asset(&asset).is_ok());
+
+        asset.business_use_percentage = Some(50.0);
+        let result = validate_asset(&asset);
+        assert!(result.unwrap_err().to_string().contains("must be depreciated under MACRS ADS"));
+
+        asset.method = Some(METHOD_MACRS_ADS.to_string());
         assert!(validate_asset(&asset).is_ok());
+
+        asset.business_use_percentage = Some(120.0);
+        assert!(validate_asset(&asset).is_err());
     }
 
-    // Salvage value validation tests
     #[test]
-    fn test_validate_asset_negative_salvage() {
+    fn test_validate_asset_intangible_amortized_straight_line() {
         let mut asset = valid_asset();
-        asset.salvage_value = -100.0;
+        asset.asset_kind = Some(KIND_INTANGIBLE.to_string());
+        asset.method = Some(METHOD_STRAIGHT_LINE.to_string());
+        asset.salvage_value = Money::ZERO;
+        assert!(validate_asset(&asset).is_ok());
+
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
         let result = validate_asset(&asset);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Salvage value cannot be negative"));
+        assert!(result.unwrap_err().to_string().contains("amortized straight-line"));
+
+        asset.method = None;
+        asset.salvage_value = Money::from_dollars(100.0);
+        assert!(validate_asset(&asset).is_err());
+
+        asset.salvage_value = Money::ZERO;
+        asset.section_179_amount = Some(Money::from_dollars(500.0));
+        assert!(validate_asset(&asset).is_err());
+
+        asset.section_179_amount = None;
+        asset.asset_kind = Some("goodwill".to_string());
+        let result = validate_asset(&asset);
+        assert!(result.unwrap_err().to_string().contains("Invalid asset kind"));
     }
 
     #[test]
-    fn test_validate_asset_salvage_exceeds_cost() {
+    fn test_validate_asset_units_of_production_requires_total_units() {
         let mut asset = valid_asset();
-        asset.cost = 1000.0;
-        asset.salvage_value = 2000.0;
+        asset.method = Some(METHOD_UNITS_OF_PRODUCTION.to_string());
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Salvage value cannot exceed cost"));
+        assert!(result.unwrap_err().to_string().contains("requires total expected units"));
+
+        asset.total_units = Some(0.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Total units must be greater than 0"));
     }
 
     #[test]
-    fn test_validate_asset_salvage_equals_cost() {
+    fn test_validate_asset_section_179() {
         let mut asset = valid_asset();
-        asset.cost = 1000.0;
-        asset.salvage_value = 1000.0;
-        // Salvage equal to cost is valid (no depreciation)
+        asset.section_179_amount = Some(Money::from_dollars(900.0));
         assert!(validate_asset(&asset).is_ok());
-    }
 
-    #[test]
-    fn test_validate_asset_zero_salvage() {
-        let mut asset = valid_asset();
-        asset.salvage_value = 0.0;
+        asset.section_179_amount = Some(Money::from_dollars(-1.0));
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot be negative"));
+
+        asset.section_179_amount = Some(Money::from_dollars(950.0));
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot exceed cost less salvage"));
+
+        // MACRS ignores salvage value, so the election runs up to cost
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
         assert!(validate_asset(&asset).is_ok());
-    }
+        asset.section_179_amount = Some(Money::from_dollars(1001.0));
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot exceed cost"));
 
-    // Useful life validation tests
-    #[test]
-    fn test_validate_asset_zero_useful_life() {
-        let mut asset = valid_asset();
-        asset.useful_life_years = 0;
+        // Real property doesn't qualify
+        asset.section_179_amount = Some(Money::from_dollars(500.0));
+        asset.property_class = Some("39".to_string());
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("at least 1 year"));
+        assert!(result.unwrap_err().to_string().contains("don't qualify for Section 179"));
+
+        // Nor does listed property used 50% or less for business
+        asset.property_class = Some("5".to_string());
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        asset.method = Some(METHOD_MACRS_ADS.to_string());
+        asset.business_use_percentage = Some(60.0);
+        assert!(validate_asset(&asset).is_ok());
+        asset.business_use_percentage = Some(50.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("doesn't qualify for Section 179"));
     }
 
     #[test]
-    fn test_validate_asset_one_year_useful_life() {
+    fn test_validate_asset_bonus_percentage() {
         let mut asset = valid_asset();
-        asset.useful_life_years = 1;
+        asset.bonus_percentage = Some(0.0);
         assert!(validate_asset(&asset).is_ok());
+        asset.bonus_percentage = Some(100.0);
+        assert!(validate_asset(&asset).is_ok());
+
+        asset.bonus_percentage = Some(120.0);
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("between 0 and 100"));
     }
 
-    // Property class validation tests
+    // Convention validation tests
     #[test]
-    fn test_validate_asset_all_valid_property_classes() {
-        for class in VALID_PROPERTY_CLASSES {
+    fn test_validate_asset_all_valid_conventions() {
+        for convention in VALID_CONVENTIONS {
             let mut asset = valid_asset();
-            asset.property_class = Some(class.to_string());
+            asset.convention = Some(convention.to_string());
             assert!(
                 validate_asset(&asset).is_ok(),
-                "Property class '{}' should be valid",
-                class
+                "Convention '{}' should be valid",
+                convention
             );
         }
     }
 
     #[test]
-    fn test_validate_asset_invalid_property_class() {
+    fn test_validate_asset_invalid_convention() {
         let mut asset = valid_asset();
-        asset.property_class = Some("6".to_string());
+        asset.convention = Some("mid_year".to_string());
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid property class"));
+        assert!(result.unwrap_err().to_string().contains("Invalid convention"));
     }
 
     #[test]
-    fn test_validate_asset_invalid_property_class_text() {
+    fn test_validate_asset_macrs_personal_property_convention() {
         let mut asset = valid_asset();
-        asset.property_class = Some("invalid".to_string());
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.convention = Some(CONVENTION_MID_QUARTER.to_string());
+        assert!(validate_asset(&asset).is_ok());
+
+        asset.convention = Some(CONVENTION_FULL_MONTH.to_string());
         let result = validate_asset(&asset);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid property class"));
+        assert!(result.unwrap_err().to_string().contains("half-year or mid-quarter"));
     }
 
     #[test]
-    fn test_validate_asset_empty_property_class_is_valid() {
+    fn test_validate_asset_macrs_real_property_requires_mid_month() {
         let mut asset = valid_asset();
-        asset.property_class = Some("".to_string());
-        // Empty string is treated as no property class
-        assert!(validate_asset(&asset).is_ok());
-    }
+        asset.method = Some(METHOD_MACRS_GDS.to_string());
+        asset.property_class = Some("39".to_string());
+        asset.convention = Some(CONVENTION_HALF_YEAR.to_string());
+        let result = validate_asset(&asset);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("mid-month"));
 
-    #[test]
-    fn test_validate_asset_none_property_class_is_valid() {
-        let mut asset = valid_asset();
-        asset.property_class = None;
+        asset.convention = Some(CONVENTION_MID_MONTH.to_string());
         assert!(validate_asset(&asset).is_ok());
     }
 
@@ -645,7 +1790,7 @@ mod tests {
     fn test_validate_asset_negative_disposed_value() {
         let mut asset = valid_asset();
         asset.disposed_date = Some("2024-06-01".to_string());
-        asset.disposed_value = Some(-100.0);
+        asset.disposed_value = Some(Money::from_dollars(-100.0));
         let result = validate_asset(&asset);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Disposal value cannot be negative"));
@@ -655,7 +1800,7 @@ mod tests {
     fn test_validate_asset_zero_disposed_value_is_valid() {
         let mut asset = valid_asset();
         asset.disposed_date = Some("2024-06-01".to_string());
-        asset.disposed_value = Some(0.0);
+        asset.disposed_value = Some(Money::from_dollars(0.0));
         assert!(validate_asset(&asset).is_ok());
     }
 
@@ -668,13 +1813,26 @@ mod tests {
             description: None,
             category_id: None,
             date_placed_in_service: "invalid".to_string(),
-            cost: 0.0,
-            salvage_value: -10.0,
+            cost: Money::from_dollars(0.0),
+            salvage_value: Money::from_dollars(-10.0),
             useful_life_years: 0,
             property_class: Some("invalid".to_string()),
+            method: None,
+            convention: None,
+            total_units: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+            business_use_percentage: None,
+            passenger_auto: false,
+            asset_kind: None,
             notes: None,
+            quantity: None,
             disposed_date: None,
             disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+            parent_asset_id: None,
+            improved_asset_id: None,
             created_at: None,
             updated_at: None,
         };
@@ -701,6 +1859,8 @@ mod tests {
             name: "Basic".to_string(),
             default_useful_life: None,
             default_property_class: None,
+            default_method: None,
+            revaluation_model: false,
             created_at: None,
             updated_at: None,
         };
@@ -766,6 +1926,18 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Invalid default property class"));
     }
 
+    #[test]
+    fn test_validate_category_default_method() {
+        let mut category = valid_category();
+        category.default_method = Some(METHOD_SUM_OF_YEARS_DIGITS.to_string());
+        assert!(validate_category(&category).is_ok());
+
+        category.default_method = Some("accelerated".to_string());
+        let result = validate_category(&category);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid depreciation method"));
+    }
+
     #[test]
     fn test_validate_category_all_valid_property_classes() {
         for class in VALID_PROPERTY_CLASSES {
@@ -783,7 +1955,7 @@ mod tests {
 
     #[test]
     fn test_validate_disposal_valid() {
-        let result = validate_disposal("2024-06-15", Some(500.0), "2024-01-15");
+        let result = validate_disposal("2024-06-15", Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_ok());
     }
 
@@ -795,21 +1967,21 @@ mod tests {
 
     #[test]
     fn test_validate_disposal_empty_date() {
-        let result = validate_disposal("", Some(500.0), "2024-01-15");
+        let result = validate_disposal("", Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Disposal date is required"));
     }
 
     #[test]
     fn test_validate_disposal_whitespace_date() {
-        let result = validate_disposal("   ", Some(500.0), "2024-01-15");
+        let result = validate_disposal("   ", Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Disposal date is required"));
     }
 
     #[test]
     fn test_validate_disposal_invalid_format() {
-        let result = validate_disposal("06-15-2024", Some(500.0), "2024-01-15");
+        let result = validate_disposal("06-15-2024", Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid disposal date format"));
     }
@@ -818,14 +1990,14 @@ mod tests {
     fn test_validate_disposal_future_date() {
         let future = chrono::Local::now().date_naive() + chrono::Duration::days(30);
         let future_str = future.format("%Y-%m-%d").to_string();
-        let result = validate_disposal(&future_str, Some(500.0), "2024-01-15");
+        let result = validate_disposal(&future_str, Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("cannot be in the future"));
     }
 
     #[test]
     fn test_validate_disposal_before_service_date() {
-        let result = validate_disposal("2024-01-01", Some(500.0), "2024-06-01");
+        let result = validate_disposal("2024-01-01", Some(Money::from_dollars(500.0)), "2024-06-01");
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -835,23 +2007,476 @@ mod tests {
 
     #[test]
     fn test_validate_disposal_same_as_service_date() {
-        let result = validate_disposal("2024-01-15", Some(500.0), "2024-01-15");
+        let result = validate_disposal("2024-01-15", Some(Money::from_dollars(500.0)), "2024-01-15");
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_validate_disposal_negative_value() {
-        let result = validate_disposal("2024-06-15", Some(-100.0), "2024-01-15");
+        let result = validate_disposal("2024-06-15", Some(Money::from_dollars(-100.0)), "2024-01-15");
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Disposal value cannot be negative"));
     }
 
     #[test]
     fn test_validate_disposal_zero_value() {
-        let result = validate_disposal("2024-06-15", Some(0.0), "2024-01-15");
+        let result = validate_disposal("2024-06-15", Some(Money::from_dollars(0.0)), "2024-01-15");
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_disposal_terms() {
+        assert!(validate_disposal_terms(None, None).is_ok());
+        assert!(validate_disposal_terms(Some(Money::from_dollars(150.0)), Some("trade_in")).is_ok());
+
+        let result = validate_disposal_terms(Some(Money::from_dollars(-1.0)), Some("sale"));
+        assert!(result.unwrap_err().to_string().contains("Selling costs cannot be negative"));
+
+        let result = validate_disposal_terms(None, Some("donation"));
+        assert!(result.unwrap_err().to_string().contains("Invalid proceeds type"));
+    }
+
+    #[test]
+    fn test_validate_asset_disposal_terms() {
+        let mut asset = valid_asset();
+        asset.disposed_date = Some("2024-06-01".to_string());
+        asset.proceeds_type = Some("casualty".to_string());
+        assert!(validate_asset(&asset).is_ok());
+
+        asset.selling_costs = Some(Money::from_dollars(-50.0));
+        let result = validate_asset(&asset);
+        assert!(result.unwrap_err().to_string().contains("Selling costs cannot be negative"));
+    }
+
+    #[test]
+    fn test_validate_partial_disposal() {
+        let mut asset = valid_asset();
+        asset.quantity = Some(10);
+        let mut disposal = PartialDisposal {
+            asset_id: 1,
+            disposed_date: "2024-06-01".to_string(),
+            quantity: Some(3),
+            percentage: None,
+            disposed_value: None,
+            selling_costs: None,
+            proceeds_type: None,
+        };
+        assert!(validate_partial_disposal(&disposal, &asset).is_ok());
+
+        // The whole quantity is a regular disposal
+        disposal.quantity = Some(10);
+        let result = validate_partial_disposal(&disposal, &asset);
+        assert!(result.unwrap_err().to_string().contains("between 1 and 9"));
+
+        disposal.quantity = None;
+        disposal.percentage = Some(40.0);
+        assert!(validate_partial_disposal(&disposal, &asset).is_ok());
+        disposal.percentage = Some(100.0);
+        assert!(validate_partial_disposal(&disposal, &asset).is_err());
+
+        disposal.quantity = Some(3);
+        disposal.percentage = Some(40.0);
+        let result = validate_partial_disposal(&disposal, &asset);
+        assert!(result.unwrap_err().to_string().contains("not both"));
+
+        // Quantities need the asset's quantity to be known
+        asset.quantity = None;
+        disposal.percentage = None;
+        let result = validate_partial_disposal(&disposal, &asset);
+        assert!(result.unwrap_err().to_string().contains("no quantity"));
+    }
+
+    #[test]
+    fn test_validate_improvement() {
+        let asset = valid_asset();
+        let mut improvement = valid_asset();
+        improvement.id = None;
+        improvement.date_placed_in_service = "2024-06-01".to_string();
+        assert!(validate_improvement(&improvement, &asset).is_ok());
+
+        improvement.date_placed_in_service = "2023-12-31".to_string();
+        let result = validate_improvement(&improvement, &asset);
+        assert!(result.unwrap_err().to_string().contains("before the asset it improves"));
+
+        // One level deep only
+        let mut nested = asset.clone();
+        nested.improved_asset_id = Some(7);
+        improvement.date_placed_in_service = "2024-06-01".to_string();
+        let result = validate_improvement(&improvement, &nested);
+        assert!(result.unwrap_err().to_string().contains("main asset instead"));
+    }
+
+    #[test]
+    fn test_validate_estimate_change() {
+        let asset = valid_asset();
+        let mut change = EstimateChange {
+            id: None,
+            asset_id: 1,
+            effective_date: "2024-06-01".to_string(),
+            useful_life_years: 7,
+            salvage_value: Money::from_dollars(100.0),
+            previous_useful_life_years: None,
+            previous_salvage_value: None,
+            reason: Some("Longer expected use".to_string()),
+            created_at: None,
+        };
+        assert!(validate_estimate_change(&change, &asset, None).is_ok());
+
+        // Revisions can't reach back past an earlier one
+        let mut latest = change.clone();
+        latest.effective_date = "2024-09-01".to_string();
+        let result = validate_estimate_change(&change, &asset, Some(&latest));
+        assert!(result.unwrap_err().to_string().contains("after the last change"));
+
+        change.useful_life_years = 0;
+        change.salvage_value = asset.cost + Money::from_dollars(1.0);
+        let result = validate_estimate_change(&change, &asset, None);
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Useful life must be at least 1 year"));
+        assert!(message.contains("Salvage value cannot exceed cost"));
+
+        let mut macrs = valid_asset();
+        macrs.method = Some(METHOD_MACRS_GDS.to_string());
+        change.useful_life_years = 7;
+        change.salvage_value = Money::ZERO;
+        let result = validate_estimate_change(&change, &macrs, None);
+        assert!(result.unwrap_err().to_string().contains("MACRS"));
+    }
+
+    #[test]
+    fn test_validate_impairment() {
+        let asset = valid_asset();
+        let mut impairment = Impairment {
+            id: None,
+            asset_id: 1,
+            impairment_date: "2024-12-31".to_string(),
+            amount: Money::from_dollars(300.0),
+            reason: Some("Market value fell".to_string()),
+            created_at: None,
+        };
+        let carrying = Money::from_dollars(820.0);
+        assert!(validate_impairment(&impairment, &asset, None, carrying).is_ok());
+
+        let latest = Impairment {
+            impairment_date: "2025-03-31".to_string(),
+            ..impairment.clone()
+        };
+        let message = validate_impairment(&impairment, &asset, Some(&latest), carrying)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("on or after the last impairment"));
+
+        impairment.impairment_date = "2023-12-31".to_string();
+        impairment.amount = Money::from_dollars(900.0);
+        let message = validate_impairment(&impairment, &asset, None, carrying)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("before the date placed in service"));
+        assert!(message.contains("can't exceed the carrying amount of $820.00"));
+    }
+
+    #[test]
+    fn test_validate_revaluation() {
+        let asset = valid_asset();
+        let fiscal = FiscalYear::CALENDAR;
+        let mut revaluation = Revaluation {
+            id: None,
+            asset_id: 1,
+            revaluation_date: "2024-12-31".to_string(),
+            fair_value: Money::from_dollars(1200.0),
+            reason: Some("Independent valuation".to_string()),
+            created_at: None,
+            carrying_amount: None,
+            reserve_change: None,
+            profit_loss: None,
+        };
+        assert!(validate_revaluation(&revaluation, &asset, None, fiscal).is_ok());
+
+        // One a fiscal year, after the last
+        let latest = Revaluation {
+            revaluation_date: "2024-01-31".to_string(),
+            ..revaluation.clone()
+        };
+        let message = validate_revaluation(&revaluation, &asset, Some(&latest), fiscal)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("later fiscal year than the last revaluation"));
+
+        revaluation.revaluation_date = "2023-12-31".to_string();
+        revaluation.fair_value = Money::from_dollars(-1.0);
+        let message = validate_revaluation(&revaluation, &asset, None, fiscal)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("before the date placed in service"));
+        assert!(message.contains("Fair value cannot be negative"));
+    }
+
+    #[test]
+    fn test_validate_component() {
+        let asset = valid_asset();
+        let mut roof = AssetComponent {
+            id: None,
+            asset_id: 1,
+            name: "Roof".to_string(),
+            cost: Money::from_dollars(500.0),
+            salvage_value: Money::ZERO,
+            useful_life_years: 20,
+            method: Some(METHOD_STRAIGHT_LINE.to_string()),
+            convention: None,
+        };
+        assert!(validate_component(&roof, &asset, &[]).is_ok());
+
+        // With the other components, more than the asset cost
+        let mut hvac = roof.clone();
+        hvac.id = Some(2);
+        hvac.cost = asset.cost;
+        let message = validate_component(&roof, &asset, &[hvac.clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("Components can't cost more than the asset's"));
+
+        // A component isn't counted twice when it is edited
+        assert!(validate_component(&hvac, &asset, &[hvac.clone()]).is_ok());
+
+        roof.name = " ".to_string();
+        roof.method = Some(METHOD_MACRS_GDS.to_string());
+        roof.salvage_value = Money::from_dollars(600.0);
+        let message = validate_component(&roof, &asset, &[]).unwrap_err().to_string();
+        assert!(message.contains("Component name is required"));
+        assert!(message.contains("MACRS applies to the whole asset"));
+        assert!(message.contains("Salvage value cannot exceed cost"));
+    }
+
+    // ==================== Section 179 Limit Validation Tests ====================
+
+    #[test]
+    fn test_validate_bonus_opt_out() {
+        assert!(validate_bonus_opt_out(2024, "7").is_ok());
+        assert!(validate_bonus_opt_out(2024, " 27.5 ").is_ok());
+
+        let result = validate_bonus_opt_out(2024, "6");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid property class"));
+
+        let result = validate_bonus_opt_out(1800, "7");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("between 1900 and 2100"));
+    }
+
+    #[test]
+    fn test_validate_section_179_limit() {
+        let mut limit = Section179Limit {
+            year: 2024,
+            dollar_limit: Money::from_dollars(1_220_000.0),
+            phase_out_threshold: Money::from_dollars(3_050_000.0),
+        };
+        assert!(validate_section_179_limit(&limit).is_ok());
+
+        limit.dollar_limit = Money::from_dollars(-1.0);
+        let result = validate_section_179_limit(&limit);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Dollar limit cannot be negative"));
+    }
+
+    // ==================== Usage Validation Tests ====================
+
+    fn usage_entry(year: i32, units: f64) -> UsageEntry {
+        UsageEntry {
+            id: None,
+            asset_id: 1,
+            year,
+            units,
+            notes: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_usage_valid() {
+        assert!(validate_usage(&usage_entry(2024, 1500.0), "2024-01-15", FiscalYear::CALENDAR).is_ok());
+        assert!(validate_usage(&usage_entry(2024, 0.0), "2024-01-15", FiscalYear::CALENDAR).is_ok());
+    }
+
+    #[test]
+    fn test_validate_usage_negative_units() {
+        let result = validate_usage(&usage_entry(2024, -1.0), "2024-01-15", FiscalYear::CALENDAR);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Units cannot be negative"));
+    }
+
+    #[test]
+    fn test_validate_usage_before_service_year() {
+        let result = validate_usage(&usage_entry(2023, 100.0), "2024-01-15", FiscalYear::CALENDAR);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("before the year placed in service"));
+    }
+
+    #[test]
+    fn test_validate_usage_future_year() {
+        let next_year = chrono::Local::now().year() + 1;
+        let result = validate_usage(&usage_entry(next_year, 100.0), "2024-01-15", FiscalYear::CALENDAR);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("future year"));
+    }
+
+    #[test]
+    fn test_validate_usage_fiscal_year() {
+        // Placed in service in August 2024, which is FY2025 with a June year end
+        let fiscal = FiscalYear::ending_in(6);
+        assert!(validate_usage(&usage_entry(2025, 100.0), "2024-08-01", fiscal).is_ok());
+        assert!(validate_usage(&usage_entry(2024, 100.0), "2024-08-01", fiscal).is_err());
+    }
+
+    #[test]
+    fn test_validate_business_use() {
+        let entry = |year, percentage| BusinessUseEntry {
+            id: None,
+            asset_id: 1,
+            year,
+            percentage,
+            notes: None,
+            created_at: None,
+        };
+        let fiscal = FiscalYear::CALENDAR;
+
+        assert!(validate_business_use(&entry(2025, 40.0), "2024-01-15", fiscal).is_ok());
+
+        let result = validate_business_use(&entry(2024, 40.0), "2024-01-15", fiscal);
+        assert!(result.unwrap_err().to_string().contains("after the one placed in service"));
+
+        let result = validate_business_use(&entry(2025, 140.0), "2024-01-15", fiscal);
+        assert!(result.unwrap_err().to_string().contains("between 0 and 100"));
+    }
+
+    #[test]
+    fn test_validate_lease() {
+        let mut lease = Lease {
+            id: None,
+            asset_id: None,
+            name: "Office".to_string(),
+            commencement_date: "2024-01-01".to_string(),
+            term_years: 5,
+            monthly_payment: Money::from_dollars(2500.0),
+            payments_in_advance: true,
+            discount_rate: 5.0,
+            initial_direct_costs: None,
+            notes: None,
+            created_at: None,
+            updated_at: None,
+        };
+        assert!(validate_lease(&lease).is_ok());
+
+        lease.term_years = 0;
+        let result = validate_lease(&lease);
+        assert!(result.unwrap_err().to_string().contains("at least 1 year"));
+
+        lease.term_years = 5;
+        lease.discount_rate = -1.0;
+        let result = validate_lease(&lease);
+        assert!(result.unwrap_err().to_string().contains("Discount rate"));
+
+        lease.discount_rate = 5.0;
+        lease.monthly_payment = Money::ZERO;
+        lease.commencement_date = "2024-13-01".to_string();
+        let error = validate_lease(&lease).unwrap_err().to_string();
+        assert!(error.contains("Monthly payment must be greater than $0"));
+        assert!(error.contains("Invalid commencement date format"));
+    }
+
+    #[test]
+    fn test_validate_period() {
+        assert!(validate_period("month").is_ok());
+        assert!(validate_period("quarter").is_ok());
+        let result = validate_period("week");
+        assert!(result.unwrap_err().to_string().contains("Invalid period"));
+    }
+
+    #[test]
+    fn test_validate_date_range() {
+        assert!(validate_date_range("2024-01-01", "2024-03-31").is_ok());
+        assert!(validate_date_range("2024-03-31", "2024-03-31").is_ok());
+
+        let result = validate_date_range("2024-04-01", "2024-03-31");
+        assert!(result.unwrap_err().to_string().contains("on or before the end date"));
+
+        let result = validate_date_range("April", "2024-03-31");
+        assert!(result.unwrap_err().to_string().contains("Start date must be in YYYY-MM-DD"));
+    }
+
+    #[test]
+    fn test_validate_fiscal_year_end() {
+        assert!(validate_fiscal_year_end(6).is_ok());
+        assert!(validate_fiscal_year_end(12).is_ok());
+        let result = validate_fiscal_year_end(0);
+        assert!(result.unwrap_err().to_string().contains("month from 1 to 12"));
+        assert!(validate_fiscal_year_end(13).is_err());
+    }
+
+    #[test]
+    fn test_validate_tax_rate() {
+        assert!(validate_tax_rate(21.0).is_ok());
+        assert!(validate_tax_rate(0.0).is_ok());
+        let result = validate_tax_rate(-1.0);
+        assert!(result.unwrap_err().to_string().contains("between 0 and 100"));
+        assert!(validate_tax_rate(120.0).is_err());
+    }
+
+    // ==================== Book Validation Tests ====================
+
+    fn asset_book(book: &str) -> AssetBook {
+        AssetBook {
+            asset_id: 1,
+            book: book.to_string(),
+            method: None,
+            convention: None,
+            useful_life_years: None,
+            property_class: None,
+            section_179_amount: None,
+            bonus_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_book() {
+        assert!(validate_book("federal").is_ok());
+        assert!(validate_book("book").is_ok());
+        let result = validate_book("gaap");
+        assert!(result.unwrap_err().to_string().contains("Unknown book"));
+    }
+
+    #[test]
+    fn test_validate_asset_book_valid() {
+        let mut book = asset_book("federal");
+        book.method = Some("macrs_gds".to_string());
+        book.convention = Some("half_year".to_string());
+        book.bonus_percentage = Some(60.0);
+        assert!(validate_asset_book(&book, &valid_asset()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_asset_book_rejects_financial_book() {
+        let result = validate_asset_book(&asset_book("book"), &valid_asset());
+        assert!(result.unwrap_err().to_string().contains("follows the asset"));
+    }
+
+    #[test]
+    fn test_validate_asset_book_checks_inherited_settings() {
+        // MACRS in the book needs a convention that fits the asset's class
+        let mut book = asset_book("amt");
+        book.method = Some("macrs_gds".to_string());
+        book.convention = Some("full_year".to_string());
+        let result = validate_asset_book(&book, &valid_asset());
+        assert!(result.unwrap_err().to_string().contains("half-year or mid-quarter"));
+
+        let mut book = asset_book("state");
+        book.useful_life_years = Some(0);
+        book.section_179_amount = Some(Money::from_dollars(5000.0));
+        let result = validate_asset_book(&book, &valid_asset());
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Useful life"));
+        assert!(message.contains("Section 179"));
+    }
+
     // ==================== Asset Import Validation Tests ====================
 
     #[test]
@@ -908,7 +2533,7 @@ mod tests {
     #[test]
     fn test_validate_asset_import_zero_cost() {
         let mut import = valid_asset_import();
-        import.cost = 0.0;
+        import.cost = Money::from_dollars(0.0);
         let result = validate_asset_import(&import, 1);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Cost must be greater than"));
@@ -917,7 +2542,7 @@ mod tests {
     #[test]
     fn test_validate_asset_import_negative_salvage() {
         let mut import = valid_asset_import();
-        import.salvage_value = Some(-50.0);
+        import.salvage_value = Some(Money::from_dollars(-50.0));
         let result = validate_asset_import(&import, 1);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Salvage value cannot be negative"));
@@ -926,8 +2551,8 @@ mod tests {
     #[test]
     fn test_validate_asset_import_salvage_exceeds_cost() {
         let mut import = valid_asset_import();
-        import.cost = 1000.0;
-        import.salvage_value = Some(2000.0);
+        import.cost = Money::from_dollars(1000.0);
+        import.salvage_value = Some(Money::from_dollars(2000.0));
         let result = validate_asset_import(&import, 7);
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -959,7 +2584,7 @@ mod tests {
     fn test_validate_asset_import_row_number_in_error() {
         let mut import = valid_asset_import();
         import.name = "".to_string();
-        import.cost = 0.0;
+        import.cost = Money::from_dollars(0.0);
 
         for row in [1, 50, 100, 999] {
             let result = validate_asset_import(&import, row);
@@ -9,6 +9,7 @@ import {
   Dashboard,
   AssetList,
   AssetDetail,
+  Leases,
   Analysis,
   Reports,
   Manual,
@@ -20,15 +21,17 @@ import type {
   AssetWithSchedule,
   Category,
   DashboardStats,
+  FiscalYearSettings,
   ImportResult,
   AnnualSummary,
   AssetFilters,
 } from "@/types";
 import { defaultAssetFilters } from "@/types";
+import { formatFiscalYear } from "@/lib/utils";
 
 import "@/index.css";
 
-type View = "dashboard" | "assets" | "asset-detail" | "analysis" | "reports" | "manual" | "settings";
+type View = "dashboard" | "assets" | "asset-detail" | "leases" | "analysis" | "reports" | "manual" | "settings";
 
 const STORAGE_KEY_SCALE = "abacus-ui-scale";
 const STORAGE_KEY_THEME = "abacus-theme";
@@ -50,6 +53,8 @@ function App() {
   const [error, setError] = useState<string | null>(null);
   const [success, setSuccess] = useState<string | null>(null);
   const [assetFilters, setAssetFilters] = useState<AssetFilters>(defaultAssetFilters);
+  const [book, setBook] = useState("book");
+  const [fiscalYear, setFiscalYear] = useState<FiscalYearSettings | null>(null);
 
   // UI Scale state - load from localStorage
   const [scale, setScale] = useState<number>(() => {
@@ -63,7 +68,8 @@ function App() {
     return (saved as Theme) || "system";
   });
 
-  const currentYear = new Date().getFullYear();
+  const currentYear = fiscalYear?.current_year ?? new Date().getFullYear();
+  const fiscalYearEnd = fiscalYear?.end_month ?? 12;
 
   // Persist scale to localStorage and apply to document root
   useEffect(() => {
@@ -119,20 +125,31 @@ function App() {
 
   useEffect(() => {
     loadData();
-  }, []);
+  }, [book]);
+
+  // Keep the open asset's schedule on the selected book
+  const selectedAssetId = selectedAsset?.asset.id;
+  useEffect(() => {
+    if (selectedAssetId === undefined) return;
+    invoke<AssetWithSchedule>("get_asset", { id: selectedAssetId, book })
+      .then(setSelectedAsset)
+      .catch((e) => setError(String(e)));
+  }, [book, selectedAssetId]);
 
   const loadData = async () => {
     try {
-      const [statsData, assetsData, categoriesData, summaryData] = await Promise.all([
+      const [statsData, assetsData, categoriesData, summaryData, fiscalYearData] = await Promise.all([
         invoke<DashboardStats>("get_dashboard_stats"),
         invoke<AssetWithSchedule[]>("get_assets"),
         invoke<Category[]>("get_categories"),
-        invoke<AnnualSummary[]>("get_annual_summary"),
+        invoke<AnnualSummary[]>("get_annual_summary", { book }),
+        invoke<FiscalYearSettings>("get_fiscal_year"),
       ]);
       setStats(statsData);
       setAssets(assetsData);
       setCategories(categoriesData);
       setAnnualSummary(summaryData);
+      setFiscalYear(fiscalYearData);
     } catch (e) {
       setError(String(e));
     }
@@ -177,11 +194,11 @@ function App() {
   const handleExportReport = async () => {
     try {
       const file = await save({
-        defaultPath: `depreciation_report_${currentYear}.xlsx`,
+        defaultPath: `depreciation_report_${formatFiscalYear(currentYear, fiscalYearEnd)}.xlsx`,
         filters: [{ name: "Excel", extensions: ["xlsx"] }],
       });
       if (file) {
-        await invoke("export_depreciation_report", { filePath: file });
+        await invoke("export_depreciation_report", { filePath: file, book });
         setSuccess("Report exported successfully");
       }
     } catch (e) {
@@ -199,12 +216,21 @@ function App() {
         if (selectedAsset && selectedAsset.asset.id === editingAsset.id) {
           const updated = await invoke<AssetWithSchedule>("get_asset", {
             id: editingAsset.id,
+            book,
           });
           setSelectedAsset(updated);
         }
       } else {
         await invoke("create_asset", { asset: editingAsset });
         setSuccess("Asset created successfully");
+        // An improvement changes the rolled-up schedule of the asset on screen
+        if (selectedAsset && selectedAsset.asset.id === editingAsset.improved_asset_id) {
+          const updated = await invoke<AssetWithSchedule>("get_asset", {
+            id: selectedAsset.asset.id,
+            book,
+          });
+          setSelectedAsset(updated);
+        }
       }
       await loadData();
       setAssetFormOpen(false);
@@ -214,6 +240,20 @@ function App() {
     }
   };
 
+  const handleScheduleChange = async () => {
+    if (!selectedAsset) return;
+    try {
+      const updated = await invoke<AssetWithSchedule>("get_asset", {
+        id: selectedAsset.asset.id,
+        book,
+      });
+      setSelectedAsset(updated);
+      await loadData();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
   const handleDeleteAsset = async () => {
     if (!selectedAsset) return;
     try {
@@ -250,6 +290,32 @@ function App() {
     setView("asset-detail");
   }, []);
 
+  const handleOpenAsset = async (id: number) => {
+    try {
+      const asset = await invoke<AssetWithSchedule>("get_asset", { id, book });
+      handleViewAsset(asset);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  // An improvement starts from the main asset's classification
+  const handleAddImprovement = useCallback(() => {
+    if (!selectedAsset) return;
+    const main = selectedAsset.asset;
+    setEditingAsset({
+      ...newAsset(),
+      name: `${main.name} - Improvement`,
+      category_id: main.category_id,
+      property_class: main.property_class,
+      method: main.method,
+      convention: main.convention,
+      useful_life_years: main.useful_life_years,
+      improved_asset_id: main.id,
+    });
+    setAssetFormOpen(true);
+  }, [selectedAsset]);
+
   const handleEditAsset = useCallback(() => {
     if (selectedAsset) {
       setEditingAsset(selectedAsset.asset);
@@ -288,6 +354,7 @@ function App() {
             stats={stats}
             assets={assets}
             currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
             onViewAsset={handleViewAsset}
             onNavigate={navigateTo}
           />
@@ -298,6 +365,7 @@ function App() {
             assets={assets}
             annualSummary={annualSummary}
             currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
             onFilterByCategory={handleFilterByCategory}
           />
         );
@@ -306,6 +374,7 @@ function App() {
           <AssetList
             assets={assets}
             currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
             filters={assetFilters}
             onFiltersChange={setAssetFilters}
             onResetFilters={handleResetFilters}
@@ -318,16 +387,38 @@ function App() {
           <AssetDetail
             asset={selectedAsset}
             currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
             onEdit={handleEditAsset}
             onDelete={handleDeleteAsset}
             onBack={() => navigateTo("assets")}
+            onOpenAsset={handleOpenAsset}
+            onAddImprovement={handleAddImprovement}
+            onScheduleChange={handleScheduleChange}
+            revaluationModel={categories.some(
+              (category) =>
+                category.id === selectedAsset.asset.category_id && category.revaluation_model
+            )}
+            book={book}
+            onBookChange={setBook}
           />
         ) : null;
+      case "leases":
+        return (
+          <Leases
+            currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
+            onLeasesChange={loadData}
+            onOpenAsset={handleOpenAsset}
+          />
+        );
       case "reports":
         return (
           <Reports
             annualSummary={annualSummary}
             currentYear={currentYear}
+            fiscalYearEnd={fiscalYearEnd}
+            book={book}
+            onBookChange={setBook}
             onExportReport={handleExportReport}
             onExportTemplate={handleExportTemplate}
           />
@@ -342,6 +433,7 @@ function App() {
             theme={theme}
             onThemeChange={handleThemeChange}
             onCategoriesChange={loadData}
+            onFiscalYearChange={loadData}
           />
         );
       default:
@@ -0,0 +1,290 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Pencil, Trash2 } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { books, bookLabel } from "@/components/BookSelect";
+import { formatCurrency } from "@/lib/utils";
+import type { AssetBook } from "@/types";
+
+const taxBooks = books.filter((b) => b.value !== "book");
+
+const propertyClasses = ["3", "5", "7", "10", "15", "20", "27.5", "39"];
+
+const depreciationMethods = [
+  { value: "straight_line", label: "Straight-Line" },
+  { value: "macrs_gds", label: "MACRS (GDS)" },
+  { value: "macrs_ads", label: "MACRS (ADS)" },
+  { value: "declining_200", label: "Double Declining Balance" },
+  { value: "declining_150", label: "150% Declining Balance" },
+  { value: "sum_of_years_digits", label: "Sum-of-the-Years' Digits" },
+  { value: "units_of_production", label: "Units of Production" },
+];
+
+const conventions = [
+  { value: "full_year", label: "Full Year" },
+  { value: "half_year", label: "Half-Year" },
+  { value: "mid_quarter", label: "Mid-Quarter" },
+  { value: "mid_month", label: "Mid-Month" },
+  { value: "full_month", label: "Full Month" },
+];
+
+const SAME = "same";
+
+interface AssetBooksProps {
+  assetId: number;
+  onChange: () => void;
+}
+
+export function AssetBooks({ assetId, onChange }: AssetBooksProps) {
+  const emptyBook = (book: string): AssetBook => ({ asset_id: assetId, book });
+
+  const [entries, setEntries] = useState<AssetBook[]>([]);
+  const [form, setForm] = useState<AssetBook>(emptyBook("federal"));
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadBooks = async () => {
+    try {
+      const data = await invoke<AssetBook[]>("get_asset_books", { assetId });
+      setEntries(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadBooks();
+    setForm(emptyBook("federal"));
+  }, [assetId]);
+
+  const update = <K extends keyof AssetBook>(field: K, value: AssetBook[K]) => {
+    setForm((prev) => ({ ...prev, [field]: value }));
+  };
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      await invoke("save_asset_book", { book: form });
+      setError(null);
+      await loadBooks();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (book: string) => {
+    try {
+      await invoke("delete_asset_book", { assetId, book });
+      setError(null);
+      await loadBooks();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const methodLabel = (method?: string) =>
+    depreciationMethods.find((m) => m.value === method)?.label ?? "—";
+
+  const conventionLabel = (convention?: string) =>
+    conventions.find((c) => c.value === convention)?.label ?? "—";
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Tax Books</CardTitle>
+        <CardDescription>
+          Settings that differ from the asset in each tax book. Blank fields follow the asset.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="grid gap-4 sm:grid-cols-4">
+          <div className="space-y-2">
+            <Label htmlFor="book_name">Book</Label>
+            <Select
+              value={form.book}
+              onValueChange={(value) =>
+                setForm(entries.find((e) => e.book === value) ?? emptyBook(value))
+              }
+            >
+              <SelectTrigger id="book_name">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {taxBooks.map((b) => (
+                  <SelectItem key={b.value} value={b.value}>
+                    {b.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_method">Method</Label>
+            <Select
+              value={form.method || SAME}
+              onValueChange={(value) => update("method", value === SAME ? undefined : value)}
+            >
+              <SelectTrigger id="book_method">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={SAME}>Same as Asset</SelectItem>
+                {depreciationMethods.map((m) => (
+                  <SelectItem key={m.value} value={m.value}>
+                    {m.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_convention">Convention</Label>
+            <Select
+              value={form.convention || SAME}
+              onValueChange={(value) => update("convention", value === SAME ? undefined : value)}
+            >
+              <SelectTrigger id="book_convention">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={SAME}>Same as Asset</SelectItem>
+                {conventions.map((c) => (
+                  <SelectItem key={c.value} value={c.value}>
+                    {c.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_class">Property Class</Label>
+            <Select
+              value={form.property_class || SAME}
+              onValueChange={(value) =>
+                update("property_class", value === SAME ? undefined : value)
+              }
+            >
+              <SelectTrigger id="book_class">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={SAME}>Same as Asset</SelectItem>
+                {propertyClasses.map((pc) => (
+                  <SelectItem key={pc} value={pc}>
+                    {pc}-year
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_life">Useful Life (Years)</Label>
+            <NumberInput
+              id="book_life"
+              step={1}
+              min={1}
+              allowEmpty
+              value={form.useful_life_years ?? ""}
+              onChange={(value) => update("useful_life_years", value)}
+              placeholder="Same"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_179">Section 179</Label>
+            <NumberInput
+              id="book_179"
+              step={100}
+              min={0}
+              prefix="$"
+              allowEmpty
+              value={form.section_179_amount ?? ""}
+              onChange={(value) => update("section_179_amount", value)}
+              placeholder="Same"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="book_bonus">Bonus %</Label>
+            <NumberInput
+              id="book_bonus"
+              step={1}
+              min={0}
+              max={100}
+              allowEmpty
+              value={form.bonus_percentage ?? ""}
+              onChange={(value) => update("bonus_percentage", value)}
+              placeholder="Same"
+            />
+          </div>
+          <div className="flex items-end">
+            <Button onClick={handleSave} disabled={saving}>
+              {saving ? "Saving..." : `Save ${bookLabel(form.book)}`}
+            </Button>
+          </div>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {entries.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Book</TableHead>
+                <TableHead>Method</TableHead>
+                <TableHead>Convention</TableHead>
+                <TableHead className="text-right">Life</TableHead>
+                <TableHead className="text-right">Section 179</TableHead>
+                <TableHead className="text-right">Bonus %</TableHead>
+                <TableHead></TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {entries.map((entry) => (
+                <TableRow key={entry.book}>
+                  <TableCell className="font-medium">{bookLabel(entry.book)}</TableCell>
+                  <TableCell>{methodLabel(entry.method)}</TableCell>
+                  <TableCell>{conventionLabel(entry.convention)}</TableCell>
+                  <TableCell className="text-right">{entry.useful_life_years ?? "—"}</TableCell>
+                  <TableCell className="text-right font-mono">
+                    {entry.section_179_amount !== undefined && entry.section_179_amount !== null
+                      ? formatCurrency(entry.section_179_amount)
+                      : "—"}
+                  </TableCell>
+                  <TableCell className="text-right">{entry.bonus_percentage ?? "—"}</TableCell>
+                  <TableCell className="text-right">
+                    <Button variant="ghost" size="sm" onClick={() => setForm(entry)}>
+                      <Pencil className="h-4 w-4" />
+                    </Button>
+                    <Button variant="ghost" size="sm" onClick={() => handleDelete(entry.book)}>
+                      <Trash2 className="h-4 w-4" />
+                    </Button>
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,286 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Pencil, Trash2 } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency } from "@/lib/utils";
+import type { Asset, AssetComponent, ComponentWithSchedule } from "@/types";
+
+const componentMethods = [
+  { value: "straight_line", label: "Straight-Line" },
+  { value: "declining_200", label: "Double Declining Balance" },
+  { value: "declining_150", label: "150% Declining Balance" },
+  { value: "sum_of_years_digits", label: "Sum-of-the-Years' Digits" },
+];
+
+const conventions = [
+  { value: "full_year", label: "Full Year" },
+  { value: "half_year", label: "Half-Year" },
+  { value: "mid_quarter", label: "Mid-Quarter" },
+  { value: "mid_month", label: "Mid-Month" },
+  { value: "full_month", label: "Full Month" },
+];
+
+const SAME = "same";
+
+interface AssetComponentsProps {
+  asset: Asset;
+  currentYear: number;
+  onChange: () => void;
+}
+
+export function AssetComponents({ asset, currentYear, onChange }: AssetComponentsProps) {
+  const emptyComponent = (): AssetComponent => ({
+    asset_id: asset.id!,
+    name: "",
+    cost: 0,
+    salvage_value: 0,
+    useful_life_years: asset.useful_life_years,
+    method: "straight_line",
+  });
+
+  const [parts, setParts] = useState<ComponentWithSchedule[]>([]);
+  const [form, setForm] = useState<AssetComponent>(emptyComponent());
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadComponents = async () => {
+    try {
+      const data = await invoke<ComponentWithSchedule[]>("get_asset_components", {
+        assetId: asset.id,
+      });
+      setParts(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadComponents();
+  }, [asset]);
+
+  useEffect(() => {
+    setForm(emptyComponent());
+  }, [asset.id]);
+
+  const update = <K extends keyof AssetComponent>(field: K, value: AssetComponent[K]) => {
+    setForm((prev) => ({ ...prev, [field]: value }));
+  };
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      await invoke("save_asset_component", { component: form });
+      setForm(emptyComponent());
+      setError(null);
+      await loadComponents();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await invoke("delete_asset_component", { id });
+      setError(null);
+      await loadComponents();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const methodLabel = (method?: string) =>
+    componentMethods.find((m) => m.value === method)?.label ?? "Straight-Line";
+
+  const yearEntry = (part: ComponentWithSchedule) =>
+    [...part.schedule].reverse().find((entry) => entry.year <= currentYear);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Components</CardTitle>
+        <CardDescription>
+          Significant parts depreciated separately in the financial book, each over its own
+          life. The rest of the cost follows the asset's settings.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="grid gap-4 sm:grid-cols-4">
+          <div className="space-y-2 sm:col-span-2">
+            <Label htmlFor="component_name">Name</Label>
+            <Input
+              id="component_name"
+              value={form.name}
+              onChange={(e) => update("name", e.target.value)}
+              placeholder="e.g., Roof"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="component_cost">Cost</Label>
+            <NumberInput
+              id="component_cost"
+              step={0.01}
+              min={0}
+              prefix="$"
+              value={form.cost}
+              onChange={(value) => update("cost", value ?? 0)}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="component_salvage">Salvage Value</Label>
+            <NumberInput
+              id="component_salvage"
+              step={0.01}
+              min={0}
+              prefix="$"
+              value={form.salvage_value}
+              onChange={(value) => update("salvage_value", value ?? 0)}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="component_life">Useful Life (Years)</Label>
+            <NumberInput
+              id="component_life"
+              step={1}
+              min={1}
+              value={form.useful_life_years}
+              onChange={(value) => update("useful_life_years", value ?? 1)}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="component_method">Method</Label>
+            <Select
+              value={form.method || "straight_line"}
+              onValueChange={(value) => update("method", value)}
+            >
+              <SelectTrigger id="component_method">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {componentMethods.map((m) => (
+                  <SelectItem key={m.value} value={m.value}>
+                    {m.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="component_convention">Convention</Label>
+            <Select
+              value={form.convention || SAME}
+              onValueChange={(value) => update("convention", value === SAME ? undefined : value)}
+            >
+              <SelectTrigger id="component_convention">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={SAME}>Same as Asset</SelectItem>
+                {conventions.map((c) => (
+                  <SelectItem key={c.value} value={c.value}>
+                    {c.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="flex items-end gap-2">
+            <Button onClick={handleSave} disabled={saving}>
+              {saving ? "Saving..." : form.id ? "Update Component" : "Add Component"}
+            </Button>
+            {form.id && (
+              <Button variant="outline" onClick={() => setForm(emptyComponent())}>
+                Cancel
+              </Button>
+            )}
+          </div>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {parts.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Component</TableHead>
+                <TableHead className="text-right">Cost</TableHead>
+                <TableHead className="text-right">Life</TableHead>
+                <TableHead>Method</TableHead>
+                <TableHead className="text-right">Depreciation {currentYear}</TableHead>
+                <TableHead className="text-right">Book Value</TableHead>
+                <TableHead></TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {parts.map((part) => {
+                const { component } = part;
+                const entry = yearEntry(part);
+                return (
+                  <TableRow key={component.id ?? "rest"}>
+                    <TableCell className="font-medium">
+                      {component.id ? (
+                        component.name
+                      ) : (
+                        <span className="text-muted-foreground">Rest of asset</span>
+                      )}
+                    </TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(component.cost)}
+                    </TableCell>
+                    <TableCell className="text-right">{component.useful_life_years} years</TableCell>
+                    <TableCell>{component.id ? methodLabel(component.method) : "As asset"}</TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(
+                        entry?.year === currentYear ? entry.depreciation_expense : 0
+                      )}
+                    </TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(entry?.ending_book_value ?? component.cost)}
+                    </TableCell>
+                    <TableCell className="text-right">
+                      {component.id && (
+                        <>
+                          <Button variant="ghost" size="sm" onClick={() => setForm(component)}>
+                            <Pencil className="h-4 w-4" />
+                          </Button>
+                          <Button
+                            variant="ghost"
+                            size="sm"
+                            onClick={() => handleDelete(component.id!)}
+                          >
+                            <Trash2 className="h-4 w-4" />
+                          </Button>
+                        </>
+                      )}
+                    </TableCell>
+                  </TableRow>
+                );
+              })}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -46,6 +46,31 @@ const propertyClasses = [
   { value: "39", label: "39-year" },
 ];
 
+const depreciationMethods = [
+  { value: "straight_line", label: "Straight-Line" },
+  { value: "macrs_gds", label: "MACRS (GDS)" },
+  { value: "macrs_ads", label: "MACRS (ADS)" },
+  { value: "declining_200", label: "Double Declining Balance" },
+  { value: "declining_150", label: "150% Declining Balance" },
+  { value: "sum_of_years_digits", label: "Sum-of-the-Years' Digits" },
+  { value: "units_of_production", label: "Units of Production" },
+];
+
+const conventions = [
+  { value: "full_year", label: "Full Year" },
+  { value: "half_year", label: "Half-Year" },
+  { value: "mid_quarter", label: "Mid-Quarter" },
+  { value: "mid_month", label: "Mid-Month" },
+  { value: "full_month", label: "Full Month" },
+];
+
+export const proceedsTypes = [
+  { value: "sale", label: "Sale" },
+  { value: "trade_in", label: "Trade-In" },
+  { value: "scrap", label: "Scrap" },
+  { value: "casualty", label: "Casualty / Theft" },
+];
+
 export function AssetFormDialog({
   open,
   onOpenChange,
@@ -99,11 +124,15 @@ export function AssetFormDialog({
     <Dialog open={open} onOpenChange={onOpenChange}>
       <DialogContent className="max-w-2xl max-h-[90vh] overflow-y-auto">
         <DialogHeader>
-          <DialogTitle>{isEditing ? "Edit Asset" : "Add Asset"}</DialogTitle>
+          <DialogTitle>
+            {isEditing ? "Edit Asset" : asset.improved_asset_id ? "Add Improvement" : "Add Asset"}
+          </DialogTitle>
           <DialogDescription>
             {isEditing
               ? "Update the asset details below."
-              : "Enter the details for your new asset."}
+              : asset.improved_asset_id
+                ? "Enter the cost, date and life of the improvement. It is depreciated on its own and rolled up into the asset."
+                : "Enter the details for your new asset."}
           </DialogDescription>
         </DialogHeader>
 
@@ -185,6 +214,74 @@ export function AssetFormDialog({
               </div>
             </div>
 
+            {/* Depreciation Method & Convention */}
+            <div className="grid gap-4 sm:grid-cols-2">
+              <div className="space-y-2">
+                <Label htmlFor="method">Depreciation Method</Label>
+                <Select
+                  value={asset.method || "default"}
+                  onValueChange={(value) =>
+                    handleChange("method", value === "default" ? undefined : value)
+                  }
+                >
+                  <SelectTrigger className={inputErrorClass("method")}>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="default">Category Default</SelectItem>
+                    {depreciationMethods.map((m) => (
+                      <SelectItem key={m.value} value={m.value}>
+                        {m.label}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+                <FieldError error={errors.method} />
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="convention">Convention</Label>
+                <Select
+                  value={asset.convention || "full_year"}
+                  onValueChange={(value) => handleChange("convention", value)}
+                >
+                  <SelectTrigger className={inputErrorClass("convention")}>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {conventions.map((c) => (
+                      <SelectItem key={c.value} value={c.value}>
+                        {c.label}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+                <FieldError error={errors.convention} />
+              </div>
+            </div>
+
+            {asset.method === "units_of_production" && (
+              <div className="space-y-2">
+                <Label htmlFor="total_units">
+                  Total Expected Units <span className="text-destructive">*</span>
+                </Label>
+                <NumberInput
+                  id="total_units"
+                  step={1}
+                  min={0}
+                  allowEmpty
+                  value={asset.total_units ?? ""}
+                  onChange={(value) => handleChange("total_units", value)}
+                  placeholder="e.g., 10000"
+                  className={inputErrorClass("total_units")}
+                />
+                <FieldError error={errors.total_units} />
+                <p className="text-xs text-muted-foreground">
+                  Hours or units the asset is expected to produce over its life
+                </p>
+              </div>
+            )}
+
             {/* Financial Info */}
             <div className="grid gap-4 sm:grid-cols-3">
               <div className="space-y-2">
@@ -231,6 +328,121 @@ export function AssetFormDialog({
               </div>
             </div>
 
+            {/* Tax Elections */}
+            <div className="grid gap-4 sm:grid-cols-3">
+              <div className="space-y-2">
+                <Label htmlFor="section_179">Section 179 Amount</Label>
+                <NumberInput
+                  id="section_179"
+                  step={0.01}
+                  min={0}
+                  allowEmpty
+                  value={asset.section_179_amount ?? ""}
+                  onChange={(value) => handleChange("section_179_amount", value)}
+                  className={inputErrorClass("section_179_amount")}
+                />
+                <FieldError error={errors.section_179_amount} />
+                <p className="text-xs text-muted-foreground">
+                  Expensed in the tax books; the financial book depreciates the full cost
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="bonus_percentage">Bonus Depreciation %</Label>
+                <NumberInput
+                  id="bonus_percentage"
+                  step={1}
+                  min={0}
+                  max={100}
+                  allowEmpty
+                  value={asset.bonus_percentage ?? ""}
+                  onChange={(value) => handleChange("bonus_percentage", value)}
+                  placeholder="Default"
+                  className={inputErrorClass("bonus_percentage")}
+                />
+                <FieldError error={errors.bonus_percentage} />
+                <p className="text-xs text-muted-foreground">
+                  Blank uses the phase-down rate for MACRS property
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="business_use_percentage">Business Use %</Label>
+                <NumberInput
+                  id="business_use_percentage"
+                  step={1}
+                  min={0}
+                  max={100}
+                  allowEmpty
+                  value={asset.business_use_percentage ?? ""}
+                  onChange={(value) => handleChange("business_use_percentage", value)}
+                  placeholder="100"
+                  className={inputErrorClass("business_use_percentage")}
+                />
+                <FieldError error={errors.business_use_percentage} />
+                <p className="text-xs text-muted-foreground">
+                  Listed property at 50% or less must use MACRS (ADS)
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="passenger_auto">Vehicle</Label>
+                <Select
+                  value={asset.passenger_auto ? "passenger_auto" : "other"}
+                  onValueChange={(value) => handleChange("passenger_auto", value === "passenger_auto")}
+                >
+                  <SelectTrigger id="passenger_auto">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="other">Not a passenger automobile</SelectItem>
+                    <SelectItem value="passenger_auto">Passenger automobile</SelectItem>
+                  </SelectContent>
+                </Select>
+                <p className="text-xs text-muted-foreground">
+                  Passenger automobiles are held to the §280F limits under MACRS
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="asset_kind">Kind</Label>
+                <Select
+                  value={asset.asset_kind || "tangible"}
+                  onValueChange={(value) =>
+                    handleChange("asset_kind", value === "tangible" ? undefined : value)
+                  }
+                >
+                  <SelectTrigger id="asset_kind">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="tangible">Tangible property</SelectItem>
+                    <SelectItem value="intangible">Intangible (§197)</SelectItem>
+                  </SelectContent>
+                </Select>
+                <p className="text-xs text-muted-foreground">
+                  Intangibles are amortized straight-line from the month acquired, and over 15 years under §197 in the federal book
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="quantity">Quantity</Label>
+                <NumberInput
+                  id="quantity"
+                  step={1}
+                  min={1}
+                  allowEmpty
+                  value={asset.quantity ?? ""}
+                  onChange={(value) => handleChange("quantity", value)}
+                  className={inputErrorClass("quantity")}
+                />
+                <FieldError error={errors.quantity} />
+                <p className="text-xs text-muted-foreground">
+                  Identical units, for disposing of some of them later
+                </p>
+              </div>
+            </div>
+
             {/* Status */}
             <div className="space-y-4">
               <div className="space-y-2">
@@ -241,6 +453,8 @@ export function AssetFormDialog({
                     if (value === "active") {
                       handleChange("disposed_date", undefined);
                       handleChange("disposed_value", undefined);
+                      handleChange("selling_costs", undefined);
+                      handleChange("proceeds_type", undefined);
                     } else {
                       handleChange("disposed_date", new Date().toISOString().split("T")[0]);
                     }
@@ -293,6 +507,38 @@ export function AssetFormDialog({
                         Leave blank if scrapped with no value
                       </p>
                     </div>
+                    <div className="space-y-2">
+                      <Label htmlFor="selling_costs">Selling Costs</Label>
+                      <NumberInput
+                        id="selling_costs"
+                        step={0.01}
+                        min={0}
+                        allowEmpty
+                        value={asset.selling_costs ?? ""}
+                        onChange={(value) => handleChange("selling_costs", value)}
+                        placeholder="0.00"
+                        className={inputErrorClass("selling_costs")}
+                      />
+                      <FieldError error={errors.selling_costs} />
+                    </div>
+                    <div className="space-y-2">
+                      <Label htmlFor="proceeds_type">Proceeds Type</Label>
+                      <Select
+                        value={asset.proceeds_type || "sale"}
+                        onValueChange={(value) => handleChange("proceeds_type", value)}
+                      >
+                        <SelectTrigger id="proceeds_type">
+                          <SelectValue />
+                        </SelectTrigger>
+                        <SelectContent>
+                          {proceedsTypes.map((p) => (
+                            <SelectItem key={p.value} value={p.value}>
+                              {p.label}
+                            </SelectItem>
+                          ))}
+                        </SelectContent>
+                      </Select>
+                    </div>
                   </div>
                 </div>
               )}
@@ -0,0 +1,40 @@
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+
+export const books = [
+  { value: "book", label: "Book" },
+  { value: "federal", label: "Federal Tax" },
+  { value: "state", label: "State Tax" },
+  { value: "amt", label: "AMT" },
+];
+
+export function bookLabel(book: string): string {
+  return books.find((b) => b.value === book)?.label ?? book;
+}
+
+interface BookSelectProps {
+  value: string;
+  onChange: (book: string) => void;
+}
+
+export function BookSelect({ value, onChange }: BookSelectProps) {
+  return (
+    <Select value={value} onValueChange={onChange}>
+      <SelectTrigger className="w-40">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        {books.map((b) => (
+          <SelectItem key={b.value} value={b.value}>
+            {b.label}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+}
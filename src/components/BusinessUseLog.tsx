@@ -0,0 +1,166 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Plus, Trash2 } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatFiscalYear } from "@/lib/utils";
+import type { BusinessUseEntry } from "@/types";
+
+interface BusinessUseLogProps {
+  assetId: number;
+  currentYear: number;
+  fiscalYearEnd: number;
+  onChange: () => void;
+}
+
+export function BusinessUseLog({
+  assetId,
+  currentYear,
+  fiscalYearEnd,
+  onChange,
+}: BusinessUseLogProps) {
+  const [entries, setEntries] = useState<BusinessUseEntry[]>([]);
+  const [year, setYear] = useState<number | undefined>(currentYear);
+  const [percentage, setPercentage] = useState<number | undefined>(undefined);
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadEntries = async () => {
+    try {
+      const data = await invoke<BusinessUseEntry[]>("get_business_use", { assetId });
+      setEntries(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadEntries();
+  }, [assetId]);
+
+  const handleRecord = async () => {
+    if (year === undefined || percentage === undefined) {
+      setError("Year and business use are required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("record_business_use", {
+        entry: { asset_id: assetId, year, percentage },
+      });
+      setPercentage(undefined);
+      setError(null);
+      await loadEntries();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await invoke("delete_business_use", { id });
+      setError(null);
+      await loadEntries();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Business Use</CardTitle>
+        <CardDescription>
+          Business use of this listed property in each year after it was placed in service, which
+          each year is depreciated at. A year at 50% or less under MACRS (GDS) recaptures the
+          depreciation taken over ADS and switches to ADS from that year.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="business_use_year">Year</Label>
+            <NumberInput
+              id="business_use_year"
+              step={1}
+              allowEmpty
+              value={year ?? ""}
+              onChange={setYear}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="business_use_percentage_entry">Business Use %</Label>
+            <NumberInput
+              id="business_use_percentage_entry"
+              step={1}
+              min={0}
+              max={100}
+              allowEmpty
+              value={percentage ?? ""}
+              onChange={setPercentage}
+              placeholder="e.g., 80"
+            />
+          </div>
+          <Button onClick={handleRecord} disabled={saving} className="gap-2">
+            <Plus className="h-4 w-4" />
+            {saving ? "Saving..." : "Record Business Use"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {entries.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Year</TableHead>
+                <TableHead className="text-right">Business Use</TableHead>
+                <TableHead></TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {entries.map((entry) => (
+                <TableRow key={entry.year}>
+                  <TableCell className="font-medium">
+                    {formatFiscalYear(entry.year, fiscalYearEnd)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {entry.percentage <= 50 && (
+                      <Badge variant="outline" className="mr-2">
+                        50% or less
+                      </Badge>
+                    )}
+                    {entry.percentage}%
+                  </TableCell>
+                  <TableCell className="text-right">
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => entry.id && handleDelete(entry.id)}
+                    >
+                      <Trash2 className="h-4 w-4" />
+                    </Button>
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,175 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { History } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { DatePicker } from "@/components/ui/date-picker";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatDate } from "@/lib/utils";
+import type { Asset, EstimateChange } from "@/types";
+
+interface EstimateChangesProps {
+  asset: Asset;
+  onChange: () => void;
+}
+
+export function EstimateChanges({ asset, onChange }: EstimateChangesProps) {
+  const [changes, setChanges] = useState<EstimateChange[]>([]);
+  const [effectiveDate, setEffectiveDate] = useState(new Date().toISOString().split("T")[0]);
+  const [usefulLife, setUsefulLife] = useState<number | undefined>(asset.useful_life_years);
+  const [salvage, setSalvage] = useState<number | undefined>(asset.salvage_value);
+  const [reason, setReason] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadChanges = async () => {
+    try {
+      const data = await invoke<EstimateChange[]>("get_estimate_changes", {
+        assetId: asset.id,
+      });
+      setChanges(data);
+      // Start the next revision from the estimate now in effect
+      const latest = data[data.length - 1];
+      setUsefulLife(latest?.useful_life_years ?? asset.useful_life_years);
+      setSalvage(latest?.salvage_value ?? asset.salvage_value);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadChanges();
+  }, [asset.id, asset.useful_life_years, asset.salvage_value]);
+
+  const handleRevise = async () => {
+    if (usefulLife === undefined || salvage === undefined) {
+      setError("Useful life and salvage value are required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("revise_estimate", {
+        change: {
+          asset_id: asset.id,
+          effective_date: effectiveDate,
+          useful_life_years: usefulLife,
+          salvage_value: salvage,
+          reason: reason || undefined,
+        },
+      });
+      setReason("");
+      setError(null);
+      await loadChanges();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Changes in Estimate</CardTitle>
+        <CardDescription>
+          Revise the useful life or salvage value from a date on. Earlier years stay as
+          reported and the remaining book value is spread over the rest of the new life.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="estimate_date">Effective Date</Label>
+            <DatePicker
+              id="estimate_date"
+              value={effectiveDate}
+              onChange={setEffectiveDate}
+              placeholder="Select date"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="estimate_life">Useful Life (Years)</Label>
+            <NumberInput
+              id="estimate_life"
+              step={1}
+              min={1}
+              allowEmpty
+              value={usefulLife ?? ""}
+              onChange={setUsefulLife}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="estimate_salvage">Salvage Value</Label>
+            <NumberInput
+              id="estimate_salvage"
+              step={0.01}
+              min={0}
+              allowEmpty
+              value={salvage ?? ""}
+              onChange={setSalvage}
+            />
+          </div>
+          <div className="space-y-2 flex-1 min-w-[200px]">
+            <Label htmlFor="estimate_reason">Reason</Label>
+            <Input
+              id="estimate_reason"
+              value={reason}
+              onChange={(e) => setReason(e.target.value)}
+              placeholder="e.g., Overhaul extended expected use"
+            />
+          </div>
+          <Button onClick={handleRevise} disabled={saving} className="gap-2">
+            <History className="h-4 w-4" />
+            {saving ? "Saving..." : "Revise Estimate"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {changes.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Effective</TableHead>
+                <TableHead className="text-right">Useful Life</TableHead>
+                <TableHead className="text-right">Salvage Value</TableHead>
+                <TableHead>Reason</TableHead>
+                <TableHead>Recorded</TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {changes.map((change) => (
+                <TableRow key={change.id}>
+                  <TableCell className="font-medium">
+                    {formatDate(change.effective_date)}
+                  </TableCell>
+                  <TableCell className="text-right">
+                    {change.previous_useful_life_years} → {change.useful_life_years} years
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {formatCurrency(change.previous_salvage_value ?? 0)} →{" "}
+                    {formatCurrency(change.salvage_value)}
+                  </TableCell>
+                  <TableCell className="text-muted-foreground">{change.reason || "—"}</TableCell>
+                  <TableCell className="text-muted-foreground">
+                    {change.created_at && formatDate(change.created_at.split(" ")[0])}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,155 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { TrendingDown } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { DatePicker } from "@/components/ui/date-picker";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatDate } from "@/lib/utils";
+import type { Asset, Impairment } from "@/types";
+
+interface ImpairmentsProps {
+  asset: Asset;
+  onChange: () => void;
+}
+
+export function Impairments({ asset, onChange }: ImpairmentsProps) {
+  const [impairments, setImpairments] = useState<Impairment[]>([]);
+  const [impairmentDate, setImpairmentDate] = useState(new Date().toISOString().split("T")[0]);
+  const [amount, setAmount] = useState<number | undefined>(undefined);
+  const [reason, setReason] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadImpairments = async () => {
+    try {
+      const data = await invoke<Impairment[]>("get_impairments", { assetId: asset.id });
+      setImpairments(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadImpairments();
+  }, [asset.id, asset.cost]);
+
+  const handleRecord = async () => {
+    if (amount === undefined || amount <= 0) {
+      setError("Impairment loss is required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("record_impairment", {
+        impairment: {
+          asset_id: asset.id,
+          impairment_date: impairmentDate,
+          amount,
+          reason: reason || undefined,
+        },
+      });
+      setAmount(undefined);
+      setReason("");
+      setError(null);
+      await loadImpairments();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Impairments</CardTitle>
+        <CardDescription>
+          Write the carrying amount down when the asset is worth less than its book value. The
+          loss is reported apart from depreciation, and later years depreciate what is left.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="impairment_date">Date</Label>
+            <DatePicker
+              id="impairment_date"
+              value={impairmentDate}
+              onChange={setImpairmentDate}
+              placeholder="Select date"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="impairment_amount">Impairment Loss</Label>
+            <NumberInput
+              id="impairment_amount"
+              step={0.01}
+              min={0}
+              prefix="$"
+              allowEmpty
+              value={amount ?? ""}
+              onChange={setAmount}
+            />
+          </div>
+          <div className="space-y-2 flex-1 min-w-[200px]">
+            <Label htmlFor="impairment_reason">Reason</Label>
+            <Input
+              id="impairment_reason"
+              value={reason}
+              onChange={(e) => setReason(e.target.value)}
+              placeholder="e.g., Damaged in storm"
+            />
+          </div>
+          <Button onClick={handleRecord} disabled={saving} className="gap-2">
+            <TrendingDown className="h-4 w-4" />
+            {saving ? "Saving..." : "Record Impairment"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {impairments.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Date</TableHead>
+                <TableHead className="text-right">Loss</TableHead>
+                <TableHead>Reason</TableHead>
+                <TableHead>Recorded</TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {impairments.map((impairment) => (
+                <TableRow key={impairment.id}>
+                  <TableCell className="font-medium">
+                    {formatDate(impairment.impairment_date)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {formatCurrency(impairment.amount)}
+                  </TableCell>
+                  <TableCell className="text-muted-foreground">
+                    {impairment.reason || "—"}
+                  </TableCell>
+                  <TableCell className="text-muted-foreground">
+                    {impairment.created_at && formatDate(impairment.created_at.split(" ")[0])}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,231 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Scissors } from "lucide-react";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { DatePicker } from "@/components/ui/date-picker";
+import { FieldError } from "@/components/ui/field-error";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { proceedsTypes } from "@/components/AssetFormDialog";
+import { validateDispose } from "@/lib/validation";
+import type { Asset, PartialDisposal } from "@/types";
+
+interface PartialDisposeDialogProps {
+  open: boolean;
+  asset: Asset;
+  onClose: () => void;
+  onDisposed: () => void;
+}
+
+const today = () => new Date().toISOString().split("T")[0];
+
+export function PartialDisposeDialog({
+  open,
+  asset,
+  onClose,
+  onDisposed,
+}: PartialDisposeDialogProps) {
+  const [by, setBy] = useState<"quantity" | "percentage">("percentage");
+  const [amount, setAmount] = useState<number | undefined>(undefined);
+  const [disposedDate, setDisposedDate] = useState(today());
+  const [disposedValue, setDisposedValue] = useState<number | undefined>(undefined);
+  const [sellingCosts, setSellingCosts] = useState<number | undefined>(undefined);
+  const [proceedsType, setProceedsType] = useState("sale");
+  const [errors, setErrors] = useState<Record<string, string>>({});
+  const [saving, setSaving] = useState(false);
+
+  // Start from a clean form each time the dialog opens
+  useEffect(() => {
+    if (open) {
+      setBy(asset.quantity ? "quantity" : "percentage");
+      setAmount(undefined);
+      setDisposedDate(today());
+      setDisposedValue(undefined);
+      setSellingCosts(undefined);
+      setProceedsType("sale");
+      setErrors({});
+    }
+  }, [open, asset.quantity]);
+
+  const handleConfirm = async () => {
+    const result = validateDispose(disposedDate, disposedValue, asset.date_placed_in_service);
+    const nextErrors = result.success ? {} : { ...result.errors };
+    if (amount === undefined || amount <= 0) {
+      nextErrors.amount = by === "quantity" ? "Quantity is required" : "Percentage is required";
+    } else if (by === "quantity" && amount >= (asset.quantity ?? 0)) {
+      nextErrors.amount = `Quantity must be less than ${asset.quantity}`;
+    } else if (by === "percentage" && amount >= 100) {
+      nextErrors.amount = "Percentage must be less than 100";
+    }
+    if (Object.keys(nextErrors).length > 0) {
+      setErrors(nextErrors);
+      return;
+    }
+
+    const disposal: PartialDisposal = {
+      asset_id: asset.id!,
+      disposed_date: disposedDate,
+      quantity: by === "quantity" ? amount : undefined,
+      percentage: by === "percentage" ? amount : undefined,
+      disposed_value: disposedValue,
+      selling_costs: sellingCosts,
+      proceeds_type: proceedsType,
+    };
+    setSaving(true);
+    try {
+      await invoke("partial_dispose_asset", { disposal });
+      onDisposed();
+      onClose();
+    } catch (err) {
+      setErrors({ form: String(err) });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const inputErrorClass = (field: string) =>
+    errors[field] ? "border-destructive focus-visible:ring-destructive" : "";
+
+  return (
+    <Dialog open={open} onOpenChange={(isOpen) => !isOpen && onClose()}>
+      <DialogContent>
+        <DialogHeader>
+          <div className="flex items-center gap-3">
+            <div className="flex h-10 w-10 items-center justify-center rounded-full bg-warning/10">
+              <Scissors className="h-5 w-5 text-warning" />
+            </div>
+            <div>
+              <DialogTitle>Dispose of Part</DialogTitle>
+              <DialogDescription>
+                Split the disposed part off "{asset.name}" with its share of the cost
+              </DialogDescription>
+            </div>
+          </div>
+        </DialogHeader>
+
+        <div className="space-y-4 py-4">
+          <div className="grid gap-4 sm:grid-cols-2">
+            <div className="space-y-2">
+              <Label htmlFor="partial-by">Dispose Of</Label>
+              <Select
+                value={by}
+                onValueChange={(value) => setBy(value as "quantity" | "percentage")}
+              >
+                <SelectTrigger id="partial-by">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {asset.quantity && (
+                    <SelectItem value="quantity">Quantity of {asset.quantity}</SelectItem>
+                  )}
+                  <SelectItem value="percentage">Percentage</SelectItem>
+                </SelectContent>
+              </Select>
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="partial-amount">
+                {by === "quantity" ? "Quantity" : "Percentage"}{" "}
+                <span className="text-destructive">*</span>
+              </Label>
+              <NumberInput
+                id="partial-amount"
+                step={by === "quantity" ? 1 : 0.01}
+                min={0}
+                allowEmpty
+                value={amount ?? ""}
+                onChange={setAmount}
+                className={inputErrorClass("amount")}
+              />
+              <FieldError error={errors.amount} />
+            </div>
+          </div>
+
+          <div className="space-y-2">
+            <Label htmlFor="partial-date">
+              Disposal Date <span className="text-destructive">*</span>
+            </Label>
+            <DatePicker
+              id="partial-date"
+              value={disposedDate}
+              onChange={setDisposedDate}
+              placeholder="Select disposal date"
+              className={inputErrorClass("disposed_date")}
+            />
+            <FieldError error={errors.disposed_date} />
+          </div>
+
+          <div className="grid gap-4 sm:grid-cols-3">
+            <div className="space-y-2">
+              <Label htmlFor="partial-value">Proceeds</Label>
+              <NumberInput
+                id="partial-value"
+                step={0.01}
+                min={0}
+                allowEmpty
+                value={disposedValue ?? ""}
+                onChange={setDisposedValue}
+                className={inputErrorClass("disposed_value")}
+              />
+              <FieldError error={errors.disposed_value} />
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="partial-costs">Selling Costs</Label>
+              <NumberInput
+                id="partial-costs"
+                step={0.01}
+                min={0}
+                allowEmpty
+                value={sellingCosts ?? ""}
+                onChange={setSellingCosts}
+              />
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="partial-type">Proceeds Type</Label>
+              <Select value={proceedsType} onValueChange={setProceedsType}>
+                <SelectTrigger id="partial-type">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {proceedsTypes.map((type) => (
+                    <SelectItem key={type.value} value={type.value}>
+                      {type.label}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          </div>
+
+          {errors.form && <p className="text-sm text-destructive">{errors.form}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={onClose}>
+            Cancel
+          </Button>
+          <Button onClick={handleConfirm} disabled={saving}>
+            {saving ? "Saving..." : "Dispose of Part"}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
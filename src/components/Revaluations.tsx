@@ -0,0 +1,167 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Scale } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { DatePicker } from "@/components/ui/date-picker";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatDate } from "@/lib/utils";
+import type { Asset, Revaluation } from "@/types";
+
+interface RevaluationsProps {
+  asset: Asset;
+  onChange: () => void;
+}
+
+export function Revaluations({ asset, onChange }: RevaluationsProps) {
+  const [revaluations, setRevaluations] = useState<Revaluation[]>([]);
+  const [revaluationDate, setRevaluationDate] = useState(new Date().toISOString().split("T")[0]);
+  const [fairValue, setFairValue] = useState<number | undefined>(undefined);
+  const [reason, setReason] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadRevaluations = async () => {
+    try {
+      const data = await invoke<Revaluation[]>("get_revaluations", { assetId: asset.id });
+      setRevaluations(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadRevaluations();
+  }, [asset.id, asset.cost]);
+
+  const handleRecord = async () => {
+    if (fairValue === undefined || fairValue < 0) {
+      setError("Fair value is required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("record_revaluation", {
+        revaluation: {
+          asset_id: asset.id,
+          revaluation_date: revaluationDate,
+          fair_value: fairValue,
+          reason: reason || undefined,
+        },
+      });
+      setFairValue(undefined);
+      setReason("");
+      setError(null);
+      await loadRevaluations();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Revaluations</CardTitle>
+        <CardDescription>
+          Restate the carrying amount to fair value at the end of the fiscal year. Increases go to
+          the revaluation reserve and decreases come out of it first; later years depreciate the
+          revalued amount.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="revaluation_date">Date</Label>
+            <DatePicker
+              id="revaluation_date"
+              value={revaluationDate}
+              onChange={setRevaluationDate}
+              placeholder="Select date"
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="revaluation_fair_value">Fair Value</Label>
+            <NumberInput
+              id="revaluation_fair_value"
+              step={0.01}
+              min={0}
+              prefix="$"
+              allowEmpty
+              value={fairValue ?? ""}
+              onChange={setFairValue}
+            />
+          </div>
+          <div className="space-y-2 flex-1 min-w-[200px]">
+            <Label htmlFor="revaluation_reason">Reason</Label>
+            <Input
+              id="revaluation_reason"
+              value={reason}
+              onChange={(e) => setReason(e.target.value)}
+              placeholder="e.g., Independent valuation"
+            />
+          </div>
+          <Button onClick={handleRecord} disabled={saving} className="gap-2">
+            <Scale className="h-4 w-4" />
+            {saving ? "Saving..." : "Record Revaluation"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {revaluations.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Date</TableHead>
+                <TableHead className="text-right">Carrying Amount</TableHead>
+                <TableHead className="text-right">Fair Value</TableHead>
+                <TableHead className="text-right">Reserve</TableHead>
+                <TableHead className="text-right">Profit or Loss</TableHead>
+                <TableHead>Reason</TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {revaluations.map((revaluation) => (
+                <TableRow key={revaluation.id}>
+                  <TableCell className="font-medium">
+                    {formatDate(revaluation.revaluation_date)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {revaluation.carrying_amount !== undefined &&
+                    revaluation.carrying_amount !== null
+                      ? formatCurrency(revaluation.carrying_amount)
+                      : "—"}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {formatCurrency(revaluation.fair_value)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {formatCurrency(revaluation.reserve_change ?? 0)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {formatCurrency(revaluation.profit_loss ?? 0)}
+                  </TableCell>
+                  <TableCell className="text-muted-foreground">
+                    {revaluation.reason || "—"}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
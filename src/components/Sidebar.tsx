@@ -1,9 +1,9 @@
-import { LayoutDashboard, Package, FileText, Upload, Download, Plus, Settings, Sun, Moon, Monitor, BookOpen, BarChart3 } from "lucide-react";
+import { LayoutDashboard, Package, FileSignature, FileText, Upload, Download, Plus, Settings, Sun, Moon, Monitor, BookOpen, BarChart3 } from "lucide-react";
 import { cn } from "@/lib/utils";
 import { Button } from "@/components/ui/button";
 import { Separator } from "@/components/ui/separator";
 
-type View = "dashboard" | "assets" | "asset-detail" | "analysis" | "reports" | "manual" | "settings";
+type View = "dashboard" | "assets" | "asset-detail" | "leases" | "analysis" | "reports" | "manual" | "settings";
 type Theme = "light" | "dark" | "system";
 
 interface SidebarProps {
@@ -28,6 +28,7 @@ export function Sidebar({
   const navItems = [
     { id: "dashboard" as View, label: "Dashboard", icon: LayoutDashboard },
     { id: "assets" as View, label: "Assets", icon: Package },
+    { id: "leases" as View, label: "Leases", icon: FileSignature },
     { id: "analysis" as View, label: "Analysis", icon: BarChart3 },
     { id: "reports" as View, label: "Reports", icon: FileText },
     { id: "manual" as View, label: "Manual", icon: BookOpen },
@@ -0,0 +1,152 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Plus, Trash2 } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatFiscalYear } from "@/lib/utils";
+import type { UsageEntry } from "@/types";
+
+interface UsageLogProps {
+  assetId: number;
+  currentYear: number;
+  fiscalYearEnd: number;
+  onChange: () => void;
+}
+
+export function UsageLog({ assetId, currentYear, fiscalYearEnd, onChange }: UsageLogProps) {
+  const [entries, setEntries] = useState<UsageEntry[]>([]);
+  const [year, setYear] = useState<number | undefined>(currentYear);
+  const [units, setUnits] = useState<number | undefined>(undefined);
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadUsage = async () => {
+    try {
+      const data = await invoke<UsageEntry[]>("get_asset_usage", { assetId });
+      setEntries(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadUsage();
+  }, [assetId]);
+
+  const handleRecord = async () => {
+    if (year === undefined || units === undefined) {
+      setError("Year and units are required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("record_usage", {
+        usage: { asset_id: assetId, year, units },
+      });
+      setUnits(undefined);
+      setError(null);
+      await loadUsage();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await invoke("delete_usage", { id });
+      setError(null);
+      await loadUsage();
+      onChange();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Usage Log</CardTitle>
+        <CardDescription>
+          Units recorded per year. Future years are projected at the average usage so far.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="usage_year">Year</Label>
+            <NumberInput
+              id="usage_year"
+              step={1}
+              allowEmpty
+              value={year ?? ""}
+              onChange={setYear}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="usage_units">Units</Label>
+            <NumberInput
+              id="usage_units"
+              step={1}
+              min={0}
+              allowEmpty
+              value={units ?? ""}
+              onChange={setUnits}
+              placeholder="e.g., 1200"
+            />
+          </div>
+          <Button onClick={handleRecord} disabled={saving} className="gap-2">
+            <Plus className="h-4 w-4" />
+            {saving ? "Saving..." : "Record Usage"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {entries.length > 0 && (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Year</TableHead>
+                <TableHead className="text-right">Units</TableHead>
+                <TableHead></TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {entries.map((entry) => (
+                <TableRow key={entry.year}>
+                  <TableCell className="font-medium">
+                    {formatFiscalYear(entry.year, fiscalYearEnd)}
+                  </TableCell>
+                  <TableCell className="text-right font-mono">
+                    {entry.units.toLocaleString()}
+                  </TableCell>
+                  <TableCell className="text-right">
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => entry.id && handleDelete(entry.id)}
+                    >
+                      <Trash2 className="h-4 w-4" />
+                    </Button>
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -22,7 +22,7 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select";
-import { formatCurrency } from "@/lib/utils";
+import { formatCurrency, formatFiscalYear, rolledUpCost } from "@/lib/utils";
 import type { AssetWithSchedule, AnnualSummary } from "@/types";
 
 export type TimeRange = "all" | "next5" | "next10" | "1y";
@@ -79,6 +79,7 @@ interface CategoryData {
 
 interface BookValueData {
   year: number;
+  label: string;
   bookValue: number;
   accumulatedDepreciation: number;
 }
@@ -162,7 +163,7 @@ export function CategoryPieChart({ assets, onCategoryClick, compact }: CategoryP
       if (!categories[categoryName]) {
         categories[categoryName] = { name: categoryName, value: 0, count: 0 };
       }
-      categories[categoryName].value += item.asset.cost;
+      categories[categoryName].value += rolledUpCost(item);
       categories[categoryName].count += 1;
     });
 
@@ -255,17 +256,19 @@ export function CategoryPieChart({ assets, onCategoryClick, compact }: CategoryP
 interface DepreciationBarChartProps {
   annualSummary: AnnualSummary[];
   currentYear: number;
+  fiscalYearEnd: number;
   timeRange?: TimeRange;
   compact?: boolean;
 }
 
-export function DepreciationBarChart({ annualSummary, currentYear, timeRange = "all", compact }: DepreciationBarChartProps) {
+export function DepreciationBarChart({ annualSummary, currentYear, fiscalYearEnd, timeRange = "all", compact }: DepreciationBarChartProps) {
   const chartData = useMemo(() => {
     const { startYear, endYear } = getYearRange(timeRange, currentYear);
     return [...annualSummary]
       .filter((s) => s.year >= startYear && s.year <= endYear)
-      .sort((a, b) => a.year - b.year);
-  }, [annualSummary, timeRange, currentYear]);
+      .sort((a, b) => a.year - b.year)
+      .map((s) => ({ ...s, label: formatFiscalYear(s.year, fiscalYearEnd) }));
+  }, [annualSummary, timeRange, currentYear, fiscalYearEnd]);
 
   if (chartData.length === 0) {
     return null;
@@ -281,7 +284,7 @@ export function DepreciationBarChart({ annualSummary, currentYear, timeRange = "
           <BarChart data={chartData} margin={{ top: 5, right: 10, left: 0, bottom: 0 }}>
             <CartesianGrid strokeDasharray="3 3" className="stroke-border" vertical={false} />
             <XAxis
-              dataKey="year"
+              dataKey="label"
               tickLine={false}
               axisLine={false}
               className="text-xs fill-muted-foreground"
@@ -319,11 +322,12 @@ export function DepreciationBarChart({ annualSummary, currentYear, timeRange = "
 interface BookValueAreaChartProps {
   assets: AssetWithSchedule[];
   currentYear: number;
+  fiscalYearEnd: number;
   timeRange?: TimeRange;
   compact?: boolean;
 }
 
-export function BookValueAreaChart({ assets, currentYear, timeRange = "all", compact }: BookValueAreaChartProps) {
+export function BookValueAreaChart({ assets, currentYear, fiscalYearEnd, timeRange = "all", compact }: BookValueAreaChartProps) {
   const chartData = useMemo(() => {
     const { startYear, endYear } = getYearRange(timeRange, currentYear);
     const yearlyData: Record<number, BookValueData> = {};
@@ -334,6 +338,7 @@ export function BookValueAreaChart({ assets, currentYear, timeRange = "all", com
         if (!yearlyData[entry.year]) {
           yearlyData[entry.year] = {
             year: entry.year,
+            label: formatFiscalYear(entry.year, fiscalYearEnd),
             bookValue: 0,
             accumulatedDepreciation: 0,
           };
@@ -345,7 +350,7 @@ export function BookValueAreaChart({ assets, currentYear, timeRange = "all", com
 
     return Object.values(yearlyData)
       .sort((a, b) => a.year - b.year);
-  }, [assets, timeRange, currentYear]);
+  }, [assets, timeRange, currentYear, fiscalYearEnd]);
 
   if (chartData.length === 0) {
     return null;
@@ -371,7 +376,7 @@ export function BookValueAreaChart({ assets, currentYear, timeRange = "all", com
             </defs>
             <CartesianGrid strokeDasharray="3 3" className="stroke-border" vertical={false} />
             <XAxis
-              dataKey="year"
+              dataKey="label"
               tickLine={false}
               axisLine={false}
               className="text-xs fill-muted-foreground"
@@ -0,0 +1,156 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Scale } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { books } from "@/components/BookSelect";
+import { formatCurrency } from "@/lib/utils";
+import type { BookTaxReport as BookTaxReportData } from "@/types";
+
+const taxBooks = books.filter((b) => b.value !== "book");
+
+interface BookTaxReportProps {
+  currentYear: number;
+}
+
+export function BookTaxReport({ currentYear }: BookTaxReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [taxBook, setTaxBook] = useState("federal");
+  const [report, setReport] = useState<BookTaxReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<BookTaxReportData>("get_book_tax_report", { year, taxBook })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year, taxBook]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <Scale className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Book-Tax Differences</h2>
+        </div>
+        <div className="flex gap-2">
+          <Select value={taxBook} onValueChange={setTaxBook}>
+            <SelectTrigger className="w-40">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {taxBooks.map((b) => (
+                <SelectItem key={b.value} value={b.value}>
+                  {b.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <NumberInput
+            step={1}
+            value={year}
+            onChange={(value) => setYear(value ?? currentYear)}
+            className="w-32"
+          />
+        </div>
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 md:grid-cols-3">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Tax Rate</div>
+                  <div className="font-medium font-mono">{report.tax_rate}%</div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Temporary Difference</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_difference)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    {report.total_deferred_tax < 0
+                      ? "Deferred Tax Asset"
+                      : "Deferred Tax Liability"}
+                  </div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(Math.abs(report.total_deferred_tax))}
+                  </div>
+                </div>
+              </div>
+
+              {report.differences.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No assets have a schedule in this tax book for {report.year}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead className="text-right">Book Depreciation</TableHead>
+                      <TableHead className="text-right">Tax Depreciation</TableHead>
+                      <TableHead className="text-right">Book Value</TableHead>
+                      <TableHead className="text-right">Tax Basis</TableHead>
+                      <TableHead className="text-right">Difference</TableHead>
+                      <TableHead className="text-right">Deferred Tax</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.differences.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.book_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.tax_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.book_value)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.tax_basis)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.temporary_difference)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.deferred_tax)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
@@ -0,0 +1,99 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { CalendarSearch } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { DatePicker } from "@/components/ui/date-picker";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency } from "@/lib/utils";
+import type { DepreciationRange } from "@/types";
+
+interface DepreciationRangeReportProps {
+  currentYear: number;
+  book: string;
+}
+
+export function DepreciationRangeReport({ currentYear, book }: DepreciationRangeReportProps) {
+  const [startDate, setStartDate] = useState(`${currentYear}-01-01`);
+  const [endDate, setEndDate] = useState(`${currentYear}-12-31`);
+  const [report, setReport] = useState<DepreciationRange | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!startDate || !endDate) return;
+    invoke<DepreciationRange>("get_depreciation_for_range", { startDate, endDate, book })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [startDate, endDate, book]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <CalendarSearch className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Depreciation for a Date Range</h2>
+        </div>
+        <div className="flex items-center gap-2">
+          <DatePicker value={startDate} onChange={setStartDate} className="w-40" />
+          <span className="text-muted-foreground">to</span>
+          <DatePicker value={endDate} onChange={setEndDate} className="w-40" />
+        </div>
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="space-y-1">
+                <div className="text-sm text-muted-foreground">
+                  Total Depreciation (whole months)
+                </div>
+                <div className="font-medium font-mono">
+                  {formatCurrency(report.total_depreciation)}
+                </div>
+              </div>
+
+              {report.assets.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No depreciation booked in this range.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead className="text-right">Depreciation</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.assets.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.depreciation_expense)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
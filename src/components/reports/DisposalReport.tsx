@@ -0,0 +1,140 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { PackageMinus } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { proceedsTypes } from "@/components/AssetFormDialog";
+import { formatCurrency, formatDate, formatFiscalYear } from "@/lib/utils";
+import type { DisposalReport as DisposalReportData } from "@/types";
+
+interface DisposalReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function DisposalReport({ currentYear, fiscalYearEnd }: DisposalReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [report, setReport] = useState<DisposalReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<DisposalReportData>("get_disposal_report", { year })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <PackageMinus className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Disposals</h2>
+        </div>
+        <NumberInput
+          step={1}
+          value={year}
+          onChange={(value) => setYear(value ?? currentYear)}
+          className="w-32"
+        />
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 md:grid-cols-3">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Proceeds</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_proceeds)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Book Value Disposed</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_book_value)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    {report.total_gain_loss < 0 ? "Net Loss" : "Net Gain"}
+                  </div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_gain_loss)}
+                  </div>
+                </div>
+              </div>
+
+              {report.disposals.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No assets were disposed of in {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead>Date</TableHead>
+                      <TableHead>Type</TableHead>
+                      <TableHead className="text-right">Cost</TableHead>
+                      <TableHead className="text-right">Accumulated Depreciation</TableHead>
+                      <TableHead className="text-right">Book Value</TableHead>
+                      <TableHead className="text-right">Proceeds</TableHead>
+                      <TableHead className="text-right">Selling Costs</TableHead>
+                      <TableHead className="text-right">Gain / Loss</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.disposals.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell>{formatDate(row.disposed_date)}</TableCell>
+                        <TableCell>
+                          {proceedsTypes.find((p) => p.value === row.proceeds_type)?.label ??
+                            row.proceeds_type}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.cost)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.accumulated_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.book_value)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.proceeds)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.selling_costs)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.gain_loss)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
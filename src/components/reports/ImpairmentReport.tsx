@@ -0,0 +1,104 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { TrendingDown } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatDate, formatFiscalYear } from "@/lib/utils";
+import type { ImpairmentReport as ImpairmentReportData } from "@/types";
+
+interface ImpairmentReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function ImpairmentReport({ currentYear, fiscalYearEnd }: ImpairmentReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [report, setReport] = useState<ImpairmentReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<ImpairmentReportData>("get_impairment_report", { year })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <TrendingDown className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Impairments</h2>
+        </div>
+        <NumberInput
+          step={1}
+          value={year}
+          onChange={(value) => setYear(value ?? currentYear)}
+          className="w-32"
+        />
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="space-y-1">
+                <div className="text-sm text-muted-foreground">Total Impairment Loss</div>
+                <div className="font-medium font-mono">{formatCurrency(report.total_amount)}</div>
+              </div>
+
+              {report.impairments.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No impairments were recorded in {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead>Date</TableHead>
+                      <TableHead>Reason</TableHead>
+                      <TableHead className="text-right">Impairment Loss</TableHead>
+                      <TableHead className="text-right">Carrying Amount at Year End</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.impairments.map((row, index) => (
+                      <TableRow key={`${row.asset_id}-${index}`}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell>{formatDate(row.impairment_date)}</TableCell>
+                        <TableCell className="text-muted-foreground">
+                          {row.reason || "—"}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.amount)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.carrying_amount)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
@@ -0,0 +1,130 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { FileSignature } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatFiscalYear } from "@/lib/utils";
+import type { LeaseReport as LeaseReportData } from "@/types";
+
+interface LeaseReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function LeaseReport({ currentYear, fiscalYearEnd }: LeaseReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [report, setReport] = useState<LeaseReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<LeaseReportData>("get_lease_report", { year })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <FileSignature className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Leases</h2>
+        </div>
+        <NumberInput
+          step={1}
+          value={year}
+          onChange={(value) => setYear(value ?? currentYear)}
+          className="w-32"
+        />
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 sm:grid-cols-3">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Right-of-Use Assets</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_asset_book_value)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Lease Liabilities</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_liability)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Current Portion</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_current_liability)}
+                  </div>
+                </div>
+              </div>
+
+              {report.leases.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No leases were in effect in {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Lease</TableHead>
+                      <TableHead className="text-right">Depreciation</TableHead>
+                      <TableHead className="text-right">Asset at Year End</TableHead>
+                      <TableHead className="text-right">Interest</TableHead>
+                      <TableHead className="text-right">Payments</TableHead>
+                      <TableHead className="text-right">Liability at Year End</TableHead>
+                      <TableHead className="text-right">Current Portion</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.leases.map((row) => (
+                      <TableRow key={row.lease_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.asset_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.asset_book_value)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.interest)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.payments)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.liability)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.current_liability)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
@@ -0,0 +1,136 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Car } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { books } from "@/components/BookSelect";
+import { formatCurrency, formatFiscalYear } from "@/lib/utils";
+import type { ListedPropertyReport as ListedPropertyReportData } from "@/types";
+
+const taxBooks = books.filter((b) => b.value !== "book");
+
+interface ListedPropertyReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function ListedPropertyReport({ currentYear, fiscalYearEnd }: ListedPropertyReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [taxBook, setTaxBook] = useState("federal");
+  const [report, setReport] = useState<ListedPropertyReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<ListedPropertyReportData>("get_listed_property_report", { year, taxBook })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year, taxBook]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <Car className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Listed Property Recapture</h2>
+        </div>
+        <div className="flex gap-2">
+          <Select value={taxBook} onValueChange={setTaxBook}>
+            <SelectTrigger className="w-40">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {taxBooks.map((b) => (
+                <SelectItem key={b.value} value={b.value}>
+                  {b.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <NumberInput
+            step={1}
+            value={year}
+            onChange={(value) => setYear(value ?? currentYear)}
+            className="w-32"
+          />
+        </div>
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="space-y-1">
+                <div className="text-sm text-muted-foreground">Excess Depreciation Recaptured</div>
+                <div className="font-medium font-mono">
+                  {formatCurrency(report.total_recapture)}
+                </div>
+              </div>
+
+              {report.assets.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No listed property dropped to 50% business use or less in{" "}
+                  {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead className="text-right">Use When Placed in Service</TableHead>
+                      <TableHead className="text-right">Use This Year</TableHead>
+                      <TableHead className="text-right">Depreciation Taken</TableHead>
+                      <TableHead className="text-right">ADS Depreciation</TableHead>
+                      <TableHead className="text-right">Recapture</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.assets.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {row.placed_in_service_use}%
+                        </TableCell>
+                        <TableCell className="text-right font-mono">{row.business_use}%</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.depreciation_taken)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.ads_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.recapture)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
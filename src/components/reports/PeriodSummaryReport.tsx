@@ -0,0 +1,126 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { CalendarDays } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatFiscalYear, formatMonth } from "@/lib/utils";
+import type { PeriodSummary } from "@/types";
+
+interface PeriodSummaryReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+  book: string;
+}
+
+export function PeriodSummaryReport({
+  currentYear,
+  fiscalYearEnd,
+  book,
+}: PeriodSummaryReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [period, setPeriod] = useState("month");
+  const [rows, setRows] = useState<PeriodSummary[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<PeriodSummary[]>("get_period_summary", { book, period })
+      .then((data) => {
+        setRows(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setRows([]);
+        setError(String(err));
+      });
+  }, [book, period]);
+
+  const yearRows = rows.filter((row) => row.year === year);
+  const total = yearRows.reduce((sum, row) => sum + row.total_depreciation, 0);
+
+  const periodLabel = (row: PeriodSummary) =>
+    period === "quarter"
+      ? `Q${row.period} ${formatFiscalYear(row.year, fiscalYearEnd)}`
+      : formatMonth(row.period_start);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <CalendarDays className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Periodic Depreciation</h2>
+        </div>
+        <div className="flex gap-2">
+          <Select value={period} onValueChange={setPeriod}>
+            <SelectTrigger className="w-32">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="month">Monthly</SelectItem>
+              <SelectItem value="quarter">Quarterly</SelectItem>
+            </SelectContent>
+          </Select>
+          <NumberInput
+            step={1}
+            value={year}
+            onChange={(value) => setYear(value ?? currentYear)}
+            className="w-32"
+          />
+        </div>
+      </div>
+
+      <Card>
+        <CardContent className="p-0">
+          {error && <p className="p-6 text-sm text-destructive">{error}</p>}
+          {!error && yearRows.length === 0 ? (
+            <p className="p-6 text-sm text-muted-foreground">
+              No depreciation booked in {formatFiscalYear(year, fiscalYearEnd)}.
+            </p>
+          ) : (
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>{period === "quarter" ? "Quarter" : "Month"}</TableHead>
+                  <TableHead className="text-right">Total Depreciation</TableHead>
+                  <TableHead className="text-right">Number of Assets</TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {yearRows.map((row) => (
+                  <TableRow key={row.period}>
+                    <TableCell className="font-medium">{periodLabel(row)}</TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(row.total_depreciation)}
+                    </TableCell>
+                    <TableCell className="text-right">{row.asset_count}</TableCell>
+                  </TableRow>
+                ))}
+                <TableRow>
+                  <TableCell className="font-semibold">Total</TableCell>
+                  <TableCell className="text-right font-mono font-semibold">
+                    {formatCurrency(total)}
+                  </TableCell>
+                  <TableCell />
+                </TableRow>
+              </TableBody>
+            </Table>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
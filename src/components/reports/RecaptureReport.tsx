@@ -0,0 +1,169 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Receipt } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { books } from "@/components/BookSelect";
+import { formatCurrency, formatDate, formatFiscalYear } from "@/lib/utils";
+import type { RecaptureReport as RecaptureReportData } from "@/types";
+
+const taxBooks = books.filter((b) => b.value !== "book");
+
+interface RecaptureReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function RecaptureReport({ currentYear, fiscalYearEnd }: RecaptureReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [taxBook, setTaxBook] = useState("federal");
+  const [report, setReport] = useState<RecaptureReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<RecaptureReportData>("get_recapture_report", { year, taxBook })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year, taxBook]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <Receipt className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Depreciation Recapture</h2>
+        </div>
+        <div className="flex gap-2">
+          <Select value={taxBook} onValueChange={setTaxBook}>
+            <SelectTrigger className="w-40">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {taxBooks.map((b) => (
+                <SelectItem key={b.value} value={b.value}>
+                  {b.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <NumberInput
+            step={1}
+            value={year}
+            onChange={(value) => setYear(value ?? currentYear)}
+            className="w-32"
+          />
+        </div>
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 md:grid-cols-4">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Tax Gain / Loss</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_gain_loss)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">§1245 Ordinary Income</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_ordinary_income)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Unrecaptured §1250 Gain</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_unrecaptured_1250)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">§1231 Gain / Loss</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_capital_gain)}
+                  </div>
+                </div>
+              </div>
+
+              {report.recaptures.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No assets were disposed of in {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead>Date</TableHead>
+                      <TableHead>Section</TableHead>
+                      <TableHead className="text-right">Amount Realized</TableHead>
+                      <TableHead className="text-right">Tax Basis</TableHead>
+                      <TableHead className="text-right">Depreciation Taken</TableHead>
+                      <TableHead className="text-right">Gain / Loss</TableHead>
+                      <TableHead className="text-right">Ordinary Income</TableHead>
+                      <TableHead className="text-right">Unrecaptured §1250</TableHead>
+                      <TableHead className="text-right">§1231</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.recaptures.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell>{formatDate(row.disposed_date)}</TableCell>
+                        <TableCell>§{row.section}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.amount_realized)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.tax_basis)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.accumulated_depreciation)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.gain_loss)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.ordinary_income)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.unrecaptured_1250)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.capital_gain)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
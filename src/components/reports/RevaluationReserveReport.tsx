@@ -0,0 +1,133 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Scale } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatFiscalYear } from "@/lib/utils";
+import type { RevaluationReserveReport as RevaluationReserveReportData } from "@/types";
+
+interface RevaluationReserveReportProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+}
+
+export function RevaluationReserveReport({
+  currentYear,
+  fiscalYearEnd,
+}: RevaluationReserveReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [report, setReport] = useState<RevaluationReserveReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<RevaluationReserveReportData>("get_revaluation_reserve_report", { year })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year]);
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <Scale className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Revaluation Reserve</h2>
+        </div>
+        <NumberInput
+          step={1}
+          value={year}
+          onChange={(value) => setYear(value ?? currentYear)}
+          className="w-32"
+        />
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 sm:grid-cols-3">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Opening Reserve</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_opening_balance)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">Closing Reserve</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_closing_balance)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">In Profit or Loss</div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(report.total_profit_loss)}
+                  </div>
+                </div>
+              </div>
+
+              {report.entries.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No revalued assets in {formatFiscalYear(report.year, fiscalYearEnd)}.
+                </p>
+              ) : (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead className="text-right">Opening</TableHead>
+                      <TableHead className="text-right">Increase</TableHead>
+                      <TableHead className="text-right">Decrease</TableHead>
+                      <TableHead className="text-right">To Retained Earnings</TableHead>
+                      <TableHead className="text-right">Closing</TableHead>
+                      <TableHead className="text-right">Profit or Loss</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.entries.map((row) => (
+                      <TableRow key={row.asset_id}>
+                        <TableCell className="font-medium">{row.name}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.opening_balance)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.increase)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.decrease)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.transferred)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.closing_balance)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(row.profit_loss)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
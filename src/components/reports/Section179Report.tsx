@@ -0,0 +1,115 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Receipt } from "lucide-react";
+import { Card, CardContent } from "@/components/ui/card";
+import { Badge } from "@/components/ui/badge";
+import { NumberInput } from "@/components/ui/number-input";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency, formatDate } from "@/lib/utils";
+import type { Section179Report as Section179ReportData } from "@/types";
+
+interface Section179ReportProps {
+  currentYear: number;
+}
+
+export function Section179Report({ currentYear }: Section179ReportProps) {
+  const [year, setYear] = useState(currentYear);
+  const [report, setReport] = useState<Section179ReportData | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<Section179ReportData>("get_section_179_report", { year })
+      .then((data) => {
+        setReport(data);
+        setError(null);
+      })
+      .catch((err) => {
+        setReport(null);
+        setError(String(err));
+      });
+  }, [year]);
+
+  const totals = report
+    ? [
+        { label: "Dollar Limit", value: report.dollar_limit },
+        { label: "Phase-out Threshold", value: report.phase_out_threshold },
+        { label: "Qualifying Property Placed in Service", value: report.qualifying_cost },
+        { label: "Allowed Limit", value: report.allowed_limit },
+        { label: "Total Elected", value: report.total_elected },
+      ]
+    : [];
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <Receipt className="h-5 w-5 text-muted-foreground" />
+          <h2 className="text-xl font-semibold">Section 179</h2>
+          {report && report.excess > 0 && (
+            <Badge variant="destructive">
+              {formatCurrency(report.excess)} over limit
+            </Badge>
+          )}
+        </div>
+        <NumberInput
+          step={1}
+          value={year}
+          onChange={(value) => setYear(value ?? currentYear)}
+          className="w-32"
+        />
+      </div>
+
+      <Card>
+        <CardContent className="space-y-4 pt-6">
+          {error && <p className="text-sm text-destructive">{error}</p>}
+          {report && (
+            <>
+              <div className="grid gap-4 md:grid-cols-3 lg:grid-cols-5">
+                {totals.map((item) => (
+                  <div key={item.label} className="space-y-1">
+                    <div className="text-sm text-muted-foreground">{item.label}</div>
+                    <div className="font-medium font-mono">{formatCurrency(item.value)}</div>
+                  </div>
+                ))}
+              </div>
+
+              {report.elections.length > 0 && (
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Asset</TableHead>
+                      <TableHead>Placed in Service</TableHead>
+                      <TableHead className="text-right">Cost</TableHead>
+                      <TableHead className="text-right">Section 179</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {report.elections.map((election) => (
+                      <TableRow key={election.asset_id}>
+                        <TableCell className="font-medium">{election.name}</TableCell>
+                        <TableCell>{formatDate(election.date_placed_in_service)}</TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(election.cost)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(election.amount)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
@@ -14,12 +14,14 @@ import {
   BookValueAreaChart,
   type TimeRange,
 } from "@/components/charts/DashboardCharts";
+import { formatFiscalYear } from "@/lib/utils";
 import type { AssetWithSchedule, AnnualSummary } from "@/types";
 
 interface AnalysisProps {
   assets: AssetWithSchedule[];
   annualSummary: AnnualSummary[];
   currentYear: number;
+  fiscalYearEnd: number;
   onFilterByCategory: (categoryName: string) => void;
 }
 
@@ -27,6 +29,7 @@ export function Analysis({
   assets,
   annualSummary,
   currentYear,
+  fiscalYearEnd,
   onFilterByCategory,
 }: AnalysisProps) {
   const [timeRange, setTimeRange] = useState<TimeRange>("all");
@@ -74,10 +77,10 @@ export function Analysis({
       totalYears: allYears.length,
       filteredYears: filteredCount,
       label: startYear === endYear
-        ? `${startYear}`
-        : `${startYear} – ${endYear}`,
+        ? formatFiscalYear(startYear, fiscalYearEnd)
+        : `${formatFiscalYear(startYear, fiscalYearEnd)} – ${formatFiscalYear(endYear, fiscalYearEnd)}`,
     };
-  }, [annualSummary, timeRange, currentYear]);
+  }, [annualSummary, timeRange, currentYear, fiscalYearEnd]);
 
   const hasData = assets.length > 0 || annualSummary.length > 0;
 
@@ -154,6 +157,7 @@ export function Analysis({
                   <DepreciationBarChart
                     annualSummary={annualSummary}
                     currentYear={currentYear}
+                    fiscalYearEnd={fiscalYearEnd}
                     timeRange={timeRange}
                     compact
                   />
@@ -162,6 +166,7 @@ export function Analysis({
                   <BookValueAreaChart
                     assets={assets}
                     currentYear={currentYear}
+                    fiscalYearEnd={fiscalYearEnd}
                     timeRange={timeRange}
                     compact
                   />
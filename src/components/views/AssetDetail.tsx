@@ -1,5 +1,6 @@
-import { useState } from "react";
-import { ChevronRight, Pencil, Trash2 } from "lucide-react";
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { ChevronRight, Pencil, Plus, Scissors, Trash2 } from "lucide-react";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
@@ -21,27 +22,74 @@ import {
   DialogTitle,
   DialogClose,
 } from "@/components/ui/dialog";
-import { formatCurrency, formatDate } from "@/lib/utils";
-import type { AssetWithSchedule } from "@/types";
+import { UsageLog } from "@/components/UsageLog";
+import { BusinessUseLog } from "@/components/BusinessUseLog";
+import { AssetBooks } from "@/components/AssetBooks";
+import { EstimateChanges } from "@/components/EstimateChanges";
+import { AssetComponents } from "@/components/AssetComponents";
+import { Impairments } from "@/components/Impairments";
+import { Revaluations } from "@/components/Revaluations";
+import { BookSelect } from "@/components/BookSelect";
+import { PartialDisposeDialog } from "@/components/PartialDisposeDialog";
+import { proceedsTypes } from "@/components/AssetFormDialog";
+import { formatCurrency, formatDate, formatFiscalYear } from "@/lib/utils";
+import type { Asset, AssetWithSchedule, Disposal } from "@/types";
 
 interface AssetDetailProps {
   asset: AssetWithSchedule;
   currentYear: number;
+  fiscalYearEnd: number;
   onEdit: () => void;
   onDelete: () => void;
   onBack: () => void;
+  onOpenAsset: (id: number) => void;
+  onAddImprovement: () => void;
+  onScheduleChange: () => void;
+  revaluationModel: boolean;
+  book: string;
+  onBookChange: (book: string) => void;
 }
 
 export function AssetDetail({
   asset,
   currentYear,
+  fiscalYearEnd,
   onEdit,
   onDelete,
   onBack,
+  onOpenAsset,
+  onAddImprovement,
+  onScheduleChange,
+  revaluationModel,
+  book,
+  onBookChange,
 }: AssetDetailProps) {
   const [showDeleteDialog, setShowDeleteDialog] = useState(false);
+  const [showPartialDialog, setShowPartialDialog] = useState(false);
+  const [disposal, setDisposal] = useState<Disposal | null>(null);
+  const [parts, setParts] = useState<Asset[]>([]);
 
-  const { asset: assetData, schedule, category_name } = asset;
+  const { asset: assetData, schedule, category_name, improvements } = asset;
+  const intangible = assetData.asset_kind === "intangible";
+  const impaired = schedule.some((entry) => entry.impairment_loss);
+  const revalued = schedule.some((entry) => entry.revaluation_adjustment);
+
+  useEffect(() => {
+    if (!assetData.id || !assetData.disposed_date) {
+      setDisposal(null);
+      return;
+    }
+    invoke<Disposal | null>("get_disposal", { assetId: assetData.id })
+      .then(setDisposal)
+      .catch(() => setDisposal(null));
+  }, [asset]);
+
+  useEffect(() => {
+    if (!assetData.id) return;
+    invoke<Asset[]>("get_asset_parts", { assetId: assetData.id })
+      .then(setParts)
+      .catch(() => setParts([]));
+  }, [asset]);
 
   const detailItems = [
     { label: "Category", value: category_name || "None" },
@@ -50,6 +98,7 @@ export function AssetDetail({
     { label: "Salvage Value", value: formatCurrency(assetData.salvage_value) },
     { label: "Useful Life", value: `${assetData.useful_life_years} years` },
     { label: "Property Class", value: assetData.property_class || "—" },
+    ...(assetData.quantity ? [{ label: "Quantity", value: String(assetData.quantity) }] : []),
   ];
 
   const handleDeleteConfirm = () => {
@@ -77,6 +126,24 @@ export function AssetDetail({
 
         {/* All Action Buttons */}
         <div className="flex items-center gap-2 flex-wrap shrink-0">
+          {!assetData.disposed_date && !assetData.improved_asset_id && (
+            <Button variant="outline" onClick={onAddImprovement} className="gap-2">
+              <Plus className="h-4 w-4" />
+              Add Improvement
+            </Button>
+          )}
+
+          {!assetData.disposed_date && (
+            <Button
+              variant="outline"
+              onClick={() => setShowPartialDialog(true)}
+              className="gap-2"
+            >
+              <Scissors className="h-4 w-4" />
+              Dispose of Part
+            </Button>
+          )}
+
           <Button variant="outline" onClick={onEdit} className="gap-2">
             <Pencil className="h-4 w-4" />
             Edit
@@ -110,6 +177,30 @@ export function AssetDetail({
             ))}
           </div>
 
+          {assetData.improved_asset_id && (
+            <p className="mt-4 text-sm text-muted-foreground">
+              Capital improvement to{" "}
+              <button
+                onClick={() => onOpenAsset(assetData.improved_asset_id!)}
+                className="font-medium text-foreground underline-offset-4 hover:underline"
+              >
+                the main asset
+              </button>
+            </p>
+          )}
+
+          {assetData.parent_asset_id && (
+            <p className="mt-4 text-sm text-muted-foreground">
+              Part disposed of from{" "}
+              <button
+                onClick={() => onOpenAsset(assetData.parent_asset_id!)}
+                className="font-medium text-foreground underline-offset-4 hover:underline"
+              >
+                the original asset
+              </button>
+            </p>
+          )}
+
           {(assetData.description || assetData.notes) && (
             <>
               <Separator className="my-6" />
@@ -134,77 +225,188 @@ export function AssetDetail({
             </>
           )}
 
-          {assetData.disposed_date && (
+          {assetData.disposed_date && disposal && (
             <>
               <Separator className="my-6" />
-              {(() => {
-                // Calculate book value at disposal and gain/loss
-                const disposalYear = new Date(assetData.disposed_date).getFullYear();
-                const scheduleAtDisposal = schedule.find(s => s.year === disposalYear);
-                const lastScheduleEntry = schedule[schedule.length - 1];
-
-                // Use the beginning book value of disposal year (value before that year's depreciation)
-                // or ending value of last schedule year if disposed after schedule ends
-                const bookValueAtDisposal = scheduleAtDisposal
-                  ? scheduleAtDisposal.beginning_book_value
-                  : lastScheduleEntry?.ending_book_value ?? assetData.salvage_value;
-
-                const disposedValue = assetData.disposed_value ?? 0;
-                const gainLoss = disposedValue - bookValueAtDisposal;
-                const hasGainLoss = assetData.disposed_value != null;
-
-                return (
-                  <div className="grid gap-4 md:grid-cols-2 lg:grid-cols-4">
-                    <div className="space-y-1">
-                      <div className="text-sm text-muted-foreground">
-                        Disposed Date
-                      </div>
-                      <div className="font-medium">{formatDate(assetData.disposed_date)}</div>
-                    </div>
-                    <div className="space-y-1">
-                      <div className="text-sm text-muted-foreground">
-                        Book Value at Disposal
-                      </div>
-                      <div className="font-medium font-mono">
-                        {formatCurrency(bookValueAtDisposal)}
-                      </div>
-                    </div>
-                    <div className="space-y-1">
-                      <div className="text-sm text-muted-foreground">
-                        Sale Price
-                      </div>
-                      <div className="font-medium font-mono">
-                        {assetData.disposed_value != null
-                          ? formatCurrency(assetData.disposed_value)
-                          : "—"}
-                      </div>
+              <div className="grid gap-4 md:grid-cols-2 lg:grid-cols-4">
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    Disposed Date
+                  </div>
+                  <div className="font-medium">{formatDate(disposal.disposed_date)}</div>
+                  <div className="text-xs text-muted-foreground">
+                    {proceedsTypes.find((p) => p.value === disposal.proceeds_type)?.label}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    Book Value at Disposal
+                  </div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(disposal.book_value)}
+                  </div>
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    Net Proceeds
+                  </div>
+                  <div className="font-medium font-mono">
+                    {formatCurrency(disposal.proceeds - disposal.selling_costs)}
+                  </div>
+                  {disposal.selling_costs > 0 && (
+                    <div className="text-xs text-muted-foreground">
+                      {formatCurrency(disposal.proceeds)} less{" "}
+                      {formatCurrency(disposal.selling_costs)} selling costs
                     </div>
-                    {hasGainLoss && (
-                      <div className="space-y-1">
-                        <div className="text-sm text-muted-foreground">
-                          {gainLoss >= 0 ? "Gain on Sale" : "Loss on Sale"}
-                        </div>
-                        <div className={`font-medium font-mono ${
-                          gainLoss >= 0
-                            ? "text-green-600 dark:text-green-400"
-                            : "text-red-600 dark:text-red-400"
-                        }`}>
-                          {gainLoss >= 0 ? "+" : ""}{formatCurrency(gainLoss)}
-                        </div>
-                      </div>
-                    )}
+                  )}
+                </div>
+                <div className="space-y-1">
+                  <div className="text-sm text-muted-foreground">
+                    {disposal.gain_loss >= 0 ? "Gain on Disposal" : "Loss on Disposal"}
+                  </div>
+                  <div className={`font-medium font-mono ${
+                    disposal.gain_loss >= 0
+                      ? "text-green-600 dark:text-green-400"
+                      : "text-red-600 dark:text-red-400"
+                  }`}>
+                    {disposal.gain_loss >= 0 ? "+" : ""}{formatCurrency(disposal.gain_loss)}
                   </div>
-                );
-              })()}
+                </div>
+              </div>
             </>
           )}
         </CardContent>
       </Card>
 
+      {improvements.length > 0 && (
+        <Card>
+          <CardHeader>
+            <CardTitle>Improvements</CardTitle>
+          </CardHeader>
+          <CardContent className="p-0">
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Improvement</TableHead>
+                  <TableHead>In Service</TableHead>
+                  <TableHead className="text-right">Cost</TableHead>
+                  <TableHead className="text-right">Useful Life</TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {improvements.map((improvement) => (
+                  <TableRow
+                    key={improvement.id}
+                    className="cursor-pointer"
+                    onClick={() => onOpenAsset(improvement.id!)}
+                  >
+                    <TableCell className="font-medium">{improvement.name}</TableCell>
+                    <TableCell>{formatDate(improvement.date_placed_in_service)}</TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(improvement.cost)}
+                    </TableCell>
+                    <TableCell className="text-right">
+                      {improvement.useful_life_years} years
+                    </TableCell>
+                  </TableRow>
+                ))}
+              </TableBody>
+            </Table>
+          </CardContent>
+        </Card>
+      )}
+
+      {parts.length > 0 && (
+        <Card>
+          <CardHeader>
+            <CardTitle>Parts Disposed Of</CardTitle>
+          </CardHeader>
+          <CardContent className="p-0">
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Part</TableHead>
+                  <TableHead>Disposed</TableHead>
+                  <TableHead className="text-right">Cost</TableHead>
+                  <TableHead className="text-right">Proceeds</TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {parts.map((part) => (
+                  <TableRow
+                    key={part.id}
+                    className="cursor-pointer"
+                    onClick={() => onOpenAsset(part.id!)}
+                  >
+                    <TableCell className="font-medium">{part.name}</TableCell>
+                    <TableCell>{part.disposed_date && formatDate(part.disposed_date)}</TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(part.cost)}
+                    </TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(part.disposed_value ?? 0)}
+                    </TableCell>
+                  </TableRow>
+                ))}
+              </TableBody>
+            </Table>
+          </CardContent>
+        </Card>
+      )}
+
+      {assetData.method === "units_of_production" && assetData.id && (
+        <UsageLog
+          assetId={assetData.id}
+          currentYear={currentYear}
+          fiscalYearEnd={fiscalYearEnd}
+          onChange={onScheduleChange}
+        />
+      )}
+
+      {assetData.business_use_percentage != null && assetData.id && (
+        <BusinessUseLog
+          assetId={assetData.id}
+          currentYear={currentYear}
+          fiscalYearEnd={fiscalYearEnd}
+          onChange={onScheduleChange}
+        />
+      )}
+
+      {assetData.id && <AssetBooks assetId={assetData.id} onChange={onScheduleChange} />}
+
+      {assetData.id && <Impairments asset={assetData} onChange={onScheduleChange} />}
+
+      {assetData.id && revaluationModel && (
+        <Revaluations asset={assetData} onChange={onScheduleChange} />
+      )}
+
+      {assetData.id && (
+        <AssetComponents
+          asset={assetData}
+          currentYear={currentYear}
+          onChange={onScheduleChange}
+        />
+      )}
+
+      {assetData.id &&
+        assetData.method !== "macrs_gds" &&
+        assetData.method !== "macrs_ads" &&
+        assetData.method !== "units_of_production" && (
+          <EstimateChanges asset={assetData} onChange={onScheduleChange} />
+        )}
+
       {/* Depreciation Schedule */}
       <Card>
-        <CardHeader>
-          <CardTitle>Depreciation Schedule</CardTitle>
+        <CardHeader className="flex flex-row items-center justify-between space-y-0">
+          <CardTitle>
+            {intangible ? "Amortization Schedule" : "Depreciation Schedule"}
+            {improvements.length > 0 && (
+              <span className="ml-2 text-sm font-normal text-muted-foreground">
+                including improvements
+              </span>
+            )}
+          </CardTitle>
+          <BookSelect value={book} onChange={onBookChange} />
         </CardHeader>
         <CardContent className="p-0">
           <Table>
@@ -212,7 +414,11 @@ export function AssetDetail({
               <TableRow>
                 <TableHead>Year</TableHead>
                 <TableHead className="text-right">Beginning Value</TableHead>
-                <TableHead className="text-right">Depreciation</TableHead>
+                <TableHead className="text-right">
+                  {intangible ? "Amortization" : "Depreciation"}
+                </TableHead>
+                {impaired && <TableHead className="text-right">Impairment</TableHead>}
+                {revalued && <TableHead className="text-right">Revaluation</TableHead>}
                 <TableHead className="text-right">Accumulated</TableHead>
                 <TableHead className="text-right">Ending Value</TableHead>
                 <TableHead></TableHead>
@@ -226,13 +432,27 @@ export function AssetDetail({
                     entry.year === currentYear ? "bg-primary/5" : ""
                   }
                 >
-                  <TableCell className="font-medium">{entry.year}</TableCell>
+                  <TableCell className="font-medium">
+                    {formatFiscalYear(entry.year, fiscalYearEnd)}
+                  </TableCell>
                   <TableCell className="text-right font-mono">
                     {formatCurrency(entry.beginning_book_value)}
                   </TableCell>
                   <TableCell className="text-right font-mono">
                     {formatCurrency(entry.depreciation_expense)}
                   </TableCell>
+                  {impaired && (
+                    <TableCell className="text-right font-mono">
+                      {entry.impairment_loss ? formatCurrency(entry.impairment_loss) : "—"}
+                    </TableCell>
+                  )}
+                  {revalued && (
+                    <TableCell className="text-right font-mono">
+                      {entry.revaluation_adjustment
+                        ? formatCurrency(entry.revaluation_adjustment)
+                        : "—"}
+                    </TableCell>
+                  )}
                   <TableCell className="text-right font-mono">
                     {formatCurrency(entry.accumulated_depreciation)}
                   </TableCell>
@@ -251,6 +471,13 @@ export function AssetDetail({
         </CardContent>
       </Card>
 
+      <PartialDisposeDialog
+        open={showPartialDialog}
+        asset={assetData}
+        onClose={() => setShowPartialDialog(false)}
+        onDisposed={onScheduleChange}
+      />
+
       {/* Delete Confirmation Dialog */}
       <Dialog open={showDeleteDialog} onOpenChange={setShowDeleteDialog}>
         <DialogContent>
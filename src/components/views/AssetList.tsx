@@ -19,12 +19,13 @@ import {
   TableHeader,
   TableRow,
 } from "@/components/ui/table";
-import { formatCurrency } from "@/lib/utils";
+import { formatCurrency, formatFiscalYear, rolledUpCost } from "@/lib/utils";
 import type { AssetWithSchedule, AssetFilters } from "@/types";
 
 interface AssetListProps {
   assets: AssetWithSchedule[];
   currentYear: number;
+  fiscalYearEnd: number;
   filters: AssetFilters;
   onFiltersChange: (filters: AssetFilters) => void;
   onResetFilters: () => void;
@@ -35,6 +36,7 @@ interface AssetListProps {
 export function AssetList({
   assets,
   currentYear,
+  fiscalYearEnd,
   filters,
   onFiltersChange,
   onResetFilters,
@@ -269,21 +271,29 @@ export function AssetList({
                   <TableHead>Category</TableHead>
                   <TableHead className="text-right">Cost</TableHead>
                   <TableHead className="text-right">Book Value</TableHead>
-                  <TableHead className="text-right">{currentYear} Depr.</TableHead>
+                  <TableHead className="text-right">{formatFiscalYear(currentYear, fiscalYearEnd)} Depr.</TableHead>
                   <TableHead>Status</TableHead>
                 </TableRow>
               </TableHeader>
               <TableBody>
                 {filteredAssets.map((item) => {
                   const currentEntry = item.schedule.find((s) => s.year === currentYear);
-                  const bookValue = currentEntry?.ending_book_value ?? item.asset.cost;
+                  const bookValue = currentEntry?.ending_book_value ?? rolledUpCost(item);
                   return (
                     <TableRow
                       key={item.asset.id}
                       className="cursor-pointer"
                       onClick={() => onViewAsset(item)}
                     >
-                      <TableCell className="font-medium">{item.asset.name}</TableCell>
+                      <TableCell className="font-medium">
+                        {item.asset.name}
+                        {item.improvements.length > 0 && (
+                          <span className="ml-2 text-xs font-normal text-muted-foreground">
+                            +{item.improvements.length} improvement
+                            {item.improvements.length === 1 ? "" : "s"}
+                          </span>
+                        )}
+                      </TableCell>
                       <TableCell>
                         {item.category_name ? (
                           <Badge variant="secondary">{item.category_name}</Badge>
@@ -292,7 +302,7 @@ export function AssetList({
                         )}
                       </TableCell>
                       <TableCell className="text-right font-mono">
-                        {formatCurrency(item.asset.cost)}
+                        {formatCurrency(rolledUpCost(item))}
                       </TableCell>
                       <TableCell className="text-right font-mono">
                         {formatCurrency(bookValue)}
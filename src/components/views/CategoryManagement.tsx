@@ -37,6 +37,16 @@ const propertyClasses = [
   { value: "39", label: "39-year" },
 ];
 
+const depreciationMethods = [
+  { value: "straight_line", label: "Straight-Line" },
+  { value: "macrs_gds", label: "MACRS (GDS)" },
+  { value: "macrs_ads", label: "MACRS (ADS)" },
+  { value: "declining_200", label: "Double Declining Balance" },
+  { value: "declining_150", label: "150% Declining Balance" },
+  { value: "sum_of_years_digits", label: "Sum-of-the-Years' Digits" },
+  { value: "units_of_production", label: "Units of Production" },
+];
+
 interface CategoryManagementProps {
   onCategoriesChange?: () => void;
 }
@@ -53,10 +63,14 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
     name: string;
     default_useful_life: number | undefined;
     default_property_class: string;
+    default_method: string;
+    revaluation_model: boolean;
   }>({
     name: "",
     default_useful_life: undefined,
     default_property_class: "",
+    default_method: "",
+    revaluation_model: false,
   });
   const [formErrors, setFormErrors] = useState<Record<string, string>>({});
   const [saving, setSaving] = useState(false);
@@ -91,6 +105,8 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
       name: "",
       default_useful_life: undefined,
       default_property_class: "",
+      default_method: "",
+      revaluation_model: false,
     });
     setFormErrors({});
     setDialogOpen(true);
@@ -102,6 +118,8 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
       name: category.name,
       default_useful_life: category.default_useful_life,
       default_property_class: category.default_property_class ?? "",
+      default_method: category.default_method ?? "",
+      revaluation_model: category.revaluation_model,
     });
     setFormErrors({});
     setDialogOpen(true);
@@ -120,6 +138,8 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
       name: formData.name.trim(),
       default_useful_life: formData.default_useful_life,
       default_property_class: formData.default_property_class.trim() || undefined,
+      default_method: formData.default_method || undefined,
+      revaluation_model: formData.revaluation_model,
     };
 
     const result = validateCategory(category);
@@ -263,6 +283,7 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
                   </div>
                 </div>
                 <div className="flex items-center gap-2">
+                  {category.revaluation_model && <Badge variant="outline">Revaluation</Badge>}
                   <Badge variant={category.asset_count > 0 ? "secondary" : "outline"}>
                     {category.asset_count} asset{category.asset_count !== 1 ? "s" : ""}
                   </Badge>
@@ -357,6 +378,51 @@ export function CategoryManagement({ onCategoriesChange }: CategoryManagementPro
                   IRS property classification for depreciation
                 </p>
               </div>
+              <div className="space-y-2">
+                <Label htmlFor="default_method">Default Depreciation Method</Label>
+                <Select
+                  value={formData.default_method || "none"}
+                  onValueChange={(value) =>
+                    handleFieldChange("default_method", value === "none" ? "" : value)
+                  }
+                >
+                  <SelectTrigger className={inputErrorClass("default_method")}>
+                    <SelectValue placeholder="Select method" />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="none">None (Straight-Line)</SelectItem>
+                    {depreciationMethods.map((m) => (
+                      <SelectItem key={m.value} value={m.value}>
+                        {m.label}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+                <FieldError error={formErrors.default_method} />
+                <p className="text-xs text-muted-foreground">
+                  Used for new assets that don't specify a method
+                </p>
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="revaluation_model">Measurement Model</Label>
+                <Select
+                  value={formData.revaluation_model ? "revaluation" : "cost"}
+                  onValueChange={(value) =>
+                    handleFieldChange("revaluation_model", value === "revaluation")
+                  }
+                >
+                  <SelectTrigger id="revaluation_model">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="cost">Cost</SelectItem>
+                    <SelectItem value="revaluation">Revaluation (IAS 16)</SelectItem>
+                  </SelectContent>
+                </Select>
+                <p className="text-xs text-muted-foreground">
+                  Revaluation carries the category's assets at fair value in the financial book
+                </p>
+              </div>
             </div>
             <DialogFooter>
               <Button variant="outline" onClick={() => setDialogOpen(false)}>
@@ -2,7 +2,7 @@ import { Package, BarChart3, FileText, ChevronRight } from "lucide-react";
 import { Card, CardContent } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
-import { formatCurrency, formatNumber } from "@/lib/utils";
+import { formatCurrency, formatFiscalYear, formatNumber, rolledUpCost } from "@/lib/utils";
 import type { DashboardStats, AssetWithSchedule } from "@/types";
 
 type View = "assets" | "analysis" | "reports";
@@ -11,6 +11,7 @@ interface DashboardProps {
   stats: DashboardStats | null;
   assets: AssetWithSchedule[];
   currentYear: number;
+  fiscalYearEnd: number;
   onViewAsset: (asset: AssetWithSchedule) => void;
   onNavigate: (view: View) => void;
 }
@@ -19,6 +20,7 @@ export function Dashboard({
   stats,
   assets,
   currentYear,
+  fiscalYearEnd,
   onViewAsset,
   onNavigate,
 }: DashboardProps) {
@@ -36,7 +38,7 @@ export function Dashboard({
       title: "Analysis",
       icon: BarChart3,
       value: stats ? formatCurrency(stats.current_year_depreciation) : "$0",
-      subtitle: `${currentYear} depreciation`,
+      subtitle: `${formatFiscalYear(currentYear, fiscalYearEnd)} depreciation`,
       valueClass: "",
     },
     {
@@ -137,7 +139,7 @@ export function Dashboard({
                         </div>
                       </div>
                       <div className="text-right space-y-1">
-                        <div className="font-medium">{formatCurrency(item.asset.cost)}</div>
+                        <div className="font-medium">{formatCurrency(rolledUpCost(item))}</div>
                         <div className="text-sm text-muted-foreground">
                           {formatCurrency(currentDepr)}/yr
                         </div>
@@ -0,0 +1,121 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { CalendarRange } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { formatFiscalYear } from "@/lib/utils";
+import type { FiscalYearSettings as FiscalYearSettingsData } from "@/types";
+
+const months = [
+  "January",
+  "February",
+  "March",
+  "April",
+  "May",
+  "June",
+  "July",
+  "August",
+  "September",
+  "October",
+  "November",
+  "December",
+];
+
+interface FiscalYearSettingsProps {
+  onFiscalYearChange?: () => void;
+}
+
+export function FiscalYearSettings({ onFiscalYearChange }: FiscalYearSettingsProps) {
+  const [settings, setSettings] = useState<FiscalYearSettingsData | null>(null);
+  const [endMonth, setEndMonth] = useState(12);
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadSettings = async () => {
+    try {
+      const data = await invoke<FiscalYearSettingsData>("get_fiscal_year");
+      setSettings(data);
+      setEndMonth(data.end_month);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadSettings();
+  }, []);
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      await invoke("set_fiscal_year_end", { endMonth });
+      setError(null);
+      await loadSettings();
+      onFiscalYearChange?.();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="flex h-10 w-10 items-center justify-center rounded-lg bg-primary/10">
+            <CalendarRange className="h-5 w-5 text-primary" />
+          </div>
+          <div>
+            <CardTitle>Fiscal Year</CardTitle>
+            <CardDescription>
+              The month your fiscal year ends in. Schedules and reports are laid out by fiscal year.
+            </CardDescription>
+          </div>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label>Fiscal Year End</Label>
+            <Select
+              value={String(endMonth)}
+              onValueChange={(value) => setEndMonth(Number(value))}
+            >
+              <SelectTrigger className="w-[180px]">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {months.map((month, index) => (
+                  <SelectItem key={month} value={String(index + 1)}>
+                    {month}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <Button
+            onClick={handleSave}
+            disabled={saving || endMonth === settings?.end_month}
+          >
+            {saving ? "Saving..." : "Save"}
+          </Button>
+          {settings && (
+            <p className="text-sm text-muted-foreground">
+              Current year: {formatFiscalYear(settings.current_year, settings.end_month)}
+            </p>
+          )}
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,509 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Plus, Pencil, Trash2, FileSignature, ExternalLink } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { DatePicker } from "@/components/ui/date-picker";
+import { Textarea } from "@/components/ui/textarea";
+import { FieldError } from "@/components/ui/field-error";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { validateLease } from "@/lib/validation";
+import { formatCurrency, formatDate, formatFiscalYear } from "@/lib/utils";
+import type { Lease, LeaseWithSchedule } from "@/types";
+
+interface LeasesProps {
+  currentYear: number;
+  fiscalYearEnd: number;
+  onLeasesChange: () => void;
+  onOpenAsset: (id: number) => void;
+}
+
+const newLease = (): Lease => ({
+  name: "",
+  commencement_date: new Date().toISOString().split("T")[0],
+  term_years: 5,
+  monthly_payment: 0,
+  payments_in_advance: false,
+  discount_rate: 5,
+});
+
+export function Leases({ currentYear, fiscalYearEnd, onLeasesChange, onOpenAsset }: LeasesProps) {
+  const [leases, setLeases] = useState<Lease[]>([]);
+  const [selected, setSelected] = useState<LeaseWithSchedule | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  // Dialog state
+  const [dialogOpen, setDialogOpen] = useState(false);
+  const [formData, setFormData] = useState<Lease>(newLease());
+  const [formErrors, setFormErrors] = useState<Record<string, string>>({});
+  const [saving, setSaving] = useState(false);
+  const [deleteDialogOpen, setDeleteDialogOpen] = useState(false);
+
+  const loadLeases = async () => {
+    try {
+      const data = await invoke<Lease[]>("get_leases");
+      setLeases(data);
+      setError(null);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const loadLease = async (id: number) => {
+    try {
+      const data = await invoke<LeaseWithSchedule>("get_lease", { id });
+      setSelected(data);
+      setError(null);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadLeases();
+  }, []);
+
+  const handleAdd = () => {
+    setFormData(newLease());
+    setFormErrors({});
+    setDialogOpen(true);
+  };
+
+  const handleEdit = () => {
+    if (!selected) return;
+    setFormData(selected.lease);
+    setFormErrors({});
+    setDialogOpen(true);
+  };
+
+  const handleSave = async () => {
+    const result = validateLease(formData);
+    if (!result.success) {
+      setFormErrors(result.errors);
+      return;
+    }
+
+    setSaving(true);
+    setFormErrors({});
+    try {
+      let id = formData.id;
+      if (id) {
+        await invoke("update_lease", { lease: result.data });
+      } else {
+        id = await invoke<number>("create_lease", { lease: result.data });
+      }
+
+      setDialogOpen(false);
+      await loadLeases();
+      await loadLease(id);
+      onLeasesChange();
+    } catch (err) {
+      setFormErrors({ form: String(err) });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!selected?.lease.id) return;
+    try {
+      await invoke("delete_lease", { id: selected.lease.id });
+      setDeleteDialogOpen(false);
+      setSelected(null);
+      await loadLeases();
+      onLeasesChange();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const handleFieldChange = <K extends keyof Lease>(field: K, value: Lease[K]) => {
+    if (formErrors[field]) {
+      setFormErrors((prev) => {
+        const next = { ...prev };
+        delete next[field];
+        return next;
+      });
+    }
+    setFormData({ ...formData, [field]: value });
+  };
+
+  const inputErrorClass = (field: string) =>
+    formErrors[field] ? "border-destructive focus-visible:ring-destructive" : "";
+
+  return (
+    <div className="space-y-6">
+      {/* Header */}
+      <div className="flex flex-col gap-4 sm:flex-row sm:items-center sm:justify-between">
+        <div className="min-w-0">
+          <h1 className="text-3xl font-bold tracking-tight">Leases</h1>
+          <p className="text-muted-foreground">
+            Right-of-use assets and lease liabilities under ASC 842 and IFRS 16
+          </p>
+        </div>
+        <Button onClick={handleAdd} className="gap-2 shrink-0">
+          <Plus className="h-4 w-4" />
+          Add Lease
+        </Button>
+      </div>
+
+      {error && <p className="text-sm text-destructive">{error}</p>}
+
+      <Card>
+        <CardContent className="p-0">
+          {leases.length === 0 ? (
+            <div className="text-center py-8 text-muted-foreground">
+              No leases yet. Click "Add Lease" to record one.
+            </div>
+          ) : (
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Lease</TableHead>
+                  <TableHead>Commencement</TableHead>
+                  <TableHead className="text-right">Term</TableHead>
+                  <TableHead className="text-right">Monthly Payment</TableHead>
+                  <TableHead className="text-right">Discount Rate</TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {leases.map((lease) => (
+                  <TableRow
+                    key={lease.id}
+                    className={`cursor-pointer ${
+                      selected?.lease.id === lease.id ? "bg-primary/5" : ""
+                    }`}
+                    onClick={() => lease.id && loadLease(lease.id)}
+                  >
+                    <TableCell className="font-medium">{lease.name}</TableCell>
+                    <TableCell>{formatDate(lease.commencement_date)}</TableCell>
+                    <TableCell className="text-right">{lease.term_years} years</TableCell>
+                    <TableCell className="text-right font-mono">
+                      {formatCurrency(lease.monthly_payment)}
+                    </TableCell>
+                    <TableCell className="text-right">{lease.discount_rate}%</TableCell>
+                  </TableRow>
+                ))}
+              </TableBody>
+            </Table>
+          )}
+        </CardContent>
+      </Card>
+
+      {selected && (
+        <>
+          <div className="flex items-center justify-between gap-3">
+            <div className="flex items-center gap-3">
+              <FileSignature className="h-5 w-5 text-muted-foreground" />
+              <h2 className="text-xl font-semibold">{selected.lease.name}</h2>
+            </div>
+            <div className="flex items-center gap-2">
+              <Button variant="outline" size="sm" className="gap-2" onClick={handleEdit}>
+                <Pencil className="h-4 w-4" />
+                Edit
+              </Button>
+              <Button
+                variant="outline"
+                size="sm"
+                className="gap-2 text-destructive hover:text-destructive"
+                onClick={() => setDeleteDialogOpen(true)}
+              >
+                <Trash2 className="h-4 w-4" />
+                Delete
+              </Button>
+            </div>
+          </div>
+
+          <div className="grid gap-6 lg:grid-cols-2">
+            <Card>
+              <CardHeader className="flex flex-row items-center justify-between space-y-0">
+                <div>
+                  <CardTitle>Right-of-Use Asset</CardTitle>
+                  <CardDescription>
+                    {formatCurrency(selected.asset.cost)} depreciated straight-line over the term
+                  </CardDescription>
+                </div>
+                {selected.asset.id && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="gap-2"
+                    onClick={() => onOpenAsset(selected.asset.id!)}
+                  >
+                    <ExternalLink className="h-4 w-4" />
+                    Open Asset
+                  </Button>
+                )}
+              </CardHeader>
+              <CardContent className="p-0">
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Year</TableHead>
+                      <TableHead className="text-right">Depreciation</TableHead>
+                      <TableHead className="text-right">Ending Value</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {selected.schedule.map((entry) => (
+                      <TableRow
+                        key={entry.year}
+                        className={entry.year === currentYear ? "bg-primary/5" : ""}
+                      >
+                        <TableCell className="font-medium">
+                          {formatFiscalYear(entry.year, fiscalYearEnd)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(entry.depreciation_expense)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(entry.ending_book_value)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              </CardContent>
+            </Card>
+
+            <Card>
+              <CardHeader>
+                <CardTitle>Lease Liability</CardTitle>
+                <CardDescription>
+                  {formatCurrency(selected.liability[0]?.beginning_balance ?? 0)} at commencement,
+                  amortized under the effective interest method
+                </CardDescription>
+              </CardHeader>
+              <CardContent className="p-0">
+                <Table>
+                  <TableHeader>
+                    <TableRow>
+                      <TableHead>Year</TableHead>
+                      <TableHead className="text-right">Interest</TableHead>
+                      <TableHead className="text-right">Payments</TableHead>
+                      <TableHead className="text-right">Ending Balance</TableHead>
+                    </TableRow>
+                  </TableHeader>
+                  <TableBody>
+                    {selected.liability.map((entry) => (
+                      <TableRow
+                        key={entry.year}
+                        className={entry.year === currentYear ? "bg-primary/5" : ""}
+                      >
+                        <TableCell className="font-medium">
+                          {formatFiscalYear(entry.year, fiscalYearEnd)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(entry.interest)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(entry.payments)}
+                        </TableCell>
+                        <TableCell className="text-right font-mono">
+                          {formatCurrency(entry.ending_balance)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              </CardContent>
+            </Card>
+          </div>
+        </>
+      )}
+
+      {/* Add/Edit Dialog */}
+      <Dialog open={dialogOpen} onOpenChange={setDialogOpen}>
+        <DialogContent className="max-w-2xl">
+          <DialogHeader>
+            <DialogTitle>{formData.id ? "Edit Lease" : "Add Lease"}</DialogTitle>
+            <DialogDescription>
+              {formData.id
+                ? "Changing the terms remeasures the right-of-use asset and the liability."
+                : "The right-of-use asset is added to the asset register."}
+            </DialogDescription>
+          </DialogHeader>
+          <div className="space-y-4 py-4">
+            <div className="grid gap-4 sm:grid-cols-2">
+              <div className="space-y-2">
+                <Label htmlFor="lease_name">
+                  Name <span className="text-destructive">*</span>
+                </Label>
+                <Input
+                  id="lease_name"
+                  value={formData.name}
+                  onChange={(e) => handleFieldChange("name", e.target.value)}
+                  placeholder="e.g., Warehouse lease"
+                  className={inputErrorClass("name")}
+                />
+                <FieldError error={formErrors.name} />
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="commencement_date">
+                  Commencement Date <span className="text-destructive">*</span>
+                </Label>
+                <DatePicker
+                  id="commencement_date"
+                  value={formData.commencement_date}
+                  onChange={(value) => handleFieldChange("commencement_date", value)}
+                  className={inputErrorClass("commencement_date")}
+                />
+                <FieldError error={formErrors.commencement_date} />
+              </div>
+            </div>
+
+            <div className="grid gap-4 sm:grid-cols-3">
+              <div className="space-y-2">
+                <Label htmlFor="term_years">
+                  Term (Years) <span className="text-destructive">*</span>
+                </Label>
+                <NumberInput
+                  id="term_years"
+                  step={1}
+                  min={1}
+                  value={formData.term_years}
+                  onChange={(value) => handleFieldChange("term_years", value ?? 1)}
+                  className={inputErrorClass("term_years")}
+                />
+                <FieldError error={formErrors.term_years} />
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="monthly_payment">
+                  Monthly Payment <span className="text-destructive">*</span>
+                </Label>
+                <NumberInput
+                  id="monthly_payment"
+                  step={0.01}
+                  min={0}
+                  value={formData.monthly_payment}
+                  onChange={(value) => handleFieldChange("monthly_payment", value ?? 0)}
+                  className={inputErrorClass("monthly_payment")}
+                />
+                <FieldError error={formErrors.monthly_payment} />
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="discount_rate">
+                  Discount Rate % <span className="text-destructive">*</span>
+                </Label>
+                <NumberInput
+                  id="discount_rate"
+                  step={0.01}
+                  min={0}
+                  max={100}
+                  value={formData.discount_rate}
+                  onChange={(value) => handleFieldChange("discount_rate", value ?? 0)}
+                  className={inputErrorClass("discount_rate")}
+                />
+                <FieldError error={formErrors.discount_rate} />
+              </div>
+            </div>
+
+            <div className="grid gap-4 sm:grid-cols-2">
+              <div className="space-y-2">
+                <Label htmlFor="payments_in_advance">Payment Timing</Label>
+                <Select
+                  value={formData.payments_in_advance ? "advance" : "arrears"}
+                  onValueChange={(value) =>
+                    handleFieldChange("payments_in_advance", value === "advance")
+                  }
+                >
+                  <SelectTrigger id="payments_in_advance">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="arrears">End of each month</SelectItem>
+                    <SelectItem value="advance">Start of each month</SelectItem>
+                  </SelectContent>
+                </Select>
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="initial_direct_costs">Initial Direct Costs</Label>
+                <NumberInput
+                  id="initial_direct_costs"
+                  step={0.01}
+                  min={0}
+                  allowEmpty
+                  value={formData.initial_direct_costs ?? ""}
+                  onChange={(value) => handleFieldChange("initial_direct_costs", value)}
+                  className={inputErrorClass("initial_direct_costs")}
+                />
+                <FieldError error={formErrors.initial_direct_costs} />
+              </div>
+            </div>
+            <p className="text-xs text-muted-foreground">
+              Use the rate implicit in the lease, or your incremental borrowing rate if it can't be
+              determined
+            </p>
+
+            <div className="space-y-2">
+              <Label htmlFor="lease_notes">Notes</Label>
+              <Textarea
+                id="lease_notes"
+                value={formData.notes ?? ""}
+                onChange={(e) => handleFieldChange("notes", e.target.value)}
+                className={inputErrorClass("notes")}
+              />
+              <FieldError error={formErrors.notes} />
+            </div>
+
+            <FieldError error={formErrors.form} />
+          </div>
+          <DialogFooter>
+            <Button variant="outline" onClick={() => setDialogOpen(false)}>
+              Cancel
+            </Button>
+            <Button onClick={handleSave} disabled={saving}>
+              {saving ? "Saving..." : formData.id ? "Save Changes" : "Add Lease"}
+            </Button>
+          </DialogFooter>
+        </DialogContent>
+      </Dialog>
+
+      {/* Delete Confirmation Dialog */}
+      <Dialog open={deleteDialogOpen} onOpenChange={setDeleteDialogOpen}>
+        <DialogContent>
+          <DialogHeader>
+            <DialogTitle>Delete Lease</DialogTitle>
+            <DialogDescription>
+              Are you sure you want to delete "{selected?.lease.name}"? Its right-of-use asset and
+              depreciation schedule are deleted with it.
+            </DialogDescription>
+          </DialogHeader>
+          <DialogFooter>
+            <Button variant="outline" onClick={() => setDeleteDialogOpen(false)}>
+              Cancel
+            </Button>
+            <Button variant="destructive" onClick={handleDelete}>
+              Delete Lease
+            </Button>
+          </DialogFooter>
+        </DialogContent>
+      </Dialog>
+    </div>
+  );
+}
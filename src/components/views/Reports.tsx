@@ -10,12 +10,26 @@ import {
   TableHeader,
   TableRow,
 } from "@/components/ui/table";
-import { formatCurrency } from "@/lib/utils";
+import { Section179Report } from "@/components/reports/Section179Report";
+import { BookTaxReport } from "@/components/reports/BookTaxReport";
+import { PeriodSummaryReport } from "@/components/reports/PeriodSummaryReport";
+import { DepreciationRangeReport } from "@/components/reports/DepreciationRangeReport";
+import { DisposalReport } from "@/components/reports/DisposalReport";
+import { RecaptureReport } from "@/components/reports/RecaptureReport";
+import { ListedPropertyReport } from "@/components/reports/ListedPropertyReport";
+import { ImpairmentReport } from "@/components/reports/ImpairmentReport";
+import { RevaluationReserveReport } from "@/components/reports/RevaluationReserveReport";
+import { LeaseReport } from "@/components/reports/LeaseReport";
+import { BookSelect } from "@/components/BookSelect";
+import { formatCurrency, formatFiscalYear } from "@/lib/utils";
 import type { AnnualSummary } from "@/types";
 
 interface ReportsProps {
   annualSummary: AnnualSummary[];
   currentYear: number;
+  fiscalYearEnd: number;
+  book: string;
+  onBookChange: (book: string) => void;
   onExportReport: () => void;
   onExportTemplate: () => void;
 }
@@ -23,6 +37,9 @@ interface ReportsProps {
 export function Reports({
   annualSummary,
   currentYear,
+  fiscalYearEnd,
+  book,
+  onBookChange,
   onExportReport,
   onExportTemplate,
 }: ReportsProps) {
@@ -37,6 +54,7 @@ export function Reports({
           </p>
         </div>
         <div className="flex gap-2 shrink-0">
+          <BookSelect value={book} onChange={onBookChange} />
           <Button variant="outline" onClick={onExportTemplate} className="gap-2">
             <FileDown className="h-4 w-4" />
             Import Template
@@ -72,6 +90,7 @@ export function Reports({
                   <TableRow>
                     <TableHead>Year</TableHead>
                     <TableHead className="text-right">Total Depreciation</TableHead>
+                    <TableHead className="text-right">Total Amortization</TableHead>
                     <TableHead className="text-right">Number of Assets</TableHead>
                   </TableRow>
                 </TableHeader>
@@ -84,7 +103,7 @@ export function Reports({
                       }
                     >
                       <TableCell className="font-medium">
-                        {row.year}
+                        {formatFiscalYear(row.year, fiscalYearEnd)}
                         {row.year === currentYear && (
                           <Badge variant="default" className="ml-2">
                             Current
@@ -94,6 +113,9 @@ export function Reports({
                       <TableCell className="text-right font-mono">
                         {formatCurrency(row.total_depreciation)}
                       </TableCell>
+                      <TableCell className="text-right font-mono">
+                        {formatCurrency(row.total_amortization)}
+                      </TableCell>
                       <TableCell className="text-right">{row.asset_count}</TableCell>
                     </TableRow>
                   ))}
@@ -103,6 +125,30 @@ export function Reports({
           </Card>
         )}
       </div>
+
+      <PeriodSummaryReport
+        currentYear={currentYear}
+        fiscalYearEnd={fiscalYearEnd}
+        book={book}
+      />
+
+      <DepreciationRangeReport currentYear={currentYear} book={book} />
+
+      <Section179Report currentYear={currentYear} />
+
+      <BookTaxReport currentYear={currentYear} />
+
+      <DisposalReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
+
+      <RecaptureReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
+
+      <ListedPropertyReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
+
+      <ImpairmentReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
+
+      <RevaluationReserveReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
+
+      <LeaseReport currentYear={currentYear} fiscalYearEnd={fiscalYearEnd} />
     </div>
   );
 }
@@ -4,6 +4,8 @@ import { Button } from "@/components/ui/button";
 import { Separator } from "@/components/ui/separator";
 import { cn } from "@/lib/utils";
 import { CategoryManagement } from "./CategoryManagement";
+import { FiscalYearSettings } from "./FiscalYearSettings";
+import { TaxSettings } from "./TaxSettings";
 
 type Theme = "light" | "dark" | "system";
 
@@ -13,13 +15,21 @@ interface SettingsProps {
   theme: Theme;
   onThemeChange: (theme: Theme) => void;
   onCategoriesChange?: () => void;
+  onFiscalYearChange?: () => void;
 }
 
 const MIN_SCALE = 0.75;
 const MAX_SCALE = 2;
 const SCALE_INCREMENT = 0.1;
 
-export function Settings({ scale, onScaleChange, theme, onThemeChange, onCategoriesChange }: SettingsProps) {
+export function Settings({
+  scale,
+  onScaleChange,
+  theme,
+  onThemeChange,
+  onCategoriesChange,
+  onFiscalYearChange,
+}: SettingsProps) {
   const handleZoomIn = () => {
     const newScale = Math.min(MAX_SCALE, Math.round((scale + SCALE_INCREMENT) * 100) / 100);
     onScaleChange(newScale);
@@ -205,6 +215,12 @@ export function Settings({ scale, onScaleChange, theme, onThemeChange, onCategor
       {/* Categories */}
       <CategoryManagement onCategoriesChange={onCategoriesChange} />
 
+      {/* Fiscal Year */}
+      <FiscalYearSettings onFiscalYearChange={onFiscalYearChange} />
+
+      {/* Tax */}
+      <TaxSettings />
+
       {/* About */}
       <Card>
         <CardHeader>
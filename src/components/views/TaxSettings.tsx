@@ -0,0 +1,307 @@
+import { useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Landmark, Trash2 } from "lucide-react";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Button } from "@/components/ui/button";
+import { NumberInput } from "@/components/ui/number-input";
+import { Label } from "@/components/ui/label";
+import { Separator } from "@/components/ui/separator";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table";
+import { formatCurrency } from "@/lib/utils";
+import type { BonusOptOut, Section179Limit } from "@/types";
+
+// Bonus depreciation applies to MACRS property with a recovery period of 20 years or less
+const bonusPropertyClasses = ["3", "5", "7", "10", "15", "20"];
+
+export function TaxSettings() {
+  const [limits, setLimits] = useState<Section179Limit[]>([]);
+  const [form, setForm] = useState<Partial<Section179Limit>>({});
+  const [optOuts, setOptOuts] = useState<BonusOptOut[]>([]);
+  const [optOutYear, setOptOutYear] = useState<number | undefined>(new Date().getFullYear());
+  const [optOutClass, setOptOutClass] = useState("5");
+  const [taxRate, setTaxRate] = useState<number | undefined>();
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+
+  const loadTaxRate = async () => {
+    try {
+      const rate = await invoke<number>("get_deferred_tax_rate");
+      setTaxRate(rate);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const handleSaveTaxRate = async () => {
+    if (taxRate === undefined) {
+      setError("Tax rate is required");
+      return;
+    }
+    try {
+      await invoke("set_deferred_tax_rate", { rate: taxRate });
+      setError(null);
+      await loadTaxRate();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const loadOptOuts = async () => {
+    try {
+      const data = await invoke<BonusOptOut[]>("get_bonus_opt_outs");
+      setOptOuts(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const handleOptOut = async (year: number, propertyClass: string, optedOut: boolean) => {
+    try {
+      await invoke("set_bonus_opt_out", { year, propertyClass, optedOut });
+      setError(null);
+      await loadOptOuts();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  const loadLimits = async () => {
+    try {
+      const data = await invoke<Section179Limit[]>("get_section_179_limits");
+      setLimits(data);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  useEffect(() => {
+    loadLimits();
+    loadOptOuts();
+    loadTaxRate();
+  }, []);
+
+  const handleSave = async () => {
+    if (
+      form.year === undefined ||
+      form.dollar_limit === undefined ||
+      form.phase_out_threshold === undefined
+    ) {
+      setError("Year, dollar limit and phase-out threshold are required");
+      return;
+    }
+    setSaving(true);
+    try {
+      await invoke("update_section_179_limit", { limit: form });
+      setForm({});
+      setError(null);
+      await loadLimits();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="flex h-10 w-10 items-center justify-center rounded-lg bg-primary/10">
+            <Landmark className="h-5 w-5 text-primary" />
+          </div>
+          <div>
+            <CardTitle>Tax Settings</CardTitle>
+            <CardDescription>
+              Section 179 limits, bonus depreciation elections and the deferred tax rate
+            </CardDescription>
+          </div>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="limit_year">Tax Year</Label>
+            <NumberInput
+              id="limit_year"
+              step={1}
+              allowEmpty
+              value={form.year ?? ""}
+              onChange={(value) => setForm((prev) => ({ ...prev, year: value }))}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="dollar_limit">Dollar Limit</Label>
+            <NumberInput
+              id="dollar_limit"
+              step={1000}
+              min={0}
+              allowEmpty
+              prefix="$"
+              value={form.dollar_limit ?? ""}
+              onChange={(value) => setForm((prev) => ({ ...prev, dollar_limit: value }))}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="phase_out_threshold">Phase-out Threshold</Label>
+            <NumberInput
+              id="phase_out_threshold"
+              step={1000}
+              min={0}
+              allowEmpty
+              prefix="$"
+              value={form.phase_out_threshold ?? ""}
+              onChange={(value) => setForm((prev) => ({ ...prev, phase_out_threshold: value }))}
+            />
+          </div>
+          <Button onClick={handleSave} disabled={saving}>
+            {saving ? "Saving..." : "Save Limit"}
+          </Button>
+        </div>
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        <Table>
+          <TableHeader>
+            <TableRow>
+              <TableHead>Tax Year</TableHead>
+              <TableHead className="text-right">Dollar Limit</TableHead>
+              <TableHead className="text-right">Phase-out Threshold</TableHead>
+            </TableRow>
+          </TableHeader>
+          <TableBody>
+            {limits.map((limit) => (
+              <TableRow
+                key={limit.year}
+                className="cursor-pointer"
+                onClick={() => setForm(limit)}
+              >
+                <TableCell className="font-medium">{limit.year}</TableCell>
+                <TableCell className="text-right font-mono">
+                  {formatCurrency(limit.dollar_limit)}
+                </TableCell>
+                <TableCell className="text-right font-mono">
+                  {formatCurrency(limit.phase_out_threshold)}
+                </TableCell>
+              </TableRow>
+            ))}
+          </TableBody>
+        </Table>
+
+        <Separator />
+
+        <div className="space-y-4">
+          <div>
+            <div className="font-medium">Bonus Depreciation Elections</div>
+            <p className="text-sm text-muted-foreground">
+              Elect a property class out of bonus depreciation for a tax year in the tax books
+            </p>
+          </div>
+          <div className="flex flex-wrap items-end gap-4">
+            <div className="space-y-2">
+              <Label htmlFor="opt_out_year">Tax Year</Label>
+              <NumberInput
+                id="opt_out_year"
+                step={1}
+                allowEmpty
+                value={optOutYear ?? ""}
+                onChange={setOptOutYear}
+              />
+            </div>
+            <div className="space-y-2">
+              <Label htmlFor="opt_out_class">Property Class</Label>
+              <Select value={optOutClass} onValueChange={setOptOutClass}>
+                <SelectTrigger className="w-32">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {bonusPropertyClasses.map((cls) => (
+                    <SelectItem key={cls} value={cls}>
+                      {cls}-year
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <Button
+              variant="outline"
+              disabled={optOutYear === undefined}
+              onClick={() => optOutYear !== undefined && handleOptOut(optOutYear, optOutClass, true)}
+            >
+              Elect Out
+            </Button>
+          </div>
+
+          {optOuts.length > 0 && (
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Tax Year</TableHead>
+                  <TableHead>Property Class</TableHead>
+                  <TableHead></TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {optOuts.map((optOut) => (
+                  <TableRow key={`${optOut.year}-${optOut.property_class}`}>
+                    <TableCell className="font-medium">{optOut.year}</TableCell>
+                    <TableCell>{optOut.property_class}-year</TableCell>
+                    <TableCell className="text-right">
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        onClick={() => handleOptOut(optOut.year, optOut.property_class, false)}
+                      >
+                        <Trash2 className="h-4 w-4" />
+                      </Button>
+                    </TableCell>
+                  </TableRow>
+                ))}
+              </TableBody>
+            </Table>
+          )}
+        </div>
+
+        <Separator />
+
+        <div className="space-y-4">
+          <div>
+            <div className="font-medium">Deferred Tax Rate</div>
+            <p className="text-sm text-muted-foreground">
+              Applied to book-to-tax differences in the deferred tax report
+            </p>
+          </div>
+          <div className="flex flex-wrap items-end gap-4">
+            <div className="space-y-2">
+              <Label htmlFor="deferred_tax_rate">Tax Rate %</Label>
+              <NumberInput
+                id="deferred_tax_rate"
+                step={0.5}
+                min={0}
+                max={100}
+                allowEmpty
+                value={taxRate ?? ""}
+                onChange={setTaxRate}
+              />
+            </div>
+            <Button variant="outline" onClick={handleSaveTaxRate}>
+              Save Rate
+            </Button>
+          </div>
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
@@ -1,6 +1,7 @@
 export * from "./Dashboard";
 export * from "./AssetList";
 export * from "./AssetDetail";
+export * from "./Leases";
 export * from "./Analysis";
 export * from "./Reports";
 export * from "./Manual";
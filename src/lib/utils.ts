@@ -1,5 +1,6 @@
 import { type ClassValue, clsx } from "clsx";
 import { twMerge } from "tailwind-merge";
+import type { AssetWithSchedule } from "@/types";
 
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs));
@@ -25,3 +26,21 @@ export function formatDate(dateString: string): string {
 export function formatNumber(value: number): string {
   return new Intl.NumberFormat("en-US").format(value);
 }
+
+// Fiscal years are numbered by the calendar year they end in
+export function formatFiscalYear(year: number, fiscalYearEnd: number): string {
+  return fiscalYearEnd === 12 ? String(year) : `FY${year}`;
+}
+
+// Cost of an asset together with its capital improvements
+export function rolledUpCost(item: AssetWithSchedule): number {
+  return item.improvements.reduce((total, improvement) => total + improvement.cost, item.asset.cost);
+}
+
+export function formatMonth(dateString: string): string {
+  const [year, month] = dateString.split("-").map(Number);
+  return new Date(year, month - 1, 1).toLocaleDateString("en-US", {
+    year: "numeric",
+    month: "short",
+  });
+}
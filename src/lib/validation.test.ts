@@ -6,6 +6,7 @@ import {
   validateAsset,
   validateCategory,
   validateDispose,
+  validateLease,
 } from "./validation";
 import type { Asset, Category } from "@/types";
 
@@ -30,6 +31,7 @@ function validCategory(): Category {
     name: "Equipment",
     default_useful_life: 5,
     default_property_class: "5",
+    revaluation_model: false,
   };
 }
 
@@ -301,6 +303,21 @@ describe("AssetSchema", () => {
     });
   });
 
+  describe("quantity", () => {
+    test("accepts a whole quantity", () => {
+      const asset = { ...validAsset(), quantity: 10 };
+      const result = AssetSchema.safeParse(asset);
+      expect(result.success).toBe(true);
+    });
+
+    test("rejects zero and fractional quantities", () => {
+      for (const quantity of [0, 2.5]) {
+        const result = AssetSchema.safeParse({ ...validAsset(), quantity });
+        expect(result.success).toBe(false);
+      }
+    });
+  });
+
   describe("disposal fields", () => {
     test("rejects invalid disposed_date format", () => {
       const asset = { ...validAsset(), disposed_date: "invalid" };
@@ -332,6 +349,15 @@ describe("AssetSchema", () => {
       expect(result.success).toBe(true);
     });
 
+    test("rejects negative selling_costs", () => {
+      const asset = { ...validAsset(), disposed_date: "2024-06-01", selling_costs: -50 };
+      const result = AssetSchema.safeParse(asset);
+      expect(result.success).toBe(false);
+      if (!result.success) {
+        expect(result.error.issues.some((i) => i.message.includes("Selling costs"))).toBe(true);
+      }
+    });
+
     test("accepts null disposed_date", () => {
       const asset = { ...validAsset(), disposed_date: null };
       const result = AssetSchema.safeParse(asset);
@@ -375,6 +401,37 @@ describe("AssetSchema", () => {
       const result = AssetSchema.safeParse(asset);
       expect(result.success).toBe(true);
     });
+
+    test("business use of 50% or less requires ADS", () => {
+      const asset = { ...validAsset(), method: "macrs_gds", business_use_percentage: 40 };
+      const result = AssetSchema.safeParse(asset);
+      expect(result.success).toBe(false);
+      if (!result.success) {
+        expect(
+          result.error.issues.some((i) => i.path.includes("business_use_percentage"))
+        ).toBe(true);
+      }
+
+      const ads = AssetSchema.safeParse({ ...asset, method: "macrs_ads" });
+      expect(ads.success).toBe(true);
+    });
+
+    test("intangibles are amortized straight-line to zero", () => {
+      const asset = {
+        ...validAsset(),
+        asset_kind: "intangible",
+        method: "macrs_gds",
+        salvage_value: 0,
+      };
+      const result = AssetSchema.safeParse(asset);
+      expect(result.success).toBe(false);
+      if (!result.success) {
+        expect(result.error.issues.some((i) => i.path.includes("method"))).toBe(true);
+      }
+
+      const amortized = AssetSchema.safeParse({ ...asset, method: "straight_line" });
+      expect(amortized.success).toBe(true);
+    });
   });
 
   describe("valid asset", () => {
@@ -675,3 +732,34 @@ describe("validateDispose", () => {
     }
   });
 });
+
+describe("validateLease", () => {
+  const validLease = () => ({
+    name: "Office",
+    commencement_date: "2024-01-01",
+    term_years: 5,
+    monthly_payment: 2500,
+    payments_in_advance: true,
+    discount_rate: 5,
+  });
+
+  test("returns success with valid data", () => {
+    const result = validateLease(validLease());
+    expect(result.success).toBe(true);
+  });
+
+  test("returns field-specific errors", () => {
+    const result = validateLease({
+      ...validLease(),
+      term_years: 0,
+      monthly_payment: 0,
+      discount_rate: 120,
+    });
+    expect(result.success).toBe(false);
+    if (!result.success) {
+      expect(result.errors).toHaveProperty("term_years");
+      expect(result.errors).toHaveProperty("monthly_payment");
+      expect(result.errors).toHaveProperty("discount_rate");
+    }
+  });
+});
@@ -1,9 +1,17 @@
 import { z } from "zod";
-import type { Asset, Category } from "@/types";
+import type { Asset, Category, Lease } from "@/types";
 
 // Valid property classes
 const PROPERTY_CLASSES = ["3", "5", "7", "10", "15", "20", "27.5", "39"] as const;
 
+// Property classes depreciated as real property, which doesn't qualify for Section 179
+const REAL_PROPERTY_CLASSES = ["27.5", "39"];
+
+// MACRS under either the general or the alternative depreciation system
+function isMacrs(method: string | null | undefined): boolean {
+  return method === "macrs_gds" || method === "macrs_ads";
+}
+
 // Date format regex (YYYY-MM-DD)
 const DATE_REGEX = /^\d{4}-\d{2}-\d{2}$/;
 
@@ -59,6 +67,36 @@ export const AssetSchema = z
       })
       .optional()
       .nullable(),
+    method: z.string().optional().nullable(),
+    total_units: z
+      .number()
+      .gt(0, "Total units must be greater than 0")
+      .optional()
+      .nullable(),
+    section_179_amount: z
+      .number()
+      .min(0, "Section 179 amount cannot be negative")
+      .optional()
+      .nullable(),
+    bonus_percentage: z
+      .number()
+      .min(0, "Bonus percentage must be between 0 and 100")
+      .max(100, "Bonus percentage must be between 0 and 100")
+      .optional()
+      .nullable(),
+    business_use_percentage: z
+      .number()
+      .min(0, "Business use percentage must be between 0 and 100")
+      .max(100, "Business use percentage must be between 0 and 100")
+      .optional()
+      .nullable(),
+    asset_kind: z.string().optional().nullable(),
+    quantity: z
+      .number()
+      .int("Quantity must be a whole number")
+      .min(1, "Quantity must be at least 1")
+      .optional()
+      .nullable(),
     notes: z
       .string()
       .max(2000, "Notes must be 2000 characters or less")
@@ -76,11 +114,75 @@ export const AssetSchema = z
       .min(0, "Disposal value cannot be negative")
       .optional()
       .nullable(),
+    selling_costs: z
+      .number()
+      .min(0, "Selling costs cannot be negative")
+      .optional()
+      .nullable(),
+    proceeds_type: z.string().optional().nullable(),
   })
   .refine((data) => data.salvage_value <= data.cost, {
     message: "Salvage value cannot exceed cost",
     path: ["salvage_value"],
   })
+  .refine(
+    (data) =>
+      isMacrs(data.method) ||
+      (data.section_179_amount ?? 0) <= data.cost - data.salvage_value,
+    {
+      message: "Section 179 amount cannot exceed cost less salvage value",
+      path: ["section_179_amount"],
+    }
+  )
+  .refine((data) => !isMacrs(data.method) || (data.section_179_amount ?? 0) <= data.cost, {
+    message: "Section 179 amount cannot exceed cost",
+    path: ["section_179_amount"],
+  })
+  .refine(
+    (data) =>
+      !(data.section_179_amount ?? 0) ||
+      !REAL_PROPERTY_CLASSES.includes(data.property_class?.trim() ?? ""),
+    {
+      message: "Residential rental and nonresidential real property don't qualify for Section 179",
+      path: ["section_179_amount"],
+    }
+  )
+  .refine(
+    (data) =>
+      !(data.section_179_amount ?? 0) ||
+      data.business_use_percentage == null ||
+      data.business_use_percentage > 50,
+    {
+      message: "Listed property used 50% or less for business doesn't qualify for Section 179",
+      path: ["section_179_amount"],
+    }
+  )
+  .refine(
+    (data) =>
+      data.method !== "macrs_gds" ||
+      data.business_use_percentage == null ||
+      data.business_use_percentage > 50,
+    {
+      message: "Listed property used 50% or less for business must be depreciated under MACRS ADS",
+      path: ["business_use_percentage"],
+    }
+  )
+  .refine(
+    (data) =>
+      data.asset_kind !== "intangible" || !data.method || data.method === "straight_line",
+    {
+      message: "Intangibles are amortized straight-line",
+      path: ["method"],
+    }
+  )
+  .refine((data) => data.asset_kind !== "intangible" || data.salvage_value === 0, {
+    message: "Intangibles are amortized to zero and cannot have a salvage value",
+    path: ["salvage_value"],
+  })
+  .refine((data) => data.method !== "units_of_production" || data.total_units != null, {
+    message: "Units-of-production depreciation requires total expected units",
+    path: ["total_units"],
+  })
   .refine(
     (data) => {
       if (!data.disposed_date) return true;
@@ -115,6 +217,45 @@ export const CategorySchema = z.object({
     })
     .optional()
     .nullable(),
+  default_method: z.string().optional().nullable(),
+  revaluation_model: z.boolean(),
+});
+
+// Lease validation schema
+export const LeaseSchema = z.object({
+  id: z.number().optional(),
+  asset_id: z.number().optional(),
+  name: z
+    .string()
+    .transform((s) => s.trim())
+    .refine((s) => s.length > 0, "Lease name is required")
+    .refine((s) => s.length <= 200, "Lease name must be 200 characters or less"),
+  commencement_date: z
+    .string()
+    .min(1, "Commencement date is required")
+    .refine(isValidDate, "Invalid date format (use YYYY-MM-DD)")
+    .refine(isNotFutureDate, "Commencement date cannot be in the future"),
+  term_years: z
+    .number()
+    .int("Lease term must be a whole number of years")
+    .min(1, "Lease term must be at least 1 year"),
+  monthly_payment: z.number().gt(0, "Monthly payment must be greater than $0"),
+  payments_in_advance: z.boolean().optional(),
+  discount_rate: z
+    .number()
+    .min(0, "Discount rate must be between 0 and 100")
+    .max(100, "Discount rate must be between 0 and 100"),
+  initial_direct_costs: z
+    .number()
+    .min(0, "Initial direct costs cannot be negative")
+    .optional()
+    .nullable(),
+  notes: z
+    .string()
+    .max(2000, "Notes must be 2000 characters or less")
+    .optional()
+    .nullable()
+    .transform((s) => (s?.trim() || undefined)),
 });
 
 // Dispose validation schema (for the dispose dialog)
@@ -177,6 +318,14 @@ export function validateCategory(category: Category): ValidationResult<Category>
   return { success: false, errors: zodErrorsToMap(result.error) };
 }
 
+export function validateLease(lease: Lease): ValidationResult<Lease> {
+  const result = LeaseSchema.safeParse(lease);
+  if (result.success) {
+    return { success: true, data: result.data as Lease };
+  }
+  return { success: false, errors: zodErrorsToMap(result.error) };
+}
+
 export function validateDispose(
   disposedDate: string,
   disposedValue: number | undefined | null,
@@ -3,6 +3,8 @@ export interface Category {
   name: string;
   default_useful_life?: number;
   default_property_class?: string;
+  default_method?: string;
+  revaluation_model: boolean;
   created_at?: string;
   updated_at?: string;
 }
@@ -12,6 +14,8 @@ export interface CategoryWithCount {
   name: string;
   default_useful_life?: number;
   default_property_class?: string;
+  default_method?: string;
+  revaluation_model: boolean;
   created_at?: string;
   updated_at?: string;
   asset_count: number;
@@ -27,9 +31,22 @@ export interface Asset {
   salvage_value: number;
   useful_life_years: number;
   property_class?: string;
+  method?: string;
+  convention?: string;
+  total_units?: number;
+  section_179_amount?: number;
+  bonus_percentage?: number;
+  business_use_percentage?: number;
+  passenger_auto?: boolean;
+  asset_kind?: string;
   notes?: string;
+  quantity?: number;
   disposed_date?: string;
   disposed_value?: number;
+  selling_costs?: number;
+  proceeds_type?: string;
+  parent_asset_id?: number;
+  improved_asset_id?: number;
   created_at?: string;
   updated_at?: string;
 }
@@ -40,14 +57,306 @@ export interface DepreciationEntry {
   year: number;
   beginning_book_value: number;
   depreciation_expense: number;
+  impairment_loss: number;
+  revaluation_adjustment: number;
   accumulated_depreciation: number;
   ending_book_value: number;
 }
 
+export interface AssetBook {
+  asset_id: number;
+  book: string;
+  method?: string;
+  convention?: string;
+  useful_life_years?: number;
+  property_class?: string;
+  section_179_amount?: number;
+  bonus_percentage?: number;
+}
+
+export interface UsageEntry {
+  id?: number;
+  asset_id: number;
+  year: number;
+  units: number;
+  notes?: string;
+  created_at?: string;
+}
+
+export interface BusinessUseEntry {
+  id?: number;
+  asset_id: number;
+  year: number;
+  percentage: number;
+  notes?: string;
+  created_at?: string;
+}
+
+export interface EstimateChange {
+  id?: number;
+  asset_id: number;
+  effective_date: string;
+  useful_life_years: number;
+  salvage_value: number;
+  previous_useful_life_years?: number;
+  previous_salvage_value?: number;
+  reason?: string;
+  created_at?: string;
+}
+
+export interface Impairment {
+  id?: number;
+  asset_id: number;
+  impairment_date: string;
+  amount: number;
+  reason?: string;
+  created_at?: string;
+}
+
+export interface Revaluation {
+  id?: number;
+  asset_id: number;
+  revaluation_date: string;
+  fair_value: number;
+  reason?: string;
+  created_at?: string;
+  carrying_amount?: number;
+  reserve_change?: number;
+  profit_loss?: number;
+}
+
+export interface AssetComponent {
+  id?: number;
+  asset_id: number;
+  name: string;
+  cost: number;
+  salvage_value: number;
+  useful_life_years: number;
+  method?: string;
+  convention?: string;
+}
+
+export interface ComponentWithSchedule {
+  component: AssetComponent;
+  schedule: DepreciationEntry[];
+}
+
+export interface PartialDisposal {
+  asset_id: number;
+  disposed_date: string;
+  quantity?: number;
+  percentage?: number;
+  disposed_value?: number;
+  selling_costs?: number;
+  proceeds_type?: string;
+}
+
+export interface Disposal {
+  asset_id: number;
+  name: string;
+  disposed_date: string;
+  proceeds_type: string;
+  cost: number;
+  accumulated_depreciation: number;
+  book_value: number;
+  proceeds: number;
+  selling_costs: number;
+  gain_loss: number;
+}
+
+export interface DisposalReport {
+  year: number;
+  total_proceeds: number;
+  total_book_value: number;
+  total_gain_loss: number;
+  disposals: Disposal[];
+}
+
+export interface ImpairmentReportEntry {
+  asset_id: number;
+  name: string;
+  impairment_date: string;
+  amount: number;
+  carrying_amount: number;
+  reason?: string;
+}
+
+export interface ImpairmentReport {
+  year: number;
+  total_amount: number;
+  impairments: ImpairmentReportEntry[];
+}
+
+export interface RevaluationReserveEntry {
+  asset_id: number;
+  name: string;
+  opening_balance: number;
+  increase: number;
+  decrease: number;
+  transferred: number;
+  closing_balance: number;
+  profit_loss: number;
+}
+
+export interface RevaluationReserveReport {
+  year: number;
+  total_opening_balance: number;
+  total_closing_balance: number;
+  total_profit_loss: number;
+  entries: RevaluationReserveEntry[];
+}
+
+export interface Recapture {
+  asset_id: number;
+  name: string;
+  disposed_date: string;
+  section: string;
+  amount_realized: number;
+  tax_basis: number;
+  accumulated_depreciation: number;
+  gain_loss: number;
+  ordinary_income: number;
+  unrecaptured_1250: number;
+  capital_gain: number;
+}
+
+export interface RecaptureReport {
+  year: number;
+  tax_book: string;
+  total_gain_loss: number;
+  total_ordinary_income: number;
+  total_unrecaptured_1250: number;
+  total_capital_gain: number;
+  recaptures: Recapture[];
+}
+
+export interface ListedPropertyRecapture {
+  asset_id: number;
+  name: string;
+  placed_in_service_use: number;
+  business_use: number;
+  depreciation_taken: number;
+  ads_depreciation: number;
+  recapture: number;
+}
+
+export interface ListedPropertyReport {
+  year: number;
+  tax_book: string;
+  total_recapture: number;
+  assets: ListedPropertyRecapture[];
+}
+
+export interface Lease {
+  id?: number;
+  asset_id?: number;
+  name: string;
+  commencement_date: string;
+  term_years: number;
+  monthly_payment: number;
+  payments_in_advance?: boolean;
+  discount_rate: number;
+  initial_direct_costs?: number;
+  notes?: string;
+  created_at?: string;
+  updated_at?: string;
+}
+
+export interface LeaseLiabilityEntry {
+  year: number;
+  beginning_balance: number;
+  interest: number;
+  payments: number;
+  ending_balance: number;
+}
+
+export interface LeaseWithSchedule {
+  lease: Lease;
+  asset: Asset;
+  schedule: DepreciationEntry[];
+  liability: LeaseLiabilityEntry[];
+}
+
+export interface LeaseReportEntry {
+  lease_id: number;
+  asset_id: number;
+  name: string;
+  asset_depreciation: number;
+  asset_book_value: number;
+  interest: number;
+  payments: number;
+  liability: number;
+  current_liability: number;
+}
+
+export interface LeaseReport {
+  year: number;
+  total_asset_book_value: number;
+  total_liability: number;
+  total_current_liability: number;
+  leases: LeaseReportEntry[];
+}
+
+export interface Section179Limit {
+  year: number;
+  dollar_limit: number;
+  phase_out_threshold: number;
+}
+
+export interface Section179Election {
+  asset_id: number;
+  name: string;
+  date_placed_in_service: string;
+  cost: number;
+  amount: number;
+}
+
+export interface Section179Report {
+  year: number;
+  dollar_limit: number;
+  phase_out_threshold: number;
+  qualifying_cost: number;
+  allowed_limit: number;
+  total_elected: number;
+  excess: number;
+  elections: Section179Election[];
+}
+
+export interface BonusOptOut {
+  year: number;
+  property_class: string;
+}
+
+export interface BookTaxDifference {
+  asset_id: number;
+  name: string;
+  book_depreciation: number;
+  tax_depreciation: number;
+  book_value: number;
+  tax_basis: number;
+  temporary_difference: number;
+  deferred_tax: number;
+}
+
+export interface BookTaxReport {
+  year: number;
+  tax_book: string;
+  tax_rate: number;
+  total_difference: number;
+  total_deferred_tax: number;
+  differences: BookTaxDifference[];
+}
+
 export interface AssetWithSchedule {
   asset: Asset;
   schedule: DepreciationEntry[];
   category_name?: string;
+  improvements: Asset[];
+}
+
+export interface FiscalYearSettings {
+  end_month: number;
+  current_year: number;
 }
 
 export interface DashboardStats {
@@ -60,9 +369,32 @@ export interface DashboardStats {
 export interface AnnualSummary {
   year: number;
   total_depreciation: number;
+  total_amortization: number;
+  asset_count: number;
+}
+
+export interface PeriodSummary {
+  year: number;
+  period: number;
+  period_start: string;
+  total_depreciation: number;
   asset_count: number;
 }
 
+export interface AssetDepreciation {
+  asset_id: number;
+  name: string;
+  depreciation_expense: number;
+}
+
+export interface DepreciationRange {
+  start_date: string;
+  end_date: string;
+  book: string;
+  total_depreciation: number;
+  assets: AssetDepreciation[];
+}
+
 export interface ImportResult {
   imported: number;
   errors: string[];